@@ -0,0 +1,66 @@
+extern crate autocfg;
+
+fn main() {
+    let ac = autocfg::new();
+
+    // Declare the custom cfgs so that newer compilers do not warn about them.
+    println!("cargo:rustc-check-cfg=cfg(impl_index)");
+    println!("cargo:rustc-check-cfg=cfg(impl_index_arrays)");
+    println!("cargo:rustc-check-cfg=cfg(get_disjoint_range_mut)");
+    println!("cargo:rustc-check-cfg=cfg(const_range)");
+    println!("cargo:rustc-check-cfg=cfg(tuple_from_range)");
+    println!("cargo:rustc-check-cfg=cfg(const_new)");
+    println!("cargo:rustc-check-cfg=cfg(try_from)");
+    println!("cargo:rustc-check-cfg=cfg(alloc_sync)");
+
+    // Implementing `Index` for `[T]` with a local range type requires the relaxed orphan rules of
+    // RFC 2451, which were stabilized in Rust 1.41. Compilers older than that reject the impls
+    // outright, so the compiler version is all that needs to be probed.
+    if ac.probe_rustc_version(1, 41) {
+        autocfg::emit("impl_index");
+        // The same rules allow implementing `From<Range>` for the foreign tuple `(Idx, Idx)`.
+        autocfg::emit("tuple_from_range");
+
+        // Arrays only gained a blanket `Index` impl delegating to slices in Rust 1.50. Before
+        // that, arrays need their own impls to satisfy `Index` bounds, but providing them once the
+        // blanket impl exists would overlap with it.
+        if !ac.probe_expression("<[u8; 0] as ::std::ops::Index<usize>>::index") {
+            autocfg::emit("impl_index_arrays");
+        }
+    } else {
+        println!(
+            "cargo:warning=more_ranges: indexing by range types requires rustc 1.41 or newer and is disabled"
+        );
+    }
+
+    // `const fn` was stabilized in Rust 1.31.
+    if ac.probe_rustc_version(1, 31) {
+        autocfg::emit("const_new");
+    }
+
+    // `TryFrom` was stabilized in Rust 1.34.
+    if ac.probe_rustc_version(1, 34) {
+        autocfg::emit("try_from");
+    }
+
+    // `SliceExclusiveExt::get_disjoint_range_mut` uses const generics and `array::map`, the latter
+    // of which was stabilized in Rust 1.55.
+    if ac.probe_rustc_version(1, 55) {
+        autocfg::emit("get_disjoint_range_mut");
+    }
+
+    // The const-generic range types validate their parameters with `assert!` in associated
+    // constants, which requires panicking in const contexts, stabilized in Rust 1.57.
+    if ac.probe_rustc_version(1, 57) {
+        autocfg::emit("const_range");
+    }
+
+    // `alloc::sync` is only available on targets with pointer-sized atomics, which excludes targets
+    // such as `thumbv6m-none-eabi`. `cfg(target_has_atomic)` was only stabilized in Rust 1.60, so
+    // the module is probed for directly instead.
+    if ac.probe_expression("{ extern crate alloc; alloc::sync::Arc::new(0u8) }") {
+        autocfg::emit("alloc_sync");
+    }
+
+    autocfg::rerun_path("build.rs");
+}
@@ -0,0 +1,204 @@
+//! Traits for accessing the bounds of any of the range types.
+//!
+//! These allow generic code to accept any range type in this crate without an overload for each.
+//! Both traits are object safe, so ranges of different types can also be stored together behind
+//! `dyn` pointers.
+
+use core::ops::Bound;
+use {
+    AnyExclusiveRange, RangeFromExclusive, RangeFromExclusiveToExclusive,
+    RangeFromExclusiveToInclusive,
+};
+
+/// A range bounded exclusively below.
+///
+/// This is implemented for every range type in this crate.
+///
+/// # Example
+/// ```
+/// use more_ranges::{ExclusiveLowerBound, RangeFromExclusive, RangeFromExclusiveToInclusive};
+///
+/// fn first_excluded<R>(range: &R) -> u32
+/// where
+///     R: ExclusiveLowerBound<u32>,
+/// {
+///     *range.start()
+/// }
+///
+/// assert_eq!(first_excluded(&RangeFromExclusive { start: 1 }), 1);
+/// assert_eq!(first_excluded(&RangeFromExclusiveToInclusive { start: 2, end: 5 }), 2);
+/// ```
+pub trait ExclusiveLowerBound<Idx> {
+    /// Returns the lower bound of the range (exclusive).
+    fn start(&self) -> &Idx;
+}
+
+/// A range with a possibly unbounded upper bound.
+///
+/// This is implemented for every range type in this crate. [`RangeFromExclusive`] returns
+/// `Unbounded`.
+///
+/// # Example
+/// ```
+/// use more_ranges::{
+///     ExclusiveLowerBound, RangeFromExclusive, RangeFromExclusiveToExclusive, UpperBound,
+/// };
+/// use std::ops::Bound;
+///
+/// /// Returns the number of values in the range, or `None` if it is unbounded.
+/// fn len<R>(range: &R) -> Option<u32>
+/// where
+///     R: ExclusiveLowerBound<u32> + UpperBound<u32>,
+/// {
+///     let start = *range.start();
+///     match range.end_bound_value() {
+///         Bound::Included(&end) => Some(end.saturating_sub(start)),
+///         Bound::Excluded(&end) => Some(end.saturating_sub(start).saturating_sub(1)),
+///         Bound::Unbounded => None,
+///     }
+/// }
+///
+/// assert_eq!(len(&RangeFromExclusiveToExclusive { start: 1, end: 5 }), Some(3));
+/// assert_eq!(len(&RangeFromExclusive { start: 1 }), None);
+/// ```
+pub trait UpperBound<Idx> {
+    /// Returns the upper bound of the range.
+    fn end_bound_value(&self) -> Bound<&Idx>;
+}
+
+impl<Idx> ExclusiveLowerBound<Idx> for RangeFromExclusive<Idx> {
+    #[inline]
+    fn start(&self) -> &Idx {
+        &self.start
+    }
+}
+
+impl<Idx> ExclusiveLowerBound<Idx> for RangeFromExclusiveToInclusive<Idx> {
+    #[inline]
+    fn start(&self) -> &Idx {
+        &self.start
+    }
+}
+
+impl<Idx> ExclusiveLowerBound<Idx> for RangeFromExclusiveToExclusive<Idx> {
+    #[inline]
+    fn start(&self) -> &Idx {
+        &self.start
+    }
+}
+
+impl<Idx> ExclusiveLowerBound<Idx> for AnyExclusiveRange<Idx> {
+    #[inline]
+    fn start(&self) -> &Idx {
+        match *self {
+            AnyExclusiveRange::From(ref range) => &range.start,
+            AnyExclusiveRange::ToInclusive(ref range) => &range.start,
+            AnyExclusiveRange::ToExclusive(ref range) => &range.start,
+        }
+    }
+}
+
+impl<Idx> UpperBound<Idx> for RangeFromExclusive<Idx> {
+    #[inline]
+    fn end_bound_value(&self) -> Bound<&Idx> {
+        Bound::Unbounded
+    }
+}
+
+impl<Idx> UpperBound<Idx> for RangeFromExclusiveToInclusive<Idx> {
+    #[inline]
+    fn end_bound_value(&self) -> Bound<&Idx> {
+        Bound::Included(&self.end)
+    }
+}
+
+impl<Idx> UpperBound<Idx> for RangeFromExclusiveToExclusive<Idx> {
+    #[inline]
+    fn end_bound_value(&self) -> Bound<&Idx> {
+        Bound::Excluded(&self.end)
+    }
+}
+
+impl<Idx> UpperBound<Idx> for AnyExclusiveRange<Idx> {
+    #[inline]
+    fn end_bound_value(&self) -> Bound<&Idx> {
+        match *self {
+            AnyExclusiveRange::From(ref range) => range.end_bound_value(),
+            AnyExclusiveRange::ToInclusive(ref range) => range.end_bound_value(),
+            AnyExclusiveRange::ToExclusive(ref range) => range.end_bound_value(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound;
+    use std::boxed::Box;
+    use std::vec::Vec;
+    use {
+        AnyExclusiveRange, ExclusiveLowerBound, RangeFromExclusive, RangeFromExclusiveToExclusive,
+        RangeFromExclusiveToInclusive, UpperBound,
+    };
+
+    #[test]
+    fn start() {
+        assert_eq!(*RangeFromExclusive { start: 1 }.start(), 1);
+        assert_eq!(
+            *RangeFromExclusiveToInclusive { start: 2, end: 5 }.start(),
+            2
+        );
+        assert_eq!(
+            *RangeFromExclusiveToExclusive { start: 3, end: 5 }.start(),
+            3
+        );
+        assert_eq!(
+            *AnyExclusiveRange::from(RangeFromExclusiveToExclusive { start: 4, end: 5 }).start(),
+            4
+        );
+    }
+
+    #[test]
+    fn end_bound_value() {
+        assert_eq!(
+            RangeFromExclusive { start: 1 }.end_bound_value(),
+            Bound::Unbounded
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 2, end: 5 }.end_bound_value(),
+            Bound::Included(&5)
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 3, end: 5 }.end_bound_value(),
+            Bound::Excluded(&5)
+        );
+        assert_eq!(
+            AnyExclusiveRange::from(RangeFromExclusiveToInclusive { start: 4, end: 6 })
+                .end_bound_value(),
+            Bound::Included(&6)
+        );
+    }
+
+    #[test]
+    fn dyn_lower_bound() {
+        let ranges: [Box<dyn ExclusiveLowerBound<u8>>; 3] = [
+            Box::new(RangeFromExclusive { start: 1 }),
+            Box::new(RangeFromExclusiveToInclusive { start: 2, end: 5 }),
+            Box::new(RangeFromExclusiveToExclusive { start: 3, end: 5 }),
+        ];
+
+        assert_eq!(
+            ranges
+                .iter()
+                .map(|range| *range.start())
+                .collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn dyn_upper_bound() {
+        let range: &dyn UpperBound<u8> = &RangeFromExclusiveToExclusive { start: 3, end: 5 };
+
+        assert_eq!(range.end_bound_value(), Bound::Excluded(&5));
+    }
+}
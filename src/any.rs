@@ -0,0 +1,360 @@
+//! A range that may be any of the range types in this crate.
+
+use core::fmt;
+use core::ops::{Bound, RangeBounds};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// Any range bounded exclusively below.
+///
+/// This allows accepting any of the range types in this crate without a generic parameter, while
+/// still guaranteeing that the start bound is exclusive (which a generic [`RangeBounds`] parameter
+/// would not).
+///
+/// When the `serde` feature is enabled, this is serialized in serde's externally tagged
+/// representation, such as `{"ToInclusive": {"start": 1, "end": 4}}`. An untagged representation
+/// is not used, since the two bounded variants serialize identically.
+///
+/// # Example
+/// ```
+/// use more_ranges::{AnyExclusiveRange, RangeFromExclusive, RangeFromExclusiveToInclusive};
+///
+/// fn count_contained(range: AnyExclusiveRange<u32>, values: &[u32]) -> usize {
+///     values.iter().filter(|value| range.contains(value)).count()
+/// }
+///
+/// let values = [1, 2, 3, 4, 5];
+///
+/// assert_eq!(count_contained(RangeFromExclusive { start: 2 }.into(), &values), 3);
+/// assert_eq!(
+///     count_contained(RangeFromExclusiveToInclusive { start: 2, end: 4 }.into(), &values),
+///     2
+/// );
+/// ```
+///
+/// [`RangeBounds`]: core::ops::RangeBounds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnyExclusiveRange<Idx> {
+    /// A range only bounded exclusively below.
+    From(RangeFromExclusive<Idx>),
+    /// A range bounded exclusively below and inclusively above.
+    ToInclusive(RangeFromExclusiveToInclusive<Idx>),
+    /// A range bounded exclusively below and above.
+    ToExclusive(RangeFromExclusiveToExclusive<Idx>),
+}
+
+impl<Idx> AnyExclusiveRange<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{AnyExclusiveRange, RangeFromExclusiveToExclusive};
+    ///
+    /// let range = AnyExclusiveRange::from(RangeFromExclusiveToExclusive { start: 1, end: 4 });
+    ///
+    /// assert!(!range.contains(&1));
+    /// assert!(range.contains(&3));
+    /// assert!(!range.contains(&4));
+    /// ```
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        match *self {
+            AnyExclusiveRange::From(ref range) => *item > range.start,
+            AnyExclusiveRange::ToInclusive(ref range) => *item > range.start && *item <= range.end,
+            AnyExclusiveRange::ToExclusive(ref range) => *item > range.start && *item < range.end,
+        }
+    }
+}
+
+impl<Idx> From<RangeFromExclusive<Idx>> for AnyExclusiveRange<Idx> {
+    #[inline]
+    fn from(range: RangeFromExclusive<Idx>) -> Self {
+        AnyExclusiveRange::From(range)
+    }
+}
+
+impl<Idx> From<RangeFromExclusiveToInclusive<Idx>> for AnyExclusiveRange<Idx> {
+    #[inline]
+    fn from(range: RangeFromExclusiveToInclusive<Idx>) -> Self {
+        AnyExclusiveRange::ToInclusive(range)
+    }
+}
+
+impl<Idx> From<RangeFromExclusiveToExclusive<Idx>> for AnyExclusiveRange<Idx> {
+    #[inline]
+    fn from(range: RangeFromExclusiveToExclusive<Idx>) -> Self {
+        AnyExclusiveRange::ToExclusive(range)
+    }
+}
+
+impl<T> RangeBounds<T> for AnyExclusiveRange<T> {
+    #[inline]
+    fn start_bound(&self) -> Bound<&T> {
+        match *self {
+            AnyExclusiveRange::From(ref range) => range.start_bound(),
+            AnyExclusiveRange::ToInclusive(ref range) => range.start_bound(),
+            AnyExclusiveRange::ToExclusive(ref range) => range.start_bound(),
+        }
+    }
+    #[inline]
+    fn end_bound(&self) -> Bound<&T> {
+        match *self {
+            AnyExclusiveRange::From(ref range) => range.end_bound(),
+            AnyExclusiveRange::ToInclusive(ref range) => range.end_bound(),
+            AnyExclusiveRange::ToExclusive(ref range) => range.end_bound(),
+        }
+    }
+}
+
+impl<'a, T> RangeBounds<T> for AnyExclusiveRange<&'a T>
+where
+    T: 'a + ?Sized,
+{
+    #[inline]
+    fn start_bound(&self) -> Bound<&T> {
+        match *self {
+            AnyExclusiveRange::From(ref range) => range.start_bound(),
+            AnyExclusiveRange::ToInclusive(ref range) => range.start_bound(),
+            AnyExclusiveRange::ToExclusive(ref range) => range.start_bound(),
+        }
+    }
+    #[inline]
+    fn end_bound(&self) -> Bound<&T> {
+        match *self {
+            AnyExclusiveRange::From(ref range) => range.end_bound(),
+            AnyExclusiveRange::ToInclusive(ref range) => range.end_bound(),
+            AnyExclusiveRange::ToExclusive(ref range) => range.end_bound(),
+        }
+    }
+}
+
+/// Formats the range in interval notation, such as `(1, 4]`.
+///
+/// A range without an upper bound is formatted with `∞` as its end, such as `(1, ∞)`.
+impl<Idx> fmt::Display for AnyExclusiveRange<Idx>
+where
+    Idx: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AnyExclusiveRange::From(ref range) => write!(f, "({}, ∞)", range.start),
+            AnyExclusiveRange::ToInclusive(ref range) => {
+                write!(f, "({}, {}]", range.start, range.end)
+            }
+            AnyExclusiveRange::ToExclusive(ref range) => {
+                write!(f, "({}, {})", range.start, range.end)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::{
+        Bound::{Excluded, Included, Unbounded},
+        RangeBounds,
+    };
+    #[cfg(feature = "serde")]
+    use serde_tokens::{assert_de_tokens_error, assert_tokens, Token};
+    use std::string::ToString;
+    use {
+        AnyExclusiveRange, RangeFromExclusive, RangeFromExclusiveToExclusive,
+        RangeFromExclusiveToInclusive,
+    };
+
+    #[test]
+    fn from_range_from_exclusive() {
+        assert_eq!(
+            AnyExclusiveRange::from(RangeFromExclusive { start: 1 }),
+            AnyExclusiveRange::From(RangeFromExclusive { start: 1 })
+        );
+    }
+
+    #[test]
+    fn from_range_from_exclusive_to_inclusive() {
+        assert_eq!(
+            AnyExclusiveRange::from(RangeFromExclusiveToInclusive { start: 1, end: 4 }),
+            AnyExclusiveRange::ToInclusive(RangeFromExclusiveToInclusive { start: 1, end: 4 })
+        );
+    }
+
+    #[test]
+    fn from_range_from_exclusive_to_exclusive() {
+        assert_eq!(
+            AnyExclusiveRange::from(RangeFromExclusiveToExclusive { start: 1, end: 4 }),
+            AnyExclusiveRange::ToExclusive(RangeFromExclusiveToExclusive { start: 1, end: 4 })
+        );
+    }
+
+    #[test]
+    fn range_bounds() {
+        let from = AnyExclusiveRange::from(RangeFromExclusive { start: 1 });
+        let to_inclusive =
+            AnyExclusiveRange::from(RangeFromExclusiveToInclusive { start: 1, end: 4 });
+        let to_exclusive =
+            AnyExclusiveRange::from(RangeFromExclusiveToExclusive { start: 1, end: 4 });
+
+        assert_matches!(from.start_bound(), Excluded(1));
+        assert_matches!(from.end_bound(), Unbounded);
+        assert_matches!(to_inclusive.start_bound(), Excluded(1));
+        assert_matches!(to_inclusive.end_bound(), Included(4));
+        assert_matches!(to_exclusive.start_bound(), Excluded(1));
+        assert_matches!(to_exclusive.end_bound(), Excluded(4));
+    }
+
+    #[test]
+    fn range_bounds_borrowed() {
+        let range = AnyExclusiveRange::from(RangeFromExclusiveToInclusive { start: &1, end: &4 });
+
+        assert_matches!(RangeBounds::<usize>::start_bound(&range), Excluded(1));
+        assert_matches!(RangeBounds::<usize>::end_bound(&range), Included(4));
+    }
+
+    #[test]
+    fn contains() {
+        let from = AnyExclusiveRange::from(RangeFromExclusive { start: 1 });
+        let to_inclusive =
+            AnyExclusiveRange::from(RangeFromExclusiveToInclusive { start: 1, end: 4 });
+        let to_exclusive =
+            AnyExclusiveRange::from(RangeFromExclusiveToExclusive { start: 1, end: 4 });
+
+        for value in 0..6 {
+            assert_eq!(from.contains(&value), value > 1);
+            assert_eq!(to_inclusive.contains(&value), value > 1 && value <= 4);
+            assert_eq!(to_exclusive.contains(&value), value > 1 && value < 4);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            AnyExclusiveRange::from(RangeFromExclusive { start: 1 }).to_string(),
+            "(1, ∞)"
+        );
+        assert_eq!(
+            AnyExclusiveRange::from(RangeFromExclusiveToInclusive { start: 1, end: 4 }).to_string(),
+            "(1, 4]"
+        );
+        assert_eq!(
+            AnyExclusiveRange::from(RangeFromExclusiveToExclusive { start: -1, end: 4 })
+                .to_string(),
+            "(-1, 4)"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_from() {
+        assert_tokens(
+            &AnyExclusiveRange::from(RangeFromExclusive { start: 1u8 }),
+            &[
+                Token::NewtypeVariant {
+                    name: "AnyExclusiveRange",
+                    variant: "From",
+                },
+                Token::Struct {
+                    name: "RangeFromExclusive",
+                    len: 1,
+                },
+                Token::Str("start"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_to_inclusive() {
+        assert_tokens(
+            &AnyExclusiveRange::from(RangeFromExclusiveToInclusive { start: 1u8, end: 4 }),
+            &[
+                Token::NewtypeVariant {
+                    name: "AnyExclusiveRange",
+                    variant: "ToInclusive",
+                },
+                Token::Struct {
+                    name: "RangeFromExclusiveToInclusive",
+                    len: 2,
+                },
+                Token::Str("start"),
+                Token::U8(1),
+                Token::Str("end"),
+                Token::U8(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_to_exclusive() {
+        assert_tokens(
+            &AnyExclusiveRange::from(RangeFromExclusiveToExclusive { start: 1u8, end: 4 }),
+            &[
+                Token::NewtypeVariant {
+                    name: "AnyExclusiveRange",
+                    variant: "ToExclusive",
+                },
+                Token::Struct {
+                    name: "RangeFromExclusiveToExclusive",
+                    len: 2,
+                },
+                Token::Str("start"),
+                Token::U8(1),
+                Token::Str("end"),
+                Token::U8(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_unknown_variant() {
+        assert_de_tokens_error::<AnyExclusiveRange<u8>>(
+            &[Token::NewtypeVariant {
+                name: "AnyExclusiveRange",
+                variant: "ToSomewhere",
+            }],
+            "unknown variant `ToSomewhere`, expected one of `From`, `ToInclusive`, `ToExclusive`",
+        );
+    }
+
+    #[cfg(impl_index)]
+    #[test]
+    fn index() {
+        let slice = [0, 1, 2, 3, 4];
+
+        assert_eq!(
+            slice[AnyExclusiveRange::from(RangeFromExclusive { start: 1 })],
+            [2, 3, 4]
+        );
+        assert_eq!(
+            slice[AnyExclusiveRange::from(RangeFromExclusiveToInclusive { start: 1, end: 3 })],
+            [2, 3]
+        );
+        assert_eq!(
+            slice[AnyExclusiveRange::from(RangeFromExclusiveToExclusive { start: 1, end: 3 })],
+            [2]
+        );
+        assert_eq!(
+            &"hello"[AnyExclusiveRange::from(RangeFromExclusive { start: 1 })],
+            "llo"
+        );
+    }
+
+    #[cfg(impl_index)]
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn index_out_of_bounds() {
+        let slice = [0, 1, 2, 3, 4];
+
+        let _ = &slice[AnyExclusiveRange::from(RangeFromExclusiveToInclusive { start: 1, end: 5 })];
+    }
+}
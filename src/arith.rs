@@ -0,0 +1,664 @@
+//! Arithmetic operators on the range types.
+//!
+//! Arithmetic is applied to each bound independently, following the usual overflow behavior of the
+//! index type: panicking when overflow checks are enabled, and wrapping otherwise.
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+#[cold]
+#[inline(never)]
+fn divide_by_zero_fail() -> ! {
+    panic!("attempted to divide the bounds of a range by zero");
+}
+
+macro_rules! impl_scale {
+    ($range:ident, $($t:ty),*) => {
+        $(
+            /// Scales both bounds by `rhs`.
+            ///
+            /// Overflow panics when overflow checks are enabled, and wraps otherwise.
+            impl Mul<$t> for $range<$t> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: $t) -> Self {
+                    $range {
+                        start: self.start * rhs,
+                        end: self.end * rhs,
+                    }
+                }
+            }
+
+            impl MulAssign<$t> for $range<$t> {
+                #[inline]
+                fn mul_assign(&mut self, rhs: $t) {
+                    self.start *= rhs;
+                    self.end *= rhs;
+                }
+            }
+
+            /// Divides both bounds by `rhs`.
+            ///
+            /// Each bound is truncated independently, so the resulting range does not in general
+            /// contain exactly the quotients of the values in the original range: it may both
+            /// gain and lose values near its bounds.
+            ///
+            /// # Panics
+            /// Panics if `rhs` is zero.
+            impl Div<$t> for $range<$t> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: $t) -> Self {
+                    if rhs == 0 {
+                        divide_by_zero_fail();
+                    }
+                    $range {
+                        start: self.start / rhs,
+                        end: self.end / rhs,
+                    }
+                }
+            }
+
+            impl DivAssign<$t> for $range<$t> {
+                #[inline]
+                fn div_assign(&mut self, rhs: $t) {
+                    if rhs == 0 {
+                        divide_by_zero_fail();
+                    }
+                    self.start /= rhs;
+                    self.end /= rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_scale!(
+    RangeFromExclusiveToExclusive,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize
+);
+impl_scale!(
+    RangeFromExclusiveToInclusive,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize
+);
+
+/// Shifts the bound up by `rhs`.
+///
+/// Overflow panics when overflow checks are enabled, and wraps otherwise.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusive;
+///
+/// assert_eq!(RangeFromExclusive { start: 1 } + 10, RangeFromExclusive { start: 11 });
+/// ```
+impl<Idx> Add<Idx> for RangeFromExclusive<Idx>
+where
+    Idx: Add<Output = Idx>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Idx) -> Self {
+        RangeFromExclusive {
+            start: self.start + rhs,
+        }
+    }
+}
+
+/// Shifts the bound down by `rhs`.
+///
+/// Overflow panics when overflow checks are enabled, and wraps otherwise.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusive;
+///
+/// assert_eq!(RangeFromExclusive { start: 11 } - 10, RangeFromExclusive { start: 1 });
+/// ```
+impl<Idx> Sub<Idx> for RangeFromExclusive<Idx>
+where
+    Idx: Sub<Output = Idx>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Idx) -> Self {
+        RangeFromExclusive {
+            start: self.start - rhs,
+        }
+    }
+}
+
+impl<Idx> AddAssign<Idx> for RangeFromExclusive<Idx>
+where
+    Idx: AddAssign,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Idx) {
+        self.start += rhs;
+    }
+}
+
+impl<Idx> SubAssign<Idx> for RangeFromExclusive<Idx>
+where
+    Idx: SubAssign,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Idx) {
+        self.start -= rhs;
+    }
+}
+
+/// Shifts both bounds up by `rhs`.
+///
+/// Overflow panics when overflow checks are enabled, and wraps otherwise.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusiveToInclusive;
+///
+/// assert_eq!(
+///     RangeFromExclusiveToInclusive { start: 1, end: 4 } + 10,
+///     RangeFromExclusiveToInclusive { start: 11, end: 14 }
+/// );
+/// ```
+impl<Idx> Add<Idx> for RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Add<Output = Idx> + Copy,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Idx) -> Self {
+        RangeFromExclusiveToInclusive {
+            start: self.start + rhs,
+            end: self.end + rhs,
+        }
+    }
+}
+
+/// Shifts both bounds down by `rhs`.
+///
+/// Overflow panics when overflow checks are enabled, and wraps otherwise.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusiveToInclusive;
+///
+/// assert_eq!(
+///     RangeFromExclusiveToInclusive { start: 11, end: 14 } - 10,
+///     RangeFromExclusiveToInclusive { start: 1, end: 4 }
+/// );
+/// ```
+impl<Idx> Sub<Idx> for RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Sub<Output = Idx> + Copy,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Idx) -> Self {
+        RangeFromExclusiveToInclusive {
+            start: self.start - rhs,
+            end: self.end - rhs,
+        }
+    }
+}
+
+impl<Idx> AddAssign<Idx> for RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: AddAssign + Copy,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Idx) {
+        self.start += rhs;
+        self.end += rhs;
+    }
+}
+
+impl<Idx> SubAssign<Idx> for RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: SubAssign + Copy,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Idx) {
+        self.start -= rhs;
+        self.end -= rhs;
+    }
+}
+
+/// Shifts both bounds up by `rhs`.
+///
+/// Overflow panics when overflow checks are enabled, and wraps otherwise.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusiveToExclusive;
+///
+/// assert_eq!(
+///     RangeFromExclusiveToExclusive { start: 1, end: 4 } + 10,
+///     RangeFromExclusiveToExclusive { start: 11, end: 14 }
+/// );
+/// ```
+impl<Idx> Add<Idx> for RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Add<Output = Idx> + Copy,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Idx) -> Self {
+        RangeFromExclusiveToExclusive {
+            start: self.start + rhs,
+            end: self.end + rhs,
+        }
+    }
+}
+
+/// Shifts both bounds down by `rhs`.
+///
+/// Overflow panics when overflow checks are enabled, and wraps otherwise.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusiveToExclusive;
+///
+/// assert_eq!(
+///     RangeFromExclusiveToExclusive { start: 11, end: 14 } - 10,
+///     RangeFromExclusiveToExclusive { start: 1, end: 4 }
+/// );
+/// ```
+impl<Idx> Sub<Idx> for RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Sub<Output = Idx> + Copy,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Idx) -> Self {
+        RangeFromExclusiveToExclusive {
+            start: self.start - rhs,
+            end: self.end - rhs,
+        }
+    }
+}
+
+impl<Idx> AddAssign<Idx> for RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: AddAssign + Copy,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Idx) {
+        self.start += rhs;
+        self.end += rhs;
+    }
+}
+
+impl<Idx> SubAssign<Idx> for RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: SubAssign + Copy,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Idx) {
+        self.start -= rhs;
+        self.end -= rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[test]
+    fn range_from_exclusive_add() {
+        assert_eq!(
+            RangeFromExclusive { start: 1u8 } + 10,
+            RangeFromExclusive { start: 11 }
+        );
+        assert_eq!(
+            RangeFromExclusive { start: -10i128 } + 3,
+            RangeFromExclusive { start: -7 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_sub() {
+        assert_eq!(
+            RangeFromExclusive { start: 11usize } - 10,
+            RangeFromExclusive { start: 1 }
+        );
+        assert_eq!(
+            RangeFromExclusive { start: 0i32 } - 3,
+            RangeFromExclusive { start: -3 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_add_assign() {
+        let mut after = RangeFromExclusive { start: 40u64 };
+        after += 20;
+
+        assert_eq!(after, RangeFromExclusive { start: 60 });
+    }
+
+    #[test]
+    fn range_from_exclusive_sub_assign() {
+        let mut after = RangeFromExclusive { start: -1i16 };
+        after -= 1;
+
+        assert_eq!(after, RangeFromExclusive { start: -2 });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn range_from_exclusive_add_overflow() {
+        let _ = RangeFromExclusive {
+            start: u32::max_value(),
+        } + 1;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn range_from_exclusive_sub_assign_overflow() {
+        let mut after = RangeFromExclusive {
+            start: i8::min_value(),
+        };
+        after -= 1;
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_mul() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 1u32,
+                end: 4
+            } * 16,
+            RangeFromExclusiveToExclusive { start: 16, end: 64 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_mul_assign() {
+        let mut range = RangeFromExclusiveToExclusive {
+            start: 1u32,
+            end: 4,
+        };
+        range *= 16;
+
+        assert_eq!(range, RangeFromExclusiveToExclusive { start: 16, end: 64 });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_div() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 16usize,
+                end: 64
+            } / 16,
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_div_truncates_both_bounds() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 31usize,
+                end: 79
+            } / 16,
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_div_assign() {
+        let mut range = RangeFromExclusiveToExclusive {
+            start: 17u8,
+            end: 47,
+        };
+        range /= 16;
+
+        assert_eq!(range, RangeFromExclusiveToExclusive { start: 1, end: 2 });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_mul() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 2u64,
+                end: 5
+            } * 3,
+            RangeFromExclusiveToInclusive { start: 6, end: 15 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_mul_assign() {
+        let mut range = RangeFromExclusiveToInclusive {
+            start: 2u64,
+            end: 5,
+        };
+        range *= 3;
+
+        assert_eq!(range, RangeFromExclusiveToInclusive { start: 6, end: 15 });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_div_truncates_both_bounds() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 7u16,
+                end: 17
+            } / 4,
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_div_assign() {
+        let mut range = RangeFromExclusiveToInclusive {
+            start: 7u16,
+            end: 17,
+        };
+        range /= 4;
+
+        assert_eq!(range, RangeFromExclusiveToInclusive { start: 1, end: 4 });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_add() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 1usize,
+                end: 4
+            } + 10,
+            RangeFromExclusiveToInclusive { start: 11, end: 14 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -4i32,
+                end: -1
+            } + 3,
+            RangeFromExclusiveToInclusive { start: -1, end: 2 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_sub() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 11u64,
+                end: 14
+            } - 10,
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 1i8, end: 4 } - 5,
+            RangeFromExclusiveToInclusive { start: -4, end: -1 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_add_float() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 0.5,
+                end: 1.5
+            } + 1.0,
+            RangeFromExclusiveToInclusive {
+                start: 1.5,
+                end: 2.5
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_add_assign() {
+        let mut range = RangeFromExclusiveToInclusive {
+            start: -4i64,
+            end: -1,
+        };
+        range += 3;
+
+        assert_eq!(range, RangeFromExclusiveToInclusive { start: -1, end: 2 });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_sub_assign() {
+        let mut range = RangeFromExclusiveToInclusive {
+            start: 11u32,
+            end: 14,
+        };
+        range -= 10;
+
+        assert_eq!(range, RangeFromExclusiveToInclusive { start: 1, end: 4 });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_add() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 1usize,
+                end: 4
+            } + 10,
+            RangeFromExclusiveToExclusive { start: 11, end: 14 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: -4i16,
+                end: -1
+            } + 3,
+            RangeFromExclusiveToExclusive { start: -1, end: 2 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_sub() {
+        // Rebasing a span within a chunk starting at offset 100.
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 103u64,
+                end: 108
+            } - 100,
+            RangeFromExclusiveToExclusive { start: 3, end: 8 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 1i8, end: 4 } - 5,
+            RangeFromExclusiveToExclusive { start: -4, end: -1 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_add_assign() {
+        let mut range = RangeFromExclusiveToExclusive {
+            start: 3usize,
+            end: 8,
+        };
+        range += 100;
+
+        assert_eq!(
+            range,
+            RangeFromExclusiveToExclusive {
+                start: 103,
+                end: 108
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_sub_assign() {
+        let mut range = RangeFromExclusiveToExclusive {
+            start: 0i32,
+            end: 2,
+        };
+        range -= 2;
+
+        assert_eq!(range, RangeFromExclusiveToExclusive { start: -2, end: 0 });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn add_assign_overflow() {
+        let mut range = RangeFromExclusiveToExclusive {
+            start: 1u8,
+            end: 250,
+        };
+        range += 10;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn add_overflow() {
+        let _ = RangeFromExclusiveToInclusive {
+            start: 1u8,
+            end: 250,
+        } + 10;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn sub_overflow() {
+        let _ = RangeFromExclusiveToInclusive { start: 1u8, end: 4 } - 2;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to divide the bounds of a range by zero")]
+    fn div_by_zero() {
+        let _ = RangeFromExclusiveToInclusive { start: 1u8, end: 2 } / 0;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to divide the bounds of a range by zero")]
+    fn div_assign_by_zero() {
+        let mut range = RangeFromExclusiveToExclusive { start: 1u8, end: 2 };
+        range /= 0;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn mul_overflow() {
+        let _ = RangeFromExclusiveToInclusive {
+            start: 1u8,
+            end: 128,
+        } * 2;
+    }
+}
@@ -0,0 +1,342 @@
+//! Ranges with bounds fixed at compile time.
+//!
+//! These types are zero-sized, with their bounds carried entirely in const generic parameters. An
+//! invalid parameterization, where `START > END`, is rejected during compilation as soon as the
+//! range is used.
+
+use core::iter::Skip;
+use core::ops::{Range, RangeInclusive};
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// A range over `usize` bounded exclusively below and above, with bounds known at compile time.
+///
+/// The `ConstRangeFromExclusiveToExclusive` contains all values with `x > START` and `x < END`.
+/// `START` must not be greater than `END`; using a range that violates this fails to compile.
+///
+/// This type requires Rust 1.57 or newer.
+///
+/// # Example
+/// ```
+/// use more_ranges::ConstRangeFromExclusiveToExclusive;
+///
+/// type Window = ConstRangeFromExclusiveToExclusive<2, 6>;
+///
+/// let mut buffer = [0; Window::LEN];
+/// for (slot, value) in buffer.iter_mut().zip(Window::new()) {
+///     *slot = value;
+/// }
+///
+/// assert_eq!(buffer, [3, 4, 5]);
+/// ```
+///
+/// An inverted range fails to compile:
+///
+/// ```compile_fail
+/// use more_ranges::ConstRangeFromExclusiveToExclusive;
+///
+/// let _ = ConstRangeFromExclusiveToExclusive::<6, 2>::LEN;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConstRangeFromExclusiveToExclusive<const START: usize, const END: usize>;
+
+impl<const START: usize, const END: usize> ConstRangeFromExclusiveToExclusive<START, END> {
+    /// The number of values contained in the range.
+    pub const LEN: usize = {
+        assert!(START <= END, "range start is greater than range end");
+        (END - START).saturating_sub(1)
+    };
+
+    /// Creates the range.
+    #[inline]
+    pub const fn new() -> Self {
+        let _ = Self::LEN;
+        ConstRangeFromExclusiveToExclusive
+    }
+
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::ConstRangeFromExclusiveToExclusive;
+    ///
+    /// const RANGE: ConstRangeFromExclusiveToExclusive<1, 4> = ConstRangeFromExclusiveToExclusive;
+    /// const CONTAINS_1: bool = RANGE.contains(1);
+    ///
+    /// assert!(!CONTAINS_1);
+    /// assert!(RANGE.contains(3));
+    /// assert!(!RANGE.contains(4));
+    /// ```
+    #[inline]
+    pub const fn contains(&self, item: usize) -> bool {
+        let _ = Self::LEN;
+        START < item && item < END
+    }
+
+    /// Returns the equivalent [`RangeFromExclusiveToExclusive`].
+    ///
+    /// [`RangeFromExclusiveToExclusive`]: ::RangeFromExclusiveToExclusive
+    #[inline]
+    pub const fn to_runtime(self) -> RangeFromExclusiveToExclusive<usize> {
+        let _ = Self::LEN;
+        RangeFromExclusiveToExclusive {
+            start: START,
+            end: END,
+        }
+    }
+}
+
+impl<const START: usize, const END: usize> IntoIterator
+    for ConstRangeFromExclusiveToExclusive<START, END>
+{
+    type Item = usize;
+    type IntoIter = Range<usize>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        // `START + 1` overflows when both bounds are the maximum `usize`, so an empty range is
+        // produced at the end instead.
+        if Self::LEN == 0 {
+            END..END
+        } else {
+            START + 1..END
+        }
+    }
+}
+
+/// A range over `usize` bounded exclusively below and inclusively above, with bounds known at
+/// compile time.
+///
+/// The `ConstRangeFromExclusiveToInclusive` contains all values with `x > START` and `x <= END`.
+/// `START` must not be greater than `END`; using a range that violates this fails to compile.
+///
+/// This type requires Rust 1.57 or newer.
+///
+/// # Example
+/// ```
+/// use more_ranges::ConstRangeFromExclusiveToInclusive;
+///
+/// type Window = ConstRangeFromExclusiveToInclusive<2, 6>;
+///
+/// let mut buffer = [0; Window::LEN];
+/// for (slot, value) in buffer.iter_mut().zip(Window::new()) {
+///     *slot = value;
+/// }
+///
+/// assert_eq!(buffer, [3, 4, 5, 6]);
+/// ```
+///
+/// An inverted range fails to compile:
+///
+/// ```compile_fail
+/// use more_ranges::ConstRangeFromExclusiveToInclusive;
+///
+/// let _ = ConstRangeFromExclusiveToInclusive::<6, 2>::LEN;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConstRangeFromExclusiveToInclusive<const START: usize, const END: usize>;
+
+impl<const START: usize, const END: usize> ConstRangeFromExclusiveToInclusive<START, END> {
+    /// The number of values contained in the range.
+    pub const LEN: usize = {
+        assert!(START <= END, "range start is greater than range end");
+        END - START
+    };
+
+    /// Creates the range.
+    #[inline]
+    pub const fn new() -> Self {
+        let _ = Self::LEN;
+        ConstRangeFromExclusiveToInclusive
+    }
+
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::ConstRangeFromExclusiveToInclusive;
+    ///
+    /// const RANGE: ConstRangeFromExclusiveToInclusive<1, 4> = ConstRangeFromExclusiveToInclusive;
+    /// const CONTAINS_4: bool = RANGE.contains(4);
+    ///
+    /// assert!(CONTAINS_4);
+    /// assert!(!RANGE.contains(1));
+    /// assert!(!RANGE.contains(5));
+    /// ```
+    #[inline]
+    pub const fn contains(&self, item: usize) -> bool {
+        let _ = Self::LEN;
+        START < item && item <= END
+    }
+
+    /// Returns the equivalent [`RangeFromExclusiveToInclusive`].
+    ///
+    /// [`RangeFromExclusiveToInclusive`]: ::RangeFromExclusiveToInclusive
+    #[inline]
+    pub const fn to_runtime(self) -> RangeFromExclusiveToInclusive<usize> {
+        let _ = Self::LEN;
+        RangeFromExclusiveToInclusive {
+            start: START,
+            end: END,
+        }
+    }
+}
+
+impl<const START: usize, const END: usize> IntoIterator
+    for ConstRangeFromExclusiveToInclusive<START, END>
+{
+    type Item = usize;
+    type IntoIter = Skip<RangeInclusive<usize>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        // Skipping `START` rather than starting from `START + 1` avoids overflow when both bounds
+        // are the maximum `usize`.
+        let _ = Self::LEN;
+        (START..=END).skip(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+    use {
+        ConstRangeFromExclusiveToExclusive, ConstRangeFromExclusiveToInclusive,
+        RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+    };
+
+    #[test]
+    fn to_exclusive_len() {
+        assert_eq!(ConstRangeFromExclusiveToExclusive::<1, 4>::LEN, 2);
+        assert_eq!(ConstRangeFromExclusiveToExclusive::<1, 2>::LEN, 0);
+        assert_eq!(ConstRangeFromExclusiveToExclusive::<3, 3>::LEN, 0);
+    }
+
+    #[test]
+    fn to_exclusive_len_as_array_length() {
+        let array = [0u8; ConstRangeFromExclusiveToExclusive::<0, 8>::LEN];
+
+        assert_eq!(array.len(), 7);
+    }
+
+    #[test]
+    fn to_exclusive_iter() {
+        assert_eq!(
+            ConstRangeFromExclusiveToExclusive::<1, 5>::new()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn to_exclusive_iter_empty() {
+        assert_eq!(
+            ConstRangeFromExclusiveToExclusive::<4, 5>::new()
+                .into_iter()
+                .count(),
+            0
+        );
+        assert_eq!(
+            ConstRangeFromExclusiveToExclusive::<{ usize::MAX }, { usize::MAX }>::new()
+                .into_iter()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn to_exclusive_iter_matches_len() {
+        let range = ConstRangeFromExclusiveToExclusive::<3, 11>::new();
+
+        assert_eq!(
+            range.into_iter().count(),
+            ConstRangeFromExclusiveToExclusive::<3, 11>::LEN
+        );
+        assert!(range.into_iter().all(|value| range.contains(value)));
+    }
+
+    #[test]
+    fn to_exclusive_contains() {
+        const RANGE: ConstRangeFromExclusiveToExclusive<1, 4> = ConstRangeFromExclusiveToExclusive;
+        const CONTAINS: [bool; 6] = [
+            RANGE.contains(0),
+            RANGE.contains(1),
+            RANGE.contains(2),
+            RANGE.contains(3),
+            RANGE.contains(4),
+            RANGE.contains(5),
+        ];
+
+        assert_eq!(CONTAINS, [false, false, true, true, false, false]);
+    }
+
+    #[test]
+    fn to_exclusive_to_runtime() {
+        const RANGE: RangeFromExclusiveToExclusive<usize> =
+            ConstRangeFromExclusiveToExclusive::<1, 4>::new().to_runtime();
+
+        assert_eq!(RANGE, RangeFromExclusiveToExclusive { start: 1, end: 4 });
+    }
+
+    #[test]
+    fn to_inclusive_len() {
+        assert_eq!(ConstRangeFromExclusiveToInclusive::<1, 4>::LEN, 3);
+        assert_eq!(ConstRangeFromExclusiveToInclusive::<3, 3>::LEN, 0);
+    }
+
+    #[test]
+    fn to_inclusive_len_as_array_length() {
+        let array = [0u8; ConstRangeFromExclusiveToInclusive::<0, 8>::LEN];
+
+        assert_eq!(array.len(), 8);
+    }
+
+    #[test]
+    fn to_inclusive_iter() {
+        assert_eq!(
+            ConstRangeFromExclusiveToInclusive::<1, 5>::new()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn to_inclusive_iter_at_maximum() {
+        assert_eq!(
+            ConstRangeFromExclusiveToInclusive::<{ usize::MAX - 1 }, { usize::MAX }>::new()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [usize::MAX]
+        );
+        assert_eq!(
+            ConstRangeFromExclusiveToInclusive::<{ usize::MAX }, { usize::MAX }>::new()
+                .into_iter()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn to_inclusive_contains() {
+        const RANGE: ConstRangeFromExclusiveToInclusive<1, 4> = ConstRangeFromExclusiveToInclusive;
+        const CONTAINS: [bool; 6] = [
+            RANGE.contains(0),
+            RANGE.contains(1),
+            RANGE.contains(2),
+            RANGE.contains(3),
+            RANGE.contains(4),
+            RANGE.contains(5),
+        ];
+
+        assert_eq!(CONTAINS, [false, false, true, true, true, false]);
+    }
+
+    #[test]
+    fn to_inclusive_to_runtime() {
+        const RANGE: RangeFromExclusiveToInclusive<usize> =
+            ConstRangeFromExclusiveToInclusive::<1, 4>::new().to_runtime();
+
+        assert_eq!(RANGE, RangeFromExclusiveToInclusive { start: 1, end: 4 });
+    }
+}
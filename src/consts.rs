@@ -0,0 +1,195 @@
+//! Associated constants for ranges over the primitive integers.
+//!
+//! Since every range type in this crate is bounded exclusively below, no range over a primitive
+//! integer can contain the minimum value of its type. The `FULL` constants therefore start at the
+//! minimum value and contain every other value.
+
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+macro_rules! impl_consts {
+    ($($t:ident),*) => {
+        $(
+            impl RangeFromExclusive<$t> {
+                /// The range containing every value of the index type except its minimum.
+                ///
+                /// The minimum value cannot be contained in any `RangeFromExclusive`, as that
+                /// would require a `start` below the minimum.
+                pub const FULL: Self = RangeFromExclusive {
+                    start: ::core::$t::MIN,
+                };
+            }
+
+            impl RangeFromExclusiveToInclusive<$t> {
+                /// The range containing every value of the index type except its minimum.
+                ///
+                /// The minimum value cannot be contained in any `RangeFromExclusiveToInclusive`, as
+                /// that would require a `start` below the minimum.
+                pub const FULL: Self = RangeFromExclusiveToInclusive {
+                    start: ::core::$t::MIN,
+                    end: ::core::$t::MAX,
+                };
+
+                /// The canonical empty range, with both bounds equal to the minimum value of the
+                /// index type.
+                pub const EMPTY: Self = RangeFromExclusiveToInclusive {
+                    start: ::core::$t::MIN,
+                    end: ::core::$t::MIN,
+                };
+            }
+
+            impl RangeFromExclusiveToExclusive<$t> {
+                /// The range containing every value of the index type except its minimum and
+                /// maximum.
+                ///
+                /// Neither extreme can be contained in any `RangeFromExclusiveToExclusive`, as that
+                /// would require bounds outside of the index type.
+                pub const FULL: Self = RangeFromExclusiveToExclusive {
+                    start: ::core::$t::MIN,
+                    end: ::core::$t::MAX,
+                };
+
+                /// The canonical empty range, with both bounds equal to the minimum value of the
+                /// index type.
+                pub const EMPTY: Self = RangeFromExclusiveToExclusive {
+                    start: ::core::$t::MIN,
+                    end: ::core::$t::MIN,
+                };
+            }
+        )*
+    };
+}
+
+impl_consts!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    const TABLE: [RangeFromExclusiveToInclusive<u8>; 2] = [
+        RangeFromExclusiveToInclusive::<u8>::FULL,
+        RangeFromExclusiveToInclusive::<u8>::EMPTY,
+    ];
+
+    static EMPTY: RangeFromExclusiveToExclusive<i16> = RangeFromExclusiveToExclusive::<i16>::EMPTY;
+
+    #[test]
+    fn const_context() {
+        assert_eq!(
+            TABLE[0],
+            RangeFromExclusiveToInclusive { start: 0, end: 255 }
+        );
+        assert_eq!(TABLE[1], RangeFromExclusiveToInclusive { start: 0, end: 0 });
+        assert_eq!(
+            EMPTY,
+            RangeFromExclusiveToExclusive {
+                start: -32768,
+                end: -32768
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_full() {
+        let full = RangeFromExclusive::<i8>::FULL;
+
+        assert!(!full.contains(&i8::min_value()));
+        assert!((i8::min_value() + 1..=i8::max_value()).all(|value| full.contains(&value)));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_full() {
+        let full = RangeFromExclusiveToInclusive::<u8>::FULL;
+
+        assert!(!full.contains(&0));
+        assert!((1..=u8::max_value()).all(|value| full.contains(&value)));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_empty() {
+        let empty = RangeFromExclusiveToInclusive::<i8>::EMPTY;
+
+        assert!((i8::min_value()..=i8::max_value()).all(|value| !empty.contains(&value)));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_full() {
+        let full = RangeFromExclusiveToExclusive::<u8>::FULL;
+
+        assert!(!full.contains(&0));
+        assert!(!full.contains(&u8::max_value()));
+        assert!((1..u8::max_value()).all(|value| full.contains(&value)));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_empty() {
+        let empty = RangeFromExclusiveToExclusive::<u8>::EMPTY;
+
+        assert!((0..=u8::max_value()).all(|value| !empty.contains(&value)));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_agrees_with_len() {
+        assert_eq!(RangeFromExclusiveToInclusive::<u8>::FULL.len(), 255);
+        assert_eq!(RangeFromExclusiveToInclusive::<i8>::FULL.len(), 255);
+        assert_eq!(RangeFromExclusiveToInclusive::<u8>::EMPTY.len(), 0);
+        assert_eq!(RangeFromExclusiveToInclusive::<i8>::EMPTY.len(), 0);
+        assert_eq!(
+            RangeFromExclusiveToInclusive::<u128>::FULL.len_u128(),
+            u128::max_value()
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_agrees_with_is_empty() {
+        assert!(!RangeFromExclusiveToInclusive::<u8>::FULL.is_empty());
+        assert!(!RangeFromExclusiveToInclusive::<i64>::FULL.is_empty());
+        assert!(RangeFromExclusiveToInclusive::<u8>::EMPTY.is_empty());
+        assert!(RangeFromExclusiveToInclusive::<i64>::EMPTY.is_empty());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_agrees_with_len() {
+        assert_eq!(RangeFromExclusiveToExclusive::<u8>::FULL.len(), 254);
+        assert_eq!(RangeFromExclusiveToExclusive::<i8>::FULL.len(), 254);
+        assert_eq!(RangeFromExclusiveToExclusive::<u8>::EMPTY.len(), 0);
+        assert_eq!(RangeFromExclusiveToExclusive::<i8>::EMPTY.len(), 0);
+        assert_eq!(
+            RangeFromExclusiveToExclusive::<i128>::FULL.len_u128(),
+            u128::max_value() - 1
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_agrees_with_is_empty() {
+        assert!(!RangeFromExclusiveToExclusive::<u8>::FULL.is_empty());
+        assert!(!RangeFromExclusiveToExclusive::<u8>::FULL.is_empty_discrete());
+        assert!(RangeFromExclusiveToExclusive::<u8>::EMPTY.is_empty());
+        assert!(RangeFromExclusiveToExclusive::<u8>::EMPTY.is_empty_discrete());
+        assert!(RangeFromExclusiveToExclusive::<isize>::EMPTY.is_empty_discrete());
+    }
+
+    #[test]
+    fn wide_types() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::<u128>::FULL,
+            RangeFromExclusiveToInclusive {
+                start: 0,
+                end: u128::max_value()
+            }
+        );
+        assert_eq!(
+            RangeFromExclusive::<i128>::FULL,
+            RangeFromExclusive {
+                start: i128::min_value()
+            }
+        );
+    }
+
+    #[test]
+    fn valid() {
+        assert_ok!(RangeFromExclusiveToInclusive::<isize>::FULL.validate());
+        assert_ok!(RangeFromExclusiveToInclusive::<isize>::EMPTY.validate());
+        assert_ok!(RangeFromExclusiveToExclusive::<usize>::FULL.validate());
+        assert_ok!(RangeFromExclusiveToExclusive::<usize>::EMPTY.validate());
+    }
+}
@@ -0,0 +1,800 @@
+//! Membership and emptiness of the range types.
+
+use core::cmp::Ordering;
+use iter_bounds::Integer;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// The position of a value relative to a range.
+///
+/// This is returned by the `locate()` method of each range type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RangePosition {
+    /// The value is less than or equal to the lower bound (exclusive) of the range.
+    Below,
+    /// The value is contained in the range.
+    Within,
+    /// The value is past the upper bound of the range.
+    Above,
+}
+
+impl<Idx> RangeFromExclusive<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// The range is unbounded above, so this is `true` for every `item` greater than `start`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// let range = RangeFromExclusive { start: 1 };
+    ///
+    /// assert!(!range.contains(&0));
+    /// assert!(!range.contains(&1));
+    /// assert!(range.contains(&2));
+    /// assert!(range.contains(&i32::max_value()));
+    /// ```
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        *item > self.start
+    }
+
+    /// Returns the position of `value` relative to the range.
+    ///
+    /// The range is unbounded above, so this never returns [`RangePosition::Above`].
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusive, RangePosition};
+    ///
+    /// let range = RangeFromExclusive { start: 1 };
+    ///
+    /// assert_eq!(range.locate(&1), RangePosition::Below);
+    /// assert_eq!(range.locate(&2), RangePosition::Within);
+    /// ```
+    ///
+    /// [`RangePosition::Above`]: RangePosition::Above
+    #[inline]
+    pub fn locate(&self, value: &Idx) -> RangePosition
+    where
+        Idx: Ord,
+    {
+        if *value > self.start {
+            RangePosition::Within
+        } else {
+            RangePosition::Below
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    /// use std::time::Duration;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 1, end: 4 };
+    ///
+    /// assert!(!range.contains(&1));
+    /// assert!(range.contains(&2));
+    /// assert!(range.contains(&4));
+    /// assert!(!range.contains(&5));
+    ///
+    /// let range = RangeFromExclusiveToInclusive {
+    ///     start: Duration::from_millis(1),
+    ///     end: Duration::from_secs(1),
+    /// };
+    ///
+    /// assert!(!range.contains(&Duration::from_millis(1)));
+    /// assert!(range.contains(&Duration::from_secs(1)));
+    /// ```
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        *item > self.start && *item <= self.end
+    }
+
+    /// Returns the position of `value` relative to the range.
+    ///
+    /// A value equal to `start` is [`RangePosition::Below`], and a value equal to `end` is
+    /// [`RangePosition::Within`].
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToInclusive, RangePosition};
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.locate(&1), RangePosition::Below);
+    /// assert_eq!(range.locate(&4), RangePosition::Within);
+    /// assert_eq!(range.locate(&5), RangePosition::Above);
+    /// ```
+    ///
+    /// [`RangePosition::Below`]: RangePosition::Below
+    /// [`RangePosition::Within`]: RangePosition::Within
+    #[inline]
+    pub fn locate(&self, value: &Idx) -> RangePosition
+    where
+        Idx: Ord,
+    {
+        if *value <= self.start {
+            RangePosition::Below
+        } else if *value > self.end {
+            RangePosition::Above
+        } else {
+            RangePosition::Within
+        }
+    }
+
+    /// Returns `true` if the range contains no values.
+    ///
+    /// This is the case when `start` is not less than `end`, including when either bound is
+    /// incomparable, such as a floating-point `NaN`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert!(!RangeFromExclusiveToInclusive { start: 1, end: 2 }.is_empty());
+    /// assert!(RangeFromExclusiveToInclusive { start: 2, end: 2 }.is_empty());
+    /// assert!(RangeFromExclusiveToInclusive { start: 3, end: 2 }.is_empty());
+    /// assert!(RangeFromExclusiveToInclusive { start: 0.0, end: std::f64::NAN }.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    where
+        Idx: PartialOrd,
+    {
+        self.start.partial_cmp(&self.end) != Some(Ordering::Less)
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Returns the range in canonical form.
+    ///
+    /// Every empty range is mapped to `{MIN, MIN}`, where `MIN` is the minimum value of `Idx`, and
+    /// non-empty ranges are returned unchanged. Two normalized ranges are therefore equal, and hash
+    /// equally, exactly when they contain the same values.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 5u8, end: 3 }.normalize(),
+    ///     RangeFromExclusiveToInclusive { start: 9u8, end: 9 }.normalize()
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1u8, end: 3 }.normalize(),
+    ///     RangeFromExclusiveToInclusive { start: 1u8, end: 3 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn normalize(self) -> Self {
+        if self.start >= self.end {
+            RangeFromExclusiveToInclusive {
+                start: Idx::MIN,
+                end: Idx::MIN,
+            }
+        } else {
+            self
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// Only [`PartialOrd`] is required, so this works for any index type, including floats. Note
+    /// that over integers, a range whose bounds are equal or adjacent contains no values.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1, end: 4 };
+    ///
+    /// assert!(!range.contains(&1));
+    /// assert!(range.contains(&2));
+    /// assert!(!range.contains(&4));
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1.0, end: 2.0 };
+    ///
+    /// assert!(range.contains(&1.5));
+    /// ```
+    ///
+    /// [`PartialOrd`]: core::cmp::PartialOrd
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        *item > self.start && *item < self.end
+    }
+
+    /// Returns the position of `value` relative to the range.
+    ///
+    /// A value equal to `start` is [`RangePosition::Below`], and a value equal to `end` is
+    /// [`RangePosition::Above`].
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, RangePosition};
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.locate(&1), RangePosition::Below);
+    /// assert_eq!(range.locate(&3), RangePosition::Within);
+    /// assert_eq!(range.locate(&4), RangePosition::Above);
+    /// ```
+    ///
+    /// [`RangePosition::Below`]: RangePosition::Below
+    /// [`RangePosition::Above`]: RangePosition::Above
+    #[inline]
+    pub fn locate(&self, value: &Idx) -> RangePosition
+    where
+        Idx: Ord,
+    {
+        if *value <= self.start {
+            RangePosition::Below
+        } else if *value >= self.end {
+            RangePosition::Above
+        } else {
+            RangePosition::Within
+        }
+    }
+
+    /// Returns `true` if the range contains no values.
+    ///
+    /// This is the case when `start` is not less than `end`, including when either bound is
+    /// incomparable, such as a floating-point `NaN`.
+    ///
+    /// This only compares the bounds, so over a discrete index type, a range whose bounds are
+    /// adjacent is not reported as empty even though no value lies between them. For the primitive
+    /// integers, use [`is_empty_discrete()`] to account for this.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert!(!RangeFromExclusiveToExclusive { start: 3.0, end: 4.0 }.is_empty());
+    /// assert!(RangeFromExclusiveToExclusive { start: 4.0, end: 4.0 }.is_empty());
+    ///
+    /// // No integers lie between 3 and 4, but the bounds are still ordered.
+    /// assert!(!RangeFromExclusiveToExclusive { start: 3, end: 4 }.is_empty());
+    /// ```
+    ///
+    /// [`is_empty_discrete()`]: RangeFromExclusiveToExclusive::is_empty_discrete
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    where
+        Idx: PartialOrd,
+    {
+        self.start.partial_cmp(&self.end) != Some(Ordering::Less)
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Returns `true` if the range contains no integers.
+    ///
+    /// Unlike [`is_empty()`], this accounts for adjacent bounds, such as in `{3, 4}`, between which
+    /// there are no integers.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert!(!RangeFromExclusiveToExclusive { start: 3, end: 5 }.is_empty_discrete());
+    /// assert!(RangeFromExclusiveToExclusive { start: 3, end: 4 }.is_empty_discrete());
+    /// assert!(RangeFromExclusiveToExclusive { start: 3, end: 3 }.is_empty_discrete());
+    /// ```
+    ///
+    /// [`is_empty()`]: RangeFromExclusiveToExclusive::is_empty
+    #[inline]
+    pub fn is_empty_discrete(&self) -> bool {
+        match self.start.checked_succ() {
+            Some(first) => first >= self.end,
+            None => true,
+        }
+    }
+
+    /// Returns the range in canonical form.
+    ///
+    /// Every range containing no integers, including one with adjacent bounds, is mapped to
+    /// `{MIN, MIN}`, where `MIN` is the minimum value of `Idx`, and non-empty ranges are returned
+    /// unchanged. Two normalized ranges are therefore equal, and hash equally, exactly when they
+    /// contain the same values.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 3u8, end: 4 }.normalize(),
+    ///     RangeFromExclusiveToExclusive { start: 9u8, end: 9 }.normalize()
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1u8, end: 3 }.normalize(),
+    ///     RangeFromExclusiveToExclusive { start: 1u8, end: 3 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn normalize(self) -> Self {
+        if self.is_empty_discrete() {
+            RangeFromExclusiveToExclusive {
+                start: Idx::MIN,
+                end: Idx::MIN,
+            }
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::path::{Path, PathBuf};
+    use std::vec::Vec;
+    use {
+        RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+        RangePosition,
+    };
+
+    #[test]
+    fn range_from_exclusive_contains() {
+        let range = RangeFromExclusive { start: 1u32 };
+
+        for value in 0..6 {
+            assert_eq!(range.contains(&value), value > 1);
+        }
+        assert!(range.contains(&u32::max_value()));
+    }
+
+    #[test]
+    fn range_from_exclusive_locate() {
+        let range = RangeFromExclusive { start: 1u32 };
+
+        assert_eq!(range.locate(&0), RangePosition::Below);
+        assert_eq!(range.locate(&1), RangePosition::Below);
+        assert_eq!(range.locate(&2), RangePosition::Within);
+        assert_eq!(range.locate(&u32::max_value()), RangePosition::Within);
+    }
+
+    #[test]
+    fn range_from_exclusive_contains_nan() {
+        assert!(!RangeFromExclusive { start: 0.0 }.contains(&::core::f64::NAN));
+        assert!(!RangeFromExclusive {
+            start: ::core::f64::NAN
+        }
+        .contains(&1.0));
+    }
+
+    #[test]
+    fn range_from_exclusive_contains_unsized() {
+        // `PathBuf` and `Path` can be compared with each other.
+        let range = RangeFromExclusive {
+            start: PathBuf::from("b"),
+        };
+
+        assert!(!range.contains(Path::new("a")));
+        assert!(!range.contains(Path::new("b")));
+        assert!(range.contains(Path::new("b/c")));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_contains() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 1u32,
+            end: 4,
+        };
+
+        for value in 0..6 {
+            assert_eq!(range.contains(&value), value > 1 && value <= 4);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_contains_bounds() {
+        let range = RangeFromExclusiveToInclusive {
+            start: -1i8,
+            end: 1,
+        };
+
+        assert!(!range.contains(&-1));
+        assert!(range.contains(&1));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_contains_empty() {
+        let range = RangeFromExclusiveToInclusive { start: 3u8, end: 3 };
+
+        assert!((0..=u8::max_value()).all(|value| !range.contains(&value)));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_contains_inverted() {
+        let range = RangeFromExclusiveToInclusive { start: 3u8, end: 1 };
+
+        assert!((0..=u8::max_value()).all(|value| !range.contains(&value)));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_locate() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 1u32,
+            end: 4,
+        };
+
+        assert_eq!(range.locate(&0), RangePosition::Below);
+        assert_eq!(range.locate(&1), RangePosition::Below);
+        assert_eq!(range.locate(&2), RangePosition::Within);
+        assert_eq!(range.locate(&4), RangePosition::Within);
+        assert_eq!(range.locate(&5), RangePosition::Above);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_locate_agrees_with_contains() {
+        for start in 0..10 {
+            for end in 0..10 {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                for value in 0..10 {
+                    assert_eq!(
+                        range.locate(&value) == RangePosition::Within,
+                        range.contains(&value)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_is_empty() {
+        assert!(!RangeFromExclusiveToInclusive { start: 1u8, end: 2 }.is_empty());
+        assert!(RangeFromExclusiveToInclusive { start: 2u8, end: 2 }.is_empty());
+        assert!(RangeFromExclusiveToInclusive { start: 3u8, end: 2 }.is_empty());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_is_empty_agrees_with_contains() {
+        for start in 0..=u8::max_value() {
+            for end in 0..=u8::max_value() {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                assert_eq!(
+                    range.is_empty(),
+                    (0..=u8::max_value()).all(|value| !range.contains(&value))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_is_empty_float() {
+        assert!(!RangeFromExclusiveToInclusive {
+            start: 1.0,
+            end: 1.5
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToInclusive {
+            start: 1.0,
+            end: 1.0
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToInclusive {
+            start: -0.0,
+            end: 0.0
+        }
+        .is_empty());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_is_empty_nan() {
+        assert!(RangeFromExclusiveToInclusive {
+            start: ::core::f64::NAN,
+            end: 1.0
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToInclusive {
+            start: 1.0,
+            end: ::core::f64::NAN
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToInclusive {
+            start: ::core::f64::NAN,
+            end: ::core::f64::NAN
+        }
+        .is_empty());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_is_empty_char() {
+        assert!(!RangeFromExclusiveToInclusive {
+            start: 'a',
+            end: 'b'
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToInclusive {
+            start: 'b',
+            end: 'b'
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToInclusive {
+            start: ::core::char::MAX,
+            end: 'a'
+        }
+        .is_empty());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_normalize() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 5u8, end: 3 }.normalize(),
+            RangeFromExclusiveToInclusive { start: 0, end: 0 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 5u8, end: 5 }.normalize(),
+            RangeFromExclusiveToInclusive { start: 0, end: 0 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 4u8, end: 5 }.normalize(),
+            RangeFromExclusiveToInclusive { start: 4, end: 5 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -1i8,
+                end: -1
+            }
+            .normalize(),
+            RangeFromExclusiveToInclusive {
+                start: i8::min_value(),
+                end: i8::min_value()
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_normalize_agrees_with_contains() {
+        let mut normalized = HashSet::new();
+        let mut value_sets = HashSet::new();
+        for start in 0..8u8 {
+            for end in 0..8u8 {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                normalized.insert(range.normalize());
+                value_sets.insert(
+                    (0..8u8)
+                        .filter(|value| range.contains(value))
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+
+        assert_eq!(normalized.len(), value_sets.len());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_contains_unsized() {
+        let range = RangeFromExclusiveToInclusive {
+            start: PathBuf::from("a"),
+            end: PathBuf::from("c"),
+        };
+
+        assert!(!range.contains(Path::new("a")));
+        assert!(range.contains(Path::new("a/b")));
+        assert!(range.contains(Path::new("c")));
+        assert!(!range.contains(Path::new("c/d")));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_contains() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 1u32,
+            end: 4,
+        };
+
+        for value in 0..6 {
+            assert_eq!(range.contains(&value), value > 1 && value < 4);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_contains_equal_bounds() {
+        let range = RangeFromExclusiveToExclusive { start: 3u8, end: 3 };
+
+        assert!((0..=u8::max_value()).all(|value| !range.contains(&value)));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_contains_adjacent_bounds() {
+        let range = RangeFromExclusiveToExclusive { start: 3u8, end: 4 };
+
+        assert!((0..=u8::max_value()).all(|value| !range.contains(&value)));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_contains_float() {
+        // Adjacent integer bounds still contain values between them.
+        let range = RangeFromExclusiveToExclusive {
+            start: 3.0f32,
+            end: 4.0,
+        };
+
+        assert!(!range.contains(&3.0));
+        assert!(range.contains(&3.5));
+        assert!(!range.contains(&4.0));
+        assert!(!range.contains(&::core::f32::NAN));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_locate() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 1u32,
+            end: 4,
+        };
+
+        assert_eq!(range.locate(&0), RangePosition::Below);
+        assert_eq!(range.locate(&1), RangePosition::Below);
+        assert_eq!(range.locate(&2), RangePosition::Within);
+        assert_eq!(range.locate(&3), RangePosition::Within);
+        assert_eq!(range.locate(&4), RangePosition::Above);
+        assert_eq!(range.locate(&5), RangePosition::Above);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_locate_agrees_with_contains() {
+        for start in 0..10 {
+            for end in 0..10 {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                for value in 0..10 {
+                    assert_eq!(
+                        range.locate(&value) == RangePosition::Within,
+                        range.contains(&value)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_locate_binary_search() {
+        // The first value of a sorted slice that is not below the range.
+        let values = [1, 3, 5, 7, 9, 11];
+        let range = RangeFromExclusiveToExclusive { start: 5, end: 10 };
+        let first = values
+            .binary_search_by(|value| match range.locate(value) {
+                RangePosition::Below => ::core::cmp::Ordering::Less,
+                _ => ::core::cmp::Ordering::Greater,
+            })
+            .unwrap_err();
+
+        assert_eq!(first, 3);
+        assert_eq!(range.locate(&values[first]), RangePosition::Within);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_is_empty() {
+        assert!(!RangeFromExclusiveToExclusive { start: 1u8, end: 3 }.is_empty());
+        assert!(!RangeFromExclusiveToExclusive { start: 1u8, end: 2 }.is_empty());
+        assert!(RangeFromExclusiveToExclusive { start: 2u8, end: 2 }.is_empty());
+        assert!(RangeFromExclusiveToExclusive { start: 3u8, end: 2 }.is_empty());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_is_empty_float() {
+        assert!(!RangeFromExclusiveToExclusive {
+            start: 1.0,
+            end: 2.0
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToExclusive {
+            start: 1.0,
+            end: 1.0
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToExclusive {
+            start: ::core::f64::NAN,
+            end: 1.0
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToExclusive {
+            start: 1.0,
+            end: ::core::f64::NAN
+        }
+        .is_empty());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_is_empty_discrete() {
+        assert!(!RangeFromExclusiveToExclusive { start: 1u8, end: 3 }.is_empty_discrete());
+        assert!(RangeFromExclusiveToExclusive { start: 1u8, end: 2 }.is_empty_discrete());
+        assert!(RangeFromExclusiveToExclusive { start: 2u8, end: 2 }.is_empty_discrete());
+        assert!(RangeFromExclusiveToExclusive { start: 3u8, end: 2 }.is_empty_discrete());
+        assert!(RangeFromExclusiveToExclusive {
+            start: u8::max_value(),
+            end: u8::max_value()
+        }
+        .is_empty_discrete());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_is_empty_discrete_agrees_with_contains() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in i8::min_value()..=i8::max_value() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                assert_eq!(
+                    range.is_empty_discrete(),
+                    (i8::min_value()..=i8::max_value()).all(|value| !range.contains(&value))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_normalize() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 5u8, end: 3 }.normalize(),
+            RangeFromExclusiveToExclusive { start: 0, end: 0 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 5u8, end: 6 }.normalize(),
+            RangeFromExclusiveToExclusive { start: 0, end: 0 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: u8::max_value(),
+                end: u8::max_value()
+            }
+            .normalize(),
+            RangeFromExclusiveToExclusive { start: 0, end: 0 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 4u8, end: 6 }.normalize(),
+            RangeFromExclusiveToExclusive { start: 4, end: 6 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_normalize_agrees_with_contains() {
+        let mut normalized = HashSet::new();
+        let mut value_sets = HashSet::new();
+        for start in 0..8u8 {
+            for end in 0..8u8 {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                normalized.insert(range.normalize());
+                value_sets.insert(
+                    (0..8u8)
+                        .filter(|value| range.contains(value))
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+
+        assert_eq!(normalized.len(), value_sets.len());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_contains_unsized() {
+        let range = RangeFromExclusiveToExclusive {
+            start: PathBuf::from("a"),
+            end: PathBuf::from("c"),
+        };
+
+        assert!(!range.contains(Path::new("a")));
+        assert!(range.contains(Path::new("b")));
+        assert!(!range.contains(Path::new("c")));
+    }
+}
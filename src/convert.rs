@@ -0,0 +1,1039 @@
+//! Conversions between the range types and other representations of ranges.
+
+use core::ops::Bound;
+use iter_bounds::Integer;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl<Idx> RangeFromExclusive<Idx> {
+    /// Returns the bounds of the range as a pair of [`Bound`]s.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    /// use std::ops::Bound;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 1 }.bounds(),
+    ///     (Bound::Excluded(&1), Bound::Unbounded)
+    /// );
+    /// ```
+    ///
+    /// [`Bound`]: core::ops::Bound
+    #[inline]
+    pub fn bounds(&self) -> (Bound<&Idx>, Bound<&Idx>) {
+        (Bound::Excluded(&self.start), Bound::Unbounded)
+    }
+
+    /// Converts the range into a pair of [`Bound`]s.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    /// use std::collections::BTreeMap;
+    /// use std::ops::Bound;
+    ///
+    /// let map = (0..5).map(|key| (key, key * 10)).collect::<BTreeMap<_, _>>();
+    /// let bounds = RangeFromExclusive { start: 2 }.into_bounds();
+    ///
+    /// assert_eq!(bounds, (Bound::Excluded(2), Bound::Unbounded));
+    /// assert!(map.range(bounds).map(|(_, &value)| value).eq(vec![30, 40]));
+    /// ```
+    ///
+    /// [`Bound`]: core::ops::Bound
+    #[inline]
+    pub fn into_bounds(self) -> (Bound<Idx>, Bound<Idx>) {
+        (Bound::Excluded(self.start), Bound::Unbounded)
+    }
+
+    /// Converts the range into its lower bound (exclusive).
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusive { start: 1 }.into_start(), 1);
+    /// ```
+    #[inline]
+    pub fn into_start(self) -> Idx {
+        self.start
+    }
+
+    /// Returns the range with its lower bound (exclusive) replaced by `start`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 1 }.with_start(2),
+    ///     RangeFromExclusive { start: 2 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_start(self, start: Idx) -> Self {
+        RangeFromExclusive { start }
+    }
+
+    /// Bounds the range above by `end` (exclusive).
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusive, RangeFromExclusiveToExclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 1 }.to(4),
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn to(self, end: Idx) -> RangeFromExclusiveToExclusive<Idx> {
+        RangeFromExclusiveToExclusive {
+            start: self.start,
+            end,
+        }
+    }
+
+    /// Bounds the range above by `end` (inclusive).
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusive, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 1 }.to_inclusive(4),
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_inclusive(self, end: Idx) -> RangeFromExclusiveToInclusive<Idx> {
+        RangeFromExclusiveToInclusive {
+            start: self.start,
+            end,
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx> {
+    /// Returns the bounds of the range as a pair of [`Bound`]s.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    /// use std::ops::Bound;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }.bounds(),
+    ///     (Bound::Excluded(&1), Bound::Included(&4))
+    /// );
+    /// ```
+    ///
+    /// [`Bound`]: core::ops::Bound
+    #[inline]
+    pub fn bounds(&self) -> (Bound<&Idx>, Bound<&Idx>) {
+        (Bound::Excluded(&self.start), Bound::Included(&self.end))
+    }
+
+    /// Converts the range into a pair of [`Bound`]s.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    /// use std::ops::Bound;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }.into_bounds(),
+    ///     (Bound::Excluded(1), Bound::Included(4))
+    /// );
+    /// ```
+    ///
+    /// [`Bound`]: core::ops::Bound
+    #[inline]
+    pub fn into_bounds(self) -> (Bound<Idx>, Bound<Idx>) {
+        (Bound::Excluded(self.start), Bound::Included(self.end))
+    }
+
+    /// Converts the range into its `(start, end)` bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 1, end: 4 }.into_inner(), (1, 4));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> (Idx, Idx) {
+        (self.start, self.end)
+    }
+
+    /// Returns the range with its lower bound (exclusive) replaced by `start`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }.with_start(2),
+    ///     RangeFromExclusiveToInclusive { start: 2, end: 4 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_start(self, start: Idx) -> Self {
+        RangeFromExclusiveToInclusive {
+            start,
+            end: self.end,
+        }
+    }
+
+    /// Returns the range with its upper bound (inclusive) replaced by `end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }.with_end(6),
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 6 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_end(self, end: Idx) -> Self {
+        RangeFromExclusiveToInclusive {
+            start: self.start,
+            end,
+        }
+    }
+
+    /// Returns the range with the same lower bound and the exclusive upper bound `end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }.with_exclusive_end(6),
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 6 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_exclusive_end(self, end: Idx) -> RangeFromExclusiveToExclusive<Idx> {
+        RangeFromExclusiveToExclusive {
+            start: self.start,
+            end,
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx> {
+    /// Returns the bounds of the range as a pair of [`Bound`]s.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    /// use std::ops::Bound;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }.bounds(),
+    ///     (Bound::Excluded(&1), Bound::Excluded(&4))
+    /// );
+    /// ```
+    ///
+    /// [`Bound`]: core::ops::Bound
+    #[inline]
+    pub fn bounds(&self) -> (Bound<&Idx>, Bound<&Idx>) {
+        (Bound::Excluded(&self.start), Bound::Excluded(&self.end))
+    }
+
+    /// Converts the range into a pair of [`Bound`]s.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    /// use std::ops::Bound;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }.into_bounds(),
+    ///     (Bound::Excluded(1), Bound::Excluded(4))
+    /// );
+    /// ```
+    ///
+    /// [`Bound`]: core::ops::Bound
+    #[inline]
+    pub fn into_bounds(self) -> (Bound<Idx>, Bound<Idx>) {
+        (Bound::Excluded(self.start), Bound::Excluded(self.end))
+    }
+
+    /// Converts the range into its `(start, end)` bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 1, end: 4 }.into_inner(), (1, 4));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> (Idx, Idx) {
+        (self.start, self.end)
+    }
+
+    /// Returns the range with its lower bound (exclusive) replaced by `start`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }.with_start(2),
+    ///     RangeFromExclusiveToExclusive { start: 2, end: 4 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_start(self, start: Idx) -> Self {
+        RangeFromExclusiveToExclusive {
+            start,
+            end: self.end,
+        }
+    }
+
+    /// Returns the range with its upper bound (exclusive) replaced by `end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }.with_end(6),
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 6 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_end(self, end: Idx) -> Self {
+        RangeFromExclusiveToExclusive {
+            start: self.start,
+            end,
+        }
+    }
+
+    /// Returns the range with the same lower bound and the inclusive upper bound `end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }.with_inclusive_end(6),
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 6 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_inclusive_end(self, end: Idx) -> RangeFromExclusiveToInclusive<Idx> {
+        RangeFromExclusiveToInclusive {
+            start: self.start,
+            end,
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Bounds the range so that it contains the next `n` values after `start`.
+    ///
+    /// Returns `None` if `start + n` overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusive, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 10 }.take_values(3),
+    ///     Some(RangeFromExclusiveToInclusive { start: 10, end: 13 })
+    /// );
+    /// assert_eq!(RangeFromExclusive { start: 250u8 }.take_values(10), None);
+    /// ```
+    #[inline]
+    pub fn take_values(self, n: Idx) -> Option<RangeFromExclusiveToInclusive<Idx>> {
+        self.start
+            .checked_add(n)
+            .map(|end| RangeFromExclusiveToInclusive {
+                start: self.start,
+                end,
+            })
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Converts the range into the range with an exclusive end containing the same values.
+    ///
+    /// The `start` is unchanged, and the `end` is incremented by one.
+    ///
+    /// Returns `None` if `end` is the maximum value of `Idx`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }.to_exclusive_end(),
+    ///     Some(RangeFromExclusiveToExclusive { start: 1, end: 5 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: u64::max_value() }.to_exclusive_end(),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_exclusive_end(self) -> Option<RangeFromExclusiveToExclusive<Idx>> {
+        self.end
+            .checked_succ()
+            .map(|end| RangeFromExclusiveToExclusive {
+                start: self.start,
+                end,
+            })
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Converts the range into the range with an inclusive end containing the same values.
+    ///
+    /// The `start` is unchanged, and the `end` is decremented by one.
+    ///
+    /// Returns `None` if `start >= end`. Such a range is empty, and decrementing its `end` would
+    /// produce an inverted range.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 5 }.to_inclusive_end(),
+    ///     Some(RangeFromExclusiveToInclusive { start: 1, end: 4 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 3, end: 3 }.to_inclusive_end(),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_inclusive_end(self) -> Option<RangeFromExclusiveToInclusive<Idx>> {
+        if self.start >= self.end {
+            return None;
+        }
+        // `start < end`, so decrementing `end` cannot overflow.
+        self.end
+            .checked_pred()
+            .map(|end| RangeFromExclusiveToInclusive {
+                start: self.start,
+                end,
+            })
+    }
+}
+
+/// Converts a lower bound into a range.
+///
+/// The inverse conversion, from a range into its `start` bound, cannot be implemented as
+/// `From<RangeFromExclusive<Idx>> for Idx`, since the orphan rules forbid implementing a foreign
+/// trait for an arbitrary type. Access the `start` field instead.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusive;
+///
+/// fn after<R>(cursor: R) -> RangeFromExclusive<u64>
+/// where
+///     R: Into<RangeFromExclusive<u64>>,
+/// {
+///     cursor.into()
+/// }
+///
+/// assert_eq!(after(5), RangeFromExclusive { start: 5 });
+/// ```
+impl<Idx> From<Idx> for RangeFromExclusive<Idx> {
+    #[inline]
+    fn from(start: Idx) -> Self {
+        RangeFromExclusive { start }
+    }
+}
+
+/// Converts a `(start, end)` pair into a range.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusiveToInclusive;
+///
+/// assert_eq!(
+///     RangeFromExclusiveToInclusive::from((1, 4)),
+///     RangeFromExclusiveToInclusive { start: 1, end: 4 }
+/// );
+/// ```
+impl<Idx> From<(Idx, Idx)> for RangeFromExclusiveToInclusive<Idx> {
+    #[inline]
+    fn from((start, end): (Idx, Idx)) -> Self {
+        RangeFromExclusiveToInclusive { start, end }
+    }
+}
+
+/// Converts a `(start, end)` pair into a range.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusiveToExclusive;
+///
+/// assert_eq!(
+///     RangeFromExclusiveToExclusive::from((1, 4)),
+///     RangeFromExclusiveToExclusive { start: 1, end: 4 }
+/// );
+/// ```
+impl<Idx> From<(Idx, Idx)> for RangeFromExclusiveToExclusive<Idx> {
+    #[inline]
+    fn from((start, end): (Idx, Idx)) -> Self {
+        RangeFromExclusiveToExclusive { start, end }
+    }
+}
+
+/// Converts a range into its `(start, end)` pair.
+///
+/// This requires Rust 1.41 or newer.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusiveToInclusive;
+///
+/// let (start, end) = RangeFromExclusiveToInclusive { start: 1, end: 4 }.into();
+///
+/// assert_eq!((start, end), (1, 4));
+/// ```
+#[cfg(tuple_from_range)]
+impl<Idx> From<RangeFromExclusiveToInclusive<Idx>> for (Idx, Idx) {
+    #[inline]
+    fn from(range: RangeFromExclusiveToInclusive<Idx>) -> Self {
+        (range.start, range.end)
+    }
+}
+
+/// Converts a range into its `(start, end)` pair.
+///
+/// This requires Rust 1.41 or newer.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusiveToExclusive;
+///
+/// let (start, end) = RangeFromExclusiveToExclusive { start: 1, end: 4 }.into();
+///
+/// assert_eq!((start, end), (1, 4));
+/// ```
+#[cfg(tuple_from_range)]
+impl<Idx> From<RangeFromExclusiveToExclusive<Idx>> for (Idx, Idx) {
+    #[inline]
+    fn from(range: RangeFromExclusiveToExclusive<Idx>) -> Self {
+        (range.start, range.end)
+    }
+}
+
+/// Converts a range into its pair of [`Bound`]s.
+///
+/// This is equivalent to [`RangeFromExclusive::into_bounds()`], and requires Rust 1.41 or newer.
+///
+/// # Example
+/// ```
+/// use more_ranges::RangeFromExclusive;
+/// use std::collections::BTreeSet;
+/// use std::ops::Bound;
+///
+/// let set = (0..5).collect::<BTreeSet<_>>();
+/// let bounds: (Bound<_>, Bound<_>) = RangeFromExclusive { start: 2 }.into();
+///
+/// assert!(set.range(bounds).eq(&[3, 4]));
+/// ```
+///
+/// [`Bound`]: core::ops::Bound
+/// [`RangeFromExclusive::into_bounds()`]: RangeFromExclusive::into_bounds
+#[cfg(tuple_from_range)]
+impl<Idx> From<RangeFromExclusive<Idx>> for (Bound<Idx>, Bound<Idx>) {
+    #[inline]
+    fn from(range: RangeFromExclusive<Idx>) -> Self {
+        range.into_bounds()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(tuple_from_range)]
+    use core::ops::Bound;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use std::collections::BTreeSet;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+    use {
+        iter_bounds, RangeFromExclusive, RangeFromExclusiveToExclusive,
+        RangeFromExclusiveToInclusive,
+    };
+
+    #[test]
+    fn range_from_exclusive_bounds() {
+        assert_eq!(
+            RangeFromExclusive { start: 1 }.bounds(),
+            (Excluded(&1), Unbounded)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_into_bounds() {
+        assert_eq!(
+            RangeFromExclusive {
+                start: "b".to_string()
+            }
+            .into_bounds(),
+            (Excluded("b".to_string()), Unbounded)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_with_start() {
+        assert_eq!(
+            RangeFromExclusive {
+                start: "a".to_string()
+            }
+            .with_start("b".to_string()),
+            RangeFromExclusive {
+                start: "b".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to() {
+        assert_eq!(
+            RangeFromExclusive { start: 'a' }.to('d'),
+            RangeFromExclusiveToExclusive {
+                start: 'a',
+                end: 'd'
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive() {
+        assert_eq!(
+            RangeFromExclusive { start: 'a' }.to_inclusive('d'),
+            RangeFromExclusiveToInclusive {
+                start: 'a',
+                end: 'd'
+            }
+        );
+    }
+
+    #[cfg(impl_index)]
+    #[test]
+    fn range_from_exclusive_to_index() {
+        let slice = [0, 1, 2, 3, 4, 5];
+        let range = RangeFromExclusive { start: 1 };
+
+        assert_eq!(slice[range.to(4)], [2, 3]);
+        assert_eq!(slice[range.to_inclusive(4)], [2, 3, 4]);
+        assert_eq!(slice[range.to(2)], [0; 0]);
+        assert_eq!(slice[range.to_inclusive(1)], [0; 0]);
+    }
+
+    #[cfg(impl_index)]
+    #[test]
+    fn range_from_exclusive_to_inclusive_index_pages() {
+        let slice = [0, 10, 20, 30, 40, 50, 60];
+        let limit = 2;
+        let mut cursor = 0;
+        let mut pages = Vec::new();
+
+        while cursor + limit < slice.len() {
+            pages.push(&slice[RangeFromExclusive { start: cursor }.to_inclusive(cursor + limit)]);
+            cursor += limit;
+        }
+
+        assert_eq!(pages, [[10, 20], [30, 40], [50, 60]]);
+    }
+
+    #[test]
+    fn range_from_exclusive_take_values() {
+        let range = assert_some!(RangeFromExclusive { start: 10u32 }.take_values(3));
+
+        assert_eq!(range, RangeFromExclusiveToInclusive { start: 10, end: 13 });
+        assert!(iter_bounds(range).eq(11..=13));
+    }
+
+    #[test]
+    fn range_from_exclusive_take_values_zero() {
+        let range = assert_some!(RangeFromExclusive { start: 10u32 }.take_values(0));
+
+        assert_eq!(iter_bounds(range).count(), 0);
+    }
+
+    #[test]
+    fn range_from_exclusive_take_values_at_maximum() {
+        assert_some_eq!(
+            RangeFromExclusive { start: 127i8 }.take_values(0),
+            RangeFromExclusiveToInclusive {
+                start: 127,
+                end: 127
+            }
+        );
+        assert_some_eq!(
+            RangeFromExclusive { start: 126i8 }.take_values(1),
+            RangeFromExclusiveToInclusive {
+                start: 126,
+                end: 127
+            }
+        );
+        assert_none!(RangeFromExclusive { start: 126i8 }.take_values(2));
+    }
+
+    #[test]
+    fn range_from_exclusive_take_values_chunks() {
+        let mut start = RangeFromExclusive { start: 0u8 };
+        let mut chunks = Vec::new();
+
+        while let Some(chunk) = start.take_values(100) {
+            chunks.push(chunk);
+            start = RangeFromExclusive { start: chunk.end };
+        }
+
+        assert_eq!(
+            chunks,
+            [
+                RangeFromExclusiveToInclusive { start: 0, end: 100 },
+                RangeFromExclusiveToInclusive {
+                    start: 100,
+                    end: 200
+                },
+            ]
+        );
+    }
+
+    #[cfg(tuple_from_range)]
+    #[test]
+    fn range_from_exclusive_into_bound_pair() {
+        assert_eq!(
+            <(Bound<u8>, Bound<u8>)>::from(RangeFromExclusive { start: 1 }),
+            (Excluded(1), Unbounded)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_bounds() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }.bounds(),
+            (Excluded(&1), Included(&4))
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_into_bounds() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: "a".to_string(),
+                end: "d".to_string()
+            }
+            .into_bounds(),
+            (Excluded("a".to_string()), Included("d".to_string()))
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_with_bounds() {
+        let range = RangeFromExclusiveToInclusive {
+            start: "a".to_string(),
+            end: "d".to_string(),
+        };
+
+        assert_eq!(
+            range.clone().with_start("b".to_string()),
+            RangeFromExclusiveToInclusive {
+                start: "b".to_string(),
+                end: "d".to_string()
+            }
+        );
+        assert_eq!(
+            range.clone().with_end("c".to_string()),
+            RangeFromExclusiveToInclusive {
+                start: "a".to_string(),
+                end: "c".to_string()
+            }
+        );
+        assert_eq!(
+            range.with_exclusive_end("e".to_string()),
+            RangeFromExclusiveToExclusive {
+                start: "a".to_string(),
+                end: "e".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_bounds() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }.bounds(),
+            (Excluded(&1), Excluded(&4))
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_with_bounds() {
+        let range = RangeFromExclusiveToExclusive {
+            start: "a".to_string(),
+            end: "d".to_string(),
+        };
+
+        assert_eq!(
+            range.clone().with_start("b".to_string()),
+            RangeFromExclusiveToExclusive {
+                start: "b".to_string(),
+                end: "d".to_string()
+            }
+        );
+        assert_eq!(
+            range.clone().with_end("c".to_string()),
+            RangeFromExclusiveToExclusive {
+                start: "a".to_string(),
+                end: "c".to_string()
+            }
+        );
+        assert_eq!(
+            range.with_inclusive_end("c".to_string()),
+            RangeFromExclusiveToInclusive {
+                start: "a".to_string(),
+                end: "c".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_into_bounds() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }.into_bounds(),
+            (Excluded(1), Excluded(4))
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_into_start() {
+        assert_eq!(
+            RangeFromExclusive {
+                start: "b".to_string()
+            }
+            .into_start(),
+            "b"
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_into_inner() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 'a',
+                end: 'd'
+            }
+            .into_inner(),
+            ('a', 'd')
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_into_inner() {
+        let (start, end) = RangeFromExclusiveToExclusive {
+            start: "a".to_string(),
+            end: "d".to_string(),
+        }
+        .into_inner();
+
+        assert_eq!((start, end), ("a".to_string(), "d".to_string()));
+    }
+
+    #[test]
+    fn into_inner_generic() {
+        fn width<R>(range: R) -> u32
+        where
+            R: Into<RangeFromExclusiveToExclusive<u32>>,
+        {
+            let (start, end) = range.into().into_inner();
+            end - start
+        }
+
+        assert_eq!(width((3, 8)), 5);
+    }
+
+    #[test]
+    fn bounds_btree_set_range() {
+        let set = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|key| key.to_string())
+            .collect::<BTreeSet<String>>();
+        let range = RangeFromExclusiveToInclusive {
+            start: "a".to_string(),
+            end: "d".to_string(),
+        };
+
+        assert_eq!(
+            set.range::<String, _>(range.bounds()).collect::<Vec<_>>(),
+            ["b", "c", "d"]
+        );
+        assert_eq!(
+            set.range(range.into_bounds()).collect::<Vec<_>>(),
+            ["b", "c", "d"]
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_to_exclusive_end() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }.to_exclusive_end(),
+            RangeFromExclusiveToExclusive { start: 1, end: 5 }
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive { start: 4, end: 4 }.to_exclusive_end(),
+            RangeFromExclusiveToExclusive { start: 4, end: 5 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_to_exclusive_end_overflow() {
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: 0,
+            end: u64::max_value()
+        }
+        .to_exclusive_end());
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: i8::max_value(),
+            end: i8::max_value()
+        }
+        .to_exclusive_end());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_to_exclusive_end_values() {
+        for start in 0..8u8 {
+            for end in 0..8 {
+                let inclusive = RangeFromExclusiveToInclusive { start, end };
+                let exclusive = assert_some!(inclusive.to_exclusive_end());
+                assert!(iter_bounds(inclusive).eq(iter_bounds(exclusive)));
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_inclusive_end() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive { start: 1, end: 5 }.to_inclusive_end(),
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive { start: 3, end: 4 }.to_inclusive_end(),
+            RangeFromExclusiveToInclusive { start: 3, end: 3 }
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: i8::min_value(),
+                end: i8::max_value()
+            }
+            .to_inclusive_end(),
+            RangeFromExclusiveToInclusive {
+                start: i8::min_value(),
+                end: i8::max_value() - 1
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_inclusive_end_empty() {
+        assert_none!(RangeFromExclusiveToExclusive { start: 3, end: 3 }.to_inclusive_end());
+        assert_none!(RangeFromExclusiveToExclusive { start: 0u8, end: 0 }.to_inclusive_end());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_inclusive_end_inverted() {
+        assert_none!(RangeFromExclusiveToExclusive { start: 5, end: 3 }.to_inclusive_end());
+    }
+
+    #[test]
+    fn exclusive_end_round_trip() {
+        for start in 0..8u8 {
+            for end in 0..8 {
+                let exclusive = RangeFromExclusiveToExclusive { start, end };
+                if let Some(inclusive) = exclusive.to_inclusive_end() {
+                    assert!(iter_bounds(inclusive).eq(iter_bounds(exclusive)));
+                    assert_some_eq!(inclusive.to_exclusive_end(), exclusive);
+                }
+
+                let inclusive = RangeFromExclusiveToInclusive { start, end };
+                if start <= end {
+                    let exclusive = assert_some!(inclusive.to_exclusive_end());
+                    assert_some_eq!(exclusive.to_inclusive_end(), inclusive);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_from_start() {
+        assert_eq!(
+            RangeFromExclusive::from(5u64),
+            RangeFromExclusive { start: 5 }
+        );
+    }
+
+    #[test]
+    fn start_into_range_from_exclusive() {
+        let range: RangeFromExclusive<&str> = "cursor".into();
+
+        assert_eq!(range.start, "cursor");
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_tuple() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::from((1, 4)),
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_tuple() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive::from((1, 4)),
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[cfg(tuple_from_range)]
+    #[test]
+    fn range_from_exclusive_to_inclusive_tuple_round_trip() {
+        let range = RangeFromExclusiveToInclusive::from((-3i64, 7));
+        assert_eq!(<(i64, i64)>::from(range), (-3, 7));
+
+        let range = RangeFromExclusiveToInclusive::from(('a', 'z'));
+        assert_eq!(<(char, char)>::from(range), ('a', 'z'));
+    }
+
+    #[cfg(tuple_from_range)]
+    #[test]
+    fn range_from_exclusive_to_exclusive_tuple_round_trip() {
+        let range = RangeFromExclusiveToExclusive::from((u8::min_value(), u8::max_value()));
+        assert_eq!(<(u8, u8)>::from(range), (0, 255));
+
+        let range = RangeFromExclusiveToExclusive::from(('\0', ::core::char::MAX));
+        assert_eq!(<(char, char)>::from(range), ('\0', ::core::char::MAX));
+    }
+
+    #[cfg(tuple_from_range)]
+    #[test]
+    fn tuple_into_range() {
+        let range: RangeFromExclusiveToExclusive<char> = ('a', 'e').into();
+        let (start, end) = range.into();
+
+        assert_eq!((start, end), ('a', 'e'));
+    }
+}
@@ -0,0 +1,335 @@
+//! Draining and replacing ranges of `Vec`s and `String`s.
+//!
+//! The standard library's `drain` and `replace_range` methods accept any [`RangeBounds`], but they
+//! panic on a range whose bounds are both excluded and equal, even though such a range is simply
+//! empty. The methods here resolve the range first, so empty ranges are always accepted.
+//!
+//! [`RangeBounds`]: core::ops::RangeBounds
+
+use alloc::string::{self, String};
+use alloc::vec::{self, Vec};
+use resolve::Resolve;
+
+/// Extension trait for draining a range of a [`Vec`].
+///
+/// [`Vec`]: alloc::vec::Vec
+pub trait VecExclusiveExt<T> {
+    /// Removes the elements within `range` from the `Vec`, returning them as an iterator.
+    ///
+    /// This behaves the same as [`Vec::drain`], except that empty ranges, including ranges whose
+    /// excluded bounds are equal, return an empty iterator rather than panicking.
+    ///
+    /// `range` may be any of [`RangeFromExclusive<usize>`], [`RangeFromExclusiveToExclusive<usize>`],
+    /// or [`RangeFromExclusiveToInclusive<usize>`].
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds of the `Vec`, with the same messages as slice indexing.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, VecExclusiveExt};
+    ///
+    /// let mut v = vec![1, 2, 3, 4];
+    ///
+    /// assert_eq!(
+    ///     v.drain_exclusive(RangeFromExclusiveToExclusive { start: 2, end: 2 }).count(),
+    ///     0
+    /// );
+    /// assert_eq!(
+    ///     v.drain_exclusive(RangeFromExclusiveToExclusive { start: 0, end: 3 })
+    ///         .collect::<Vec<_>>(),
+    ///     [2, 3]
+    /// );
+    /// assert_eq!(v, [1, 4]);
+    /// ```
+    ///
+    /// [`Vec::drain`]: alloc::vec::Vec::drain
+    /// [`RangeFromExclusive<usize>`]: ::RangeFromExclusive
+    /// [`RangeFromExclusiveToExclusive<usize>`]: ::RangeFromExclusiveToExclusive
+    /// [`RangeFromExclusiveToInclusive<usize>`]: ::RangeFromExclusiveToInclusive
+    fn drain_exclusive<R>(&mut self, range: R) -> vec::Drain<'_, T>
+    where
+        R: Resolve;
+}
+
+impl<T> VecExclusiveExt<T> for Vec<T> {
+    #[inline]
+    fn drain_exclusive<R>(&mut self, range: R) -> vec::Drain<'_, T>
+    where
+        R: Resolve,
+    {
+        let range = range.resolve(self.len());
+        self.drain(range)
+    }
+}
+
+/// Extension trait for draining and replacing a range of a [`String`].
+///
+/// [`String`]: alloc::string::String
+pub trait StringExclusiveExt {
+    /// Removes the bytes within `range` from the `String`, returning them as an iterator of
+    /// `char`s.
+    ///
+    /// This behaves the same as [`String::drain`], except that empty ranges, including ranges
+    /// whose excluded bounds are equal, return an empty iterator rather than panicking.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds of the `String`, with the same messages as slice
+    /// indexing, or if either end of the resolved range does not lie on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToInclusive, StringExclusiveExt};
+    ///
+    /// let mut s = String::from("hello");
+    ///
+    /// assert_eq!(
+    ///     s.drain_exclusive(RangeFromExclusiveToInclusive { start: 0, end: 2 })
+    ///         .collect::<String>(),
+    ///     "el"
+    /// );
+    /// assert_eq!(s, "hlo");
+    /// ```
+    ///
+    /// [`String::drain`]: alloc::string::String::drain
+    fn drain_exclusive<R>(&mut self, range: R) -> string::Drain<'_>
+    where
+        R: Resolve;
+
+    /// Replaces the bytes within `range` with `replace_with`.
+    ///
+    /// This behaves the same as [`String::replace_range`], except that empty ranges, including
+    /// ranges whose excluded bounds are equal, insert `replace_with` at the range's position rather
+    /// than panicking.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds of the `String`, with the same messages as slice
+    /// indexing, or if either end of the resolved range does not lie on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, StringExclusiveExt};
+    ///
+    /// let mut s = String::from("hello");
+    /// s.replace_range_exclusive(RangeFromExclusiveToExclusive { start: 0, end: 4 }, "ipp");
+    ///
+    /// assert_eq!(s, "hippo");
+    /// ```
+    ///
+    /// [`String::replace_range`]: alloc::string::String::replace_range
+    fn replace_range_exclusive<R>(&mut self, range: R, replace_with: &str)
+    where
+        R: Resolve;
+}
+
+impl StringExclusiveExt for String {
+    #[inline]
+    fn drain_exclusive<R>(&mut self, range: R) -> string::Drain<'_>
+    where
+        R: Resolve,
+    {
+        let range = range.resolve(self.len());
+        self.drain(range)
+    }
+
+    #[inline]
+    fn replace_range_exclusive<R>(&mut self, range: R, replace_with: &str)
+    where
+        R: Resolve,
+    {
+        let range = range.resolve(self.len());
+        self.replace_range(range, replace_with);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::String;
+    use std::vec::Vec;
+    use {
+        RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+        StringExclusiveExt, VecExclusiveExt,
+    };
+
+    #[test]
+    fn vec_drain_range_from_exclusive() {
+        let mut v = (0..5).collect::<Vec<_>>();
+
+        assert_eq!(
+            v.drain_exclusive(RangeFromExclusive { start: 1 })
+                .collect::<Vec<_>>(),
+            [2, 3, 4]
+        );
+        assert_eq!(v, [0, 1]);
+    }
+
+    #[test]
+    fn vec_drain_range_from_exclusive_to_exclusive() {
+        let mut v = (0..5).collect::<Vec<_>>();
+
+        assert_eq!(
+            v.drain_exclusive(RangeFromExclusiveToExclusive { start: 0, end: 3 })
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(v, [0, 3, 4]);
+    }
+
+    #[test]
+    fn vec_drain_range_from_exclusive_to_inclusive() {
+        let mut v = (0..5).collect::<Vec<_>>();
+
+        assert_eq!(
+            v.drain_exclusive(RangeFromExclusiveToInclusive { start: 0, end: 3 })
+                .collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert_eq!(v, [0, 4]);
+    }
+
+    #[test]
+    fn vec_drain_equal_excluded_at_start() {
+        let mut v = (0..5).collect::<Vec<_>>();
+
+        assert_eq!(
+            v.drain_exclusive(RangeFromExclusiveToExclusive { start: 0, end: 0 })
+                .count(),
+            0
+        );
+        assert_eq!(v, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn vec_drain_equal_excluded_mid_buffer() {
+        let mut v = (0..5).collect::<Vec<_>>();
+
+        assert_eq!(
+            v.drain_exclusive(RangeFromExclusiveToExclusive { start: 2, end: 2 })
+                .count(),
+            0
+        );
+        assert_eq!(v, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn vec_drain_equal_excluded_at_end() {
+        let mut v = (0..5).collect::<Vec<_>>();
+
+        assert_eq!(
+            v.drain_exclusive(RangeFromExclusiveToExclusive { start: 5, end: 5 })
+                .count(),
+            0
+        );
+        assert_eq!(v, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn vec_drain_out_of_bounds() {
+        let mut v = (0..5).collect::<Vec<_>>();
+
+        v.drain_exclusive(RangeFromExclusiveToExclusive { start: 0, end: 6 });
+    }
+
+    #[test]
+    fn string_drain_range_from_exclusive() {
+        let mut s = String::from("hello");
+
+        assert_eq!(
+            s.drain_exclusive(RangeFromExclusive { start: 2 })
+                .collect::<String>(),
+            "lo"
+        );
+        assert_eq!(s, "hel");
+    }
+
+    #[test]
+    fn string_drain_equal_excluded_at_start() {
+        let mut s = String::from("hello");
+
+        assert_eq!(
+            s.drain_exclusive(RangeFromExclusiveToExclusive { start: 0, end: 0 })
+                .count(),
+            0
+        );
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    fn string_drain_equal_excluded_mid_buffer() {
+        let mut s = String::from("hello");
+
+        assert_eq!(
+            s.drain_exclusive(RangeFromExclusiveToExclusive { start: 3, end: 3 })
+                .count(),
+            0
+        );
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    fn string_drain_equal_excluded_at_end() {
+        let mut s = String::from("hello");
+
+        assert_eq!(
+            s.drain_exclusive(RangeFromExclusiveToExclusive { start: 5, end: 5 })
+                .count(),
+            0
+        );
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "range start index 7 out of range for slice of length 5")]
+    fn string_drain_out_of_bounds() {
+        let mut s = String::from("hello");
+
+        s.drain_exclusive(RangeFromExclusive { start: 6 });
+    }
+
+    #[test]
+    fn string_replace_range_from_exclusive_to_inclusive() {
+        let mut s = String::from("hello");
+        s.replace_range_exclusive(RangeFromExclusiveToInclusive { start: 0, end: 3 }, "ipp");
+
+        assert_eq!(s, "hippo");
+    }
+
+    #[test]
+    fn string_replace_range_equal_excluded_at_start() {
+        let mut s = String::from("hello");
+        s.replace_range_exclusive(RangeFromExclusiveToExclusive { start: 0, end: 0 }, "oh, ");
+
+        assert_eq!(s, "oh, hello");
+    }
+
+    #[test]
+    fn string_replace_range_equal_excluded_mid_buffer() {
+        let mut s = String::from("hello");
+        s.replace_range_exclusive(RangeFromExclusiveToExclusive { start: 2, end: 2 }, "-");
+
+        assert_eq!(s, "he-llo");
+    }
+
+    #[test]
+    fn string_replace_range_equal_excluded_at_end() {
+        let mut s = String::from("hello");
+        s.replace_range_exclusive(RangeFromExclusiveToExclusive { start: 5, end: 5 }, "!");
+
+        assert_eq!(s, "hello!");
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn string_replace_range_out_of_bounds() {
+        let mut s = String::from("hello");
+        s.replace_range_exclusive(RangeFromExclusiveToInclusive { start: 0, end: 5 }, "");
+    }
+
+    #[test]
+    #[should_panic(expected = "character boundary")]
+    fn string_replace_range_not_char_boundary() {
+        let mut s = String::from("héllo");
+        s.replace_range_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 3 }, "");
+    }
+}
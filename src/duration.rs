@@ -0,0 +1,569 @@
+//! Stepping over ranges of [`Duration`]s.
+//!
+//! [`Duration`] has nanosecond resolution, so every value here is exact; no rounding ever occurs.
+//!
+//! [`Duration`]: core::time::Duration
+
+use core::ops::Bound;
+use core::time::Duration;
+use core::{iter, usize};
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Returns the total number of nanoseconds in `duration`.
+///
+/// This is the same as `Duration::as_nanos()`, which was stabilized in Rust 1.33.
+#[inline]
+fn nanos(duration: Duration) -> u128 {
+    duration.as_secs() as u128 * NANOS_PER_SEC + duration.subsec_nanos() as u128
+}
+
+#[cold]
+#[inline(never)]
+fn zero_step_fail() -> ! {
+    panic!("attempted to step by a zero duration");
+}
+
+/// An iterator over the [`Duration`]s in a range, at a fixed step.
+///
+/// This is created by the `iter_by()` method on the ranges over [`Duration`]. The first value
+/// yielded is `start + step`. Iteration ends at the end of the range, or once the next value would
+/// overflow [`Duration`].
+///
+/// [`Duration`]: core::time::Duration
+#[derive(Clone, Debug)]
+pub struct DurationSteps {
+    next: Option<Duration>,
+    step: Duration,
+    end: Bound<Duration>,
+}
+
+impl DurationSteps {
+    #[inline]
+    fn new(start: Duration, step: Duration, end: Bound<Duration>) -> Self {
+        if step == Duration::new(0, 0) {
+            zero_step_fail();
+        }
+        DurationSteps {
+            next: start.checked_add(step),
+            step,
+            end,
+        }
+    }
+
+    /// Returns the number of values remaining, as a `u128`.
+    fn remaining(&self) -> u128 {
+        let next = match self.next {
+            Some(next) => next,
+            None => return 0,
+        };
+        let step = nanos(self.step);
+        match self.end {
+            Bound::Included(end) if next <= end => (nanos(end) - nanos(next)) / step + 1,
+            Bound::Excluded(end) if next < end => (nanos(end) - nanos(next) + step - 1) / step,
+            Bound::Included(_) | Bound::Excluded(_) => 0,
+            // Every value up to the maximum `Duration` is yielded.
+            Bound::Unbounded => {
+                (nanos(Duration::new(u64::max_value(), 999_999_999)) - nanos(next)) / step + 1
+            }
+        }
+    }
+}
+
+impl Iterator for DurationSteps {
+    type Item = Duration;
+
+    #[inline]
+    fn next(&mut self) -> Option<Duration> {
+        let current = self.next?;
+        let contained = match self.end {
+            Bound::Included(end) => current <= end,
+            Bound::Excluded(end) => current < end,
+            Bound::Unbounded => true,
+        };
+        if contained {
+            self.next = current.checked_add(self.step);
+            Some(current)
+        } else {
+            self.next = None;
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        if remaining > usize::MAX as u128 {
+            (usize::MAX, None)
+        } else {
+            (remaining as usize, Some(remaining as usize))
+        }
+    }
+}
+
+impl iter::FusedIterator for DurationSteps {}
+
+impl RangeFromExclusive<Duration> {
+    /// Returns an iterator over the values in the range, beginning at `start + step` and
+    /// continuing in increments of `step` until the maximum `Duration` would be exceeded.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    /// use std::time::Duration;
+    ///
+    /// let mut steps = RangeFromExclusive {
+    ///     start: Duration::from_secs(1),
+    /// }
+    /// .iter_by(Duration::from_millis(500));
+    ///
+    /// assert_eq!(steps.next(), Some(Duration::from_millis(1500)));
+    /// assert_eq!(steps.next(), Some(Duration::from_secs(2)));
+    /// ```
+    #[inline]
+    pub fn iter_by(&self, step: Duration) -> DurationSteps {
+        DurationSteps::new(self.start, step, Bound::Unbounded)
+    }
+}
+
+impl RangeFromExclusiveToInclusive<Duration> {
+    /// Returns an iterator over the values in the range, beginning at `start + step` and
+    /// continuing in increments of `step` for as long as they do not exceed `end`.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    /// use std::time::Duration;
+    ///
+    /// let buckets = RangeFromExclusiveToInclusive {
+    ///     start: Duration::from_millis(1),
+    ///     end: Duration::from_secs(1),
+    /// }
+    /// .iter_by(Duration::from_millis(1));
+    ///
+    /// assert_eq!(buckets.clone().next(), Some(Duration::from_millis(2)));
+    /// assert_eq!(buckets.last(), Some(Duration::from_secs(1)));
+    /// ```
+    #[inline]
+    pub fn iter_by(&self, step: Duration) -> DurationSteps {
+        DurationSteps::new(self.start, step, Bound::Included(self.end))
+    }
+
+    /// Returns the number of values yielded by [`iter_by(step)`] if `step` evenly divides the
+    /// range, so that the last value yielded is exactly `end`.
+    ///
+    /// Returns `None` if `step` is zero or does not evenly divide `end - start`. An empty range
+    /// yields no values, and returns `Some(0)`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    /// use std::time::Duration;
+    ///
+    /// let range = RangeFromExclusiveToInclusive {
+    ///     start: Duration::from_millis(1),
+    ///     end: Duration::from_secs(1),
+    /// };
+    ///
+    /// assert_eq!(range.len_by(Duration::from_millis(1)), Some(999));
+    /// assert_eq!(range.len_by(Duration::from_millis(2)), None);
+    /// ```
+    ///
+    /// [`iter_by(step)`]: RangeFromExclusiveToInclusive::iter_by
+    pub fn len_by(&self, step: Duration) -> Option<u128> {
+        let step = nanos(step);
+        if step == 0 {
+            return None;
+        }
+        if self.is_empty() {
+            return Some(0);
+        }
+        let span = nanos(self.end) - nanos(self.start);
+        if span % step == 0 {
+            Some(span / step)
+        } else {
+            None
+        }
+    }
+}
+
+impl RangeFromExclusiveToExclusive<Duration> {
+    /// Returns an iterator over the values in the range, beginning at `start + step` and
+    /// continuing in increments of `step` for as long as they are less than `end`.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    /// use std::time::Duration;
+    ///
+    /// let steps = RangeFromExclusiveToExclusive {
+    ///     start: Duration::from_millis(0),
+    ///     end: Duration::from_millis(10),
+    /// }
+    /// .iter_by(Duration::from_millis(3));
+    ///
+    /// assert_eq!(steps.map(|d| d.subsec_millis()).collect::<Vec<_>>(), [3, 6, 9]);
+    /// ```
+    #[inline]
+    pub fn iter_by(&self, step: Duration) -> DurationSteps {
+        DurationSteps::new(self.start, step, Bound::Excluded(self.end))
+    }
+
+    /// Returns the number of values yielded by [`iter_by(step)`] if `step` evenly divides the
+    /// range, so that the value following the last one yielded is exactly `end`.
+    ///
+    /// Returns `None` if `step` is zero or does not evenly divide `end - start`. An empty range
+    /// yields no values, and returns `Some(0)`.
+    ///
+    /// [`iter_by(step)`]: RangeFromExclusiveToExclusive::iter_by
+    pub fn len_by(&self, step: Duration) -> Option<u128> {
+        let step = nanos(step);
+        if step == 0 {
+            return None;
+        }
+        if self.is_empty() {
+            return Some(0);
+        }
+        let span = nanos(self.end) - nanos(self.start);
+        if span % step == 0 {
+            Some(span / step - 1)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the range contains no whole nanoseconds.
+    ///
+    /// Since [`Duration`] has nanosecond resolution, a range whose bounds are a single nanosecond
+    /// apart is empty, although [`is_empty()`] reports that it is not.
+    ///
+    /// [`Duration`]: core::time::Duration
+    /// [`is_empty()`]: RangeFromExclusiveToExclusive::is_empty
+    #[inline]
+    pub fn is_empty_discrete(&self) -> bool {
+        match self.start.checked_add(Duration::new(0, 1)) {
+            Some(first) => first >= self.end,
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use std::vec::Vec;
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    fn max() -> Duration {
+        Duration::new(u64::max_value(), 999_999_999)
+    }
+
+    #[test]
+    fn to_inclusive_iter_by_exact() {
+        let range = RangeFromExclusiveToInclusive {
+            start: Duration::from_millis(1),
+            end: Duration::from_secs(1),
+        };
+        let steps = range.iter_by(Duration::from_millis(1)).collect::<Vec<_>>();
+
+        assert_eq!(steps.len(), 999);
+        for (index, step) in steps.into_iter().enumerate() {
+            assert_eq!(step, Duration::from_millis(index as u64 + 2));
+        }
+    }
+
+    #[test]
+    fn to_inclusive_iter_by_uneven() {
+        let range = RangeFromExclusiveToInclusive {
+            start: Duration::from_millis(0),
+            end: Duration::from_millis(10),
+        };
+
+        assert_eq!(
+            range.iter_by(Duration::from_millis(3)).collect::<Vec<_>>(),
+            [
+                Duration::from_millis(3),
+                Duration::from_millis(6),
+                Duration::from_millis(9)
+            ]
+        );
+        assert_none!(range.len_by(Duration::from_millis(3)));
+    }
+
+    #[test]
+    fn to_inclusive_iter_by_nanoseconds() {
+        let range = RangeFromExclusiveToInclusive {
+            start: Duration::new(0, 999_999_998),
+            end: Duration::new(1, 1),
+        };
+
+        assert_eq!(
+            range.iter_by(Duration::new(0, 1)).collect::<Vec<_>>(),
+            [
+                Duration::new(0, 999_999_999),
+                Duration::new(1, 0),
+                Duration::new(1, 1)
+            ]
+        );
+        assert_some_eq!(range.len_by(Duration::new(0, 1)), 3);
+    }
+
+    #[test]
+    fn to_inclusive_len_by() {
+        let range = RangeFromExclusiveToInclusive {
+            start: Duration::from_millis(1),
+            end: Duration::from_secs(1),
+        };
+
+        assert_some_eq!(range.len_by(Duration::from_millis(1)), 999);
+        assert_some_eq!(range.len_by(Duration::from_millis(999)), 1);
+        assert_none!(range.len_by(Duration::from_millis(2)));
+        assert_none!(range.len_by(Duration::new(0, 0)));
+    }
+
+    #[test]
+    fn to_inclusive_len_by_matches_count() {
+        for end in 0..20 {
+            for step in 1..6 {
+                let range = RangeFromExclusiveToInclusive {
+                    start: Duration::from_millis(2),
+                    end: Duration::from_millis(end),
+                };
+                let steps = range.iter_by(Duration::from_millis(step));
+                assert_eq!(steps.size_hint().0, steps.clone().count());
+                if let Some(len) = range.len_by(Duration::from_millis(step)) {
+                    assert_eq!(len, steps.count() as u128);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_inclusive_iter_by_near_max() {
+        let range = RangeFromExclusiveToInclusive {
+            start: max() - Duration::from_secs(3),
+            end: max(),
+        };
+        let steps = range.iter_by(Duration::from_secs(2));
+
+        assert_eq!(steps.size_hint(), (1, Some(1)));
+        assert_eq!(steps.collect::<Vec<_>>(), [max() - Duration::from_secs(1)]);
+    }
+
+    #[test]
+    fn to_inclusive_iter_by_reaches_max() {
+        let range = RangeFromExclusiveToInclusive {
+            start: max() - Duration::from_secs(2),
+            end: max(),
+        };
+
+        assert_eq!(
+            range.iter_by(Duration::from_secs(1)).collect::<Vec<_>>(),
+            [max() - Duration::from_secs(1), max()]
+        );
+    }
+
+    #[test]
+    fn to_inclusive_contains() {
+        let range = RangeFromExclusiveToInclusive {
+            start: Duration::from_millis(1),
+            end: Duration::from_millis(3),
+        };
+
+        assert!(!range.contains(&Duration::from_millis(1)));
+        assert!(range.contains(&Duration::new(0, 1_000_001)));
+        assert!(range.contains(&Duration::from_millis(3)));
+        assert!(!range.contains(&Duration::new(0, 3_000_001)));
+    }
+
+    #[test]
+    fn to_inclusive_is_empty() {
+        assert!(RangeFromExclusiveToInclusive {
+            start: Duration::from_millis(1),
+            end: Duration::from_millis(1),
+        }
+        .is_empty());
+        assert!(!RangeFromExclusiveToInclusive {
+            start: Duration::from_millis(1),
+            end: Duration::new(0, 1_000_001),
+        }
+        .is_empty());
+    }
+
+    #[test]
+    fn to_exclusive_iter_by_exact() {
+        let range = RangeFromExclusiveToExclusive {
+            start: Duration::from_millis(1),
+            end: Duration::from_secs(1),
+        };
+        let steps = range.iter_by(Duration::from_millis(1)).collect::<Vec<_>>();
+
+        assert_eq!(steps.len(), 998);
+        assert_eq!(steps[0], Duration::from_millis(2));
+        assert_eq!(steps[997], Duration::from_millis(999));
+        assert_some_eq!(range.len_by(Duration::from_millis(1)), 998);
+    }
+
+    #[test]
+    fn to_exclusive_iter_by_uneven() {
+        let range = RangeFromExclusiveToExclusive {
+            start: Duration::from_millis(0),
+            end: Duration::from_millis(10),
+        };
+
+        assert_eq!(
+            range.iter_by(Duration::from_millis(4)).collect::<Vec<_>>(),
+            [Duration::from_millis(4), Duration::from_millis(8)]
+        );
+        assert_none!(range.len_by(Duration::from_millis(4)));
+    }
+
+    #[test]
+    fn to_exclusive_len_by_matches_count() {
+        for end in 0..20 {
+            for step in 1..6 {
+                let range = RangeFromExclusiveToExclusive {
+                    start: Duration::from_millis(2),
+                    end: Duration::from_millis(end),
+                };
+                let steps = range.iter_by(Duration::from_millis(step));
+                assert_eq!(steps.size_hint().0, steps.clone().count());
+                if let Some(len) = range.len_by(Duration::from_millis(step)) {
+                    assert_eq!(len, steps.count() as u128);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_exclusive_iter_by_near_max() {
+        let range = RangeFromExclusiveToExclusive {
+            start: max() - Duration::from_secs(2),
+            end: max(),
+        };
+
+        assert_eq!(
+            range.iter_by(Duration::from_secs(1)).collect::<Vec<_>>(),
+            [max() - Duration::from_secs(1)]
+        );
+    }
+
+    #[test]
+    fn to_exclusive_is_empty() {
+        assert!(RangeFromExclusiveToExclusive {
+            start: Duration::from_millis(1),
+            end: Duration::from_millis(1),
+        }
+        .is_empty());
+        assert!(!RangeFromExclusiveToExclusive {
+            start: Duration::from_millis(1),
+            end: Duration::new(0, 1_000_001),
+        }
+        .is_empty());
+        assert!(RangeFromExclusiveToExclusive {
+            start: max(),
+            end: max(),
+        }
+        .is_empty());
+    }
+
+    #[test]
+    fn to_exclusive_is_empty_discrete() {
+        assert!(RangeFromExclusiveToExclusive {
+            start: Duration::from_millis(1),
+            end: Duration::new(0, 1_000_001),
+        }
+        .is_empty_discrete());
+        assert!(!RangeFromExclusiveToExclusive {
+            start: Duration::from_millis(1),
+            end: Duration::new(0, 1_000_002),
+        }
+        .is_empty_discrete());
+        assert!(RangeFromExclusiveToExclusive {
+            start: max(),
+            end: max(),
+        }
+        .is_empty_discrete());
+    }
+
+    #[test]
+    fn to_exclusive_contains() {
+        let range = RangeFromExclusiveToExclusive {
+            start: Duration::from_millis(1),
+            end: Duration::from_millis(3),
+        };
+
+        assert!(!range.contains(&Duration::from_millis(1)));
+        assert!(range.contains(&Duration::from_millis(2)));
+        assert!(!range.contains(&Duration::from_millis(3)));
+    }
+
+    #[test]
+    fn from_iter_by_terminates_at_max() {
+        let range = RangeFromExclusive {
+            start: max() - Duration::from_secs(5),
+        };
+        let steps = range.iter_by(Duration::from_secs(2));
+
+        assert_eq!(steps.size_hint(), (2, Some(2)));
+        assert_eq!(
+            steps.collect::<Vec<_>>(),
+            [
+                max() - Duration::from_secs(3),
+                max() - Duration::from_secs(1)
+            ]
+        );
+    }
+
+    #[test]
+    fn from_iter_by_start_is_max() {
+        assert_eq!(
+            RangeFromExclusive { start: max() }
+                .iter_by(Duration::new(0, 1))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn from_size_hint_exceeds_usize() {
+        let steps = RangeFromExclusive {
+            start: Duration::new(0, 0),
+        }
+        .iter_by(Duration::new(0, 1));
+
+        assert_eq!(steps.size_hint(), (usize::max_value(), None));
+    }
+
+    #[test]
+    fn fused() {
+        let mut steps = RangeFromExclusiveToExclusive {
+            start: Duration::from_millis(0),
+            end: Duration::from_millis(2),
+        }
+        .iter_by(Duration::from_millis(1));
+
+        assert_some_eq!(steps.next(), Duration::from_millis(1));
+        assert_none!(steps.next());
+        assert_none!(steps.next());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to step by a zero duration")]
+    fn zero_step() {
+        RangeFromExclusive {
+            start: Duration::new(0, 0),
+        }
+        .iter_by(Duration::new(0, 0));
+    }
+}
@@ -0,0 +1,124 @@
+//! Constructing the range types from their bounds with method syntax.
+
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Extension trait for constructing the range types from a lower bound (exclusive).
+///
+/// This is implemented for the primitive integer types and `char`, and cannot be implemented
+/// outside of this crate.
+///
+/// # Example
+/// ```
+/// use more_ranges::ExclusiveStartExt;
+///
+/// let idx = 1;
+/// let len = 3;
+///
+/// assert!(idx.excl_to_incl(idx + len).contains(&4));
+/// assert!(!idx.excl_to(idx + len).contains(&4));
+/// assert!(!idx.excl_from().contains(&idx));
+/// ```
+pub trait ExclusiveStartExt: sealed::Sealed + Sized {
+    /// Returns the range of values after `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{ExclusiveStartExt, RangeFromExclusive};
+    ///
+    /// assert_eq!(1.excl_from(), RangeFromExclusive { start: 1 });
+    /// ```
+    #[inline]
+    fn excl_from(self) -> RangeFromExclusive<Self> {
+        RangeFromExclusive { start: self }
+    }
+
+    /// Returns the range of values after `self` and before `end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{ExclusiveStartExt, RangeFromExclusiveToExclusive};
+    ///
+    /// assert_eq!(1.excl_to(4), RangeFromExclusiveToExclusive { start: 1, end: 4 });
+    /// ```
+    #[inline]
+    fn excl_to(self, end: Self) -> RangeFromExclusiveToExclusive<Self> {
+        RangeFromExclusiveToExclusive { start: self, end }
+    }
+
+    /// Returns the range of values after `self` up to and including `end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{ExclusiveStartExt, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     'a'.excl_to_incl('d'),
+    ///     RangeFromExclusiveToInclusive { start: 'a', end: 'd' }
+    /// );
+    /// ```
+    #[inline]
+    fn excl_to_incl(self, end: Self) -> RangeFromExclusiveToInclusive<Self> {
+        RangeFromExclusiveToInclusive { start: self, end }
+    }
+}
+
+macro_rules! impl_exclusive_start_ext {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl ExclusiveStartExt for $t {}
+        )*
+    };
+}
+
+impl_exclusive_start_ext!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char);
+
+#[cfg(test)]
+mod tests {
+    use {
+        ExclusiveStartExt, RangeFromExclusive, RangeFromExclusiveToExclusive,
+        RangeFromExclusiveToInclusive,
+    };
+
+    #[test]
+    fn excl_from() {
+        assert_eq!((-1i8).excl_from(), RangeFromExclusive { start: -1 });
+    }
+
+    #[test]
+    fn excl_to() {
+        assert_eq!(
+            u128::max_value().excl_to(0),
+            RangeFromExclusiveToExclusive {
+                start: u128::max_value(),
+                end: 0
+            }
+        );
+    }
+
+    #[test]
+    fn excl_to_incl() {
+        assert_eq!(
+            'x'.excl_to_incl('z'),
+            RangeFromExclusiveToInclusive {
+                start: 'x',
+                end: 'z'
+            }
+        );
+    }
+
+    #[cfg(impl_index)]
+    #[test]
+    fn slice_index() {
+        let slice = [0, 1, 2, 3, 4, 5];
+
+        assert_eq!(slice[1.excl_from()], [2, 3, 4, 5]);
+        assert_eq!(slice[1.excl_to(4)], [2, 3]);
+        assert_eq!(slice[1.excl_to_incl(4)], [2, 3, 4]);
+    }
+}
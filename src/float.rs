@@ -0,0 +1,446 @@
+//! Containment checks for ranges over floating point numbers that account for NaN.
+//!
+//! The [`PartialOrd`] comparisons used by [`RangeBounds::contains`] are false whenever NaN is
+//! involved, so a NaN value is never contained, and a NaN bound makes the range contain nothing.
+//! The methods here make the treatment of NaN explicit instead.
+//!
+//! [`RangeBounds::contains`]: core::ops::RangeBounds::contains
+
+use core::cmp::Ordering;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// How NaN is treated when checking whether a float range contains a value.
+///
+/// A policy applies whenever the value or any bound of the range is NaN. When no NaN is involved,
+/// containment is decided by the usual comparisons, and the policy has no effect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// NaN is never contained, and a NaN bound makes the range contain nothing.
+    ///
+    /// This matches [`RangeBounds::contains`].
+    ///
+    /// [`RangeBounds::contains`]: core::ops::RangeBounds::contains
+    Reject,
+    /// NaN is always contained, and a NaN bound makes the range contain everything.
+    Accept,
+    /// NaN is an error: no answer is given, and the containment check returns `None`.
+    Error,
+}
+
+impl NanPolicy {
+    /// Returns whether `contained` holds, unless any of `values` is NaN, in which case the answer
+    /// dictated by the policy is returned instead.
+    #[inline]
+    fn decide<T>(self, values: &[T], contained: bool) -> Option<bool>
+    where
+        T: Float,
+    {
+        if values.iter().any(|value| value.is_nan()) {
+            match self {
+                NanPolicy::Reject => Some(false),
+                NanPolicy::Accept => Some(true),
+                NanPolicy::Error => None,
+            }
+        } else {
+            Some(contained)
+        }
+    }
+}
+
+/// A primitive floating point type.
+///
+/// This is implemented for `f32` and `f64`, and cannot be implemented outside of this crate. It
+/// provides the NaN checks, IEEE 754 total ordering, and conversions through `f64` that the float
+/// range methods rely on without requiring `std`.
+pub trait Float: sealed::Sealed + Copy + PartialOrd {
+    /// Returns `true` if the value is NaN.
+    fn is_nan(self) -> bool;
+
+    /// Returns `true` if the value is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+
+    /// Compares two values using the IEEE 754 `totalOrder` predicate.
+    ///
+    /// This is the same ordering as `total_cmp()`, which was stabilized in Rust 1.62.
+    fn total_cmp(self, other: Self) -> Ordering;
+
+    /// Converts the value to an `f64`, losslessly.
+    fn to_f64(self) -> f64;
+
+    /// Converts an `f64` to the nearest value of this type.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ident, $signed:ident, $unsigned:ident, $shift:expr;)*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Float for $t {
+                #[inline]
+                fn is_nan(self) -> bool {
+                    self.is_nan()
+                }
+
+                #[inline]
+                fn is_finite(self) -> bool {
+                    self.is_finite()
+                }
+
+                #[inline]
+                fn total_cmp(self, other: Self) -> Ordering {
+                    // Flipping every bit but the sign of negative values makes the bit patterns of
+                    // all values order correctly as signed integers.
+                    let mut left = self.to_bits() as $signed;
+                    let mut right = other.to_bits() as $signed;
+                    left ^= (((left >> $shift) as $unsigned) >> 1) as $signed;
+                    right ^= (((right >> $shift) as $unsigned) >> 1) as $signed;
+                    left.cmp(&right)
+                }
+
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_float! {
+    f32, i32, u32, 31;
+    f64, i64, u64, 63;
+}
+
+impl<T> RangeFromExclusive<T>
+where
+    T: Float,
+{
+    /// Returns whether `value` is contained in the range, treating NaN according to `policy`.
+    ///
+    /// This is only `None` under [`NanPolicy::Error`], if the value or a bound is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{NanPolicy, RangeFromExclusive};
+    ///
+    /// let range = RangeFromExclusive { start: 1.0 };
+    ///
+    /// assert_eq!(range.contains_with(2.0, NanPolicy::Reject), Some(true));
+    /// assert_eq!(range.contains_with(std::f64::NAN, NanPolicy::Reject), Some(false));
+    /// assert_eq!(range.contains_with(std::f64::NAN, NanPolicy::Accept), Some(true));
+    /// assert_eq!(range.contains_with(std::f64::NAN, NanPolicy::Error), None);
+    /// ```
+    #[inline]
+    pub fn contains_with(&self, value: T, policy: NanPolicy) -> Option<bool> {
+        policy.decide(&[value, self.start], value > self.start)
+    }
+
+    /// Returns `true` if `value` is contained in the range under the IEEE 754 total ordering.
+    ///
+    /// Under this ordering, `-0.0` is less than `+0.0`, positive NaN is greater than positive
+    /// infinity, and negative NaN is less than negative infinity. This is the ordering of
+    /// `f64::total_cmp()`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// let range = RangeFromExclusive { start: -0.0 };
+    ///
+    /// assert!(range.contains_total(0.0));
+    /// assert!(range.contains_total(std::f64::NAN));
+    /// ```
+    #[inline]
+    pub fn contains_total(&self, value: T) -> bool {
+        value.total_cmp(self.start) == Ordering::Greater
+    }
+
+    /// Returns `true` if the bound is not finite, that is, if it is infinite or NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert!(!RangeFromExclusive { start: 1.0 }.is_degenerate());
+    /// assert!(RangeFromExclusive { start: std::f64::NEG_INFINITY }.is_degenerate());
+    /// ```
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        !self.start.is_finite()
+    }
+}
+
+impl<T> RangeFromExclusiveToInclusive<T>
+where
+    T: Float,
+{
+    /// Returns whether `value` is contained in the range, treating NaN according to `policy`.
+    ///
+    /// This is only `None` under [`NanPolicy::Error`], if the value or a bound is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{NanPolicy, RangeFromExclusiveToInclusive};
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 1.0, end: 2.0 };
+    ///
+    /// assert_eq!(range.contains_with(2.0, NanPolicy::Reject), Some(true));
+    /// assert_eq!(range.contains_with(std::f64::NAN, NanPolicy::Reject), Some(false));
+    /// assert_eq!(range.contains_with(std::f64::NAN, NanPolicy::Accept), Some(true));
+    /// assert_eq!(range.contains_with(std::f64::NAN, NanPolicy::Error), None);
+    /// ```
+    #[inline]
+    pub fn contains_with(&self, value: T, policy: NanPolicy) -> Option<bool> {
+        policy.decide(
+            &[value, self.start, self.end],
+            value > self.start && value <= self.end,
+        )
+    }
+
+    /// Returns `true` if `value` is contained in the range under the IEEE 754 total ordering.
+    ///
+    /// Under this ordering, `-0.0` is less than `+0.0`, positive NaN is greater than positive
+    /// infinity, and negative NaN is less than negative infinity. This is the ordering of
+    /// `f64::total_cmp()`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: -0.0, end: 0.0 };
+    ///
+    /// assert!(range.contains_total(0.0));
+    /// assert!(!range.contains_total(-0.0));
+    /// ```
+    #[inline]
+    pub fn contains_total(&self, value: T) -> bool {
+        value.total_cmp(self.start) == Ordering::Greater
+            && value.total_cmp(self.end) != Ordering::Greater
+    }
+
+    /// Returns `true` if either bound is not finite, that is, if it is infinite or NaN.
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        !self.start.is_finite() || !self.end.is_finite()
+    }
+}
+
+impl<T> RangeFromExclusiveToExclusive<T>
+where
+    T: Float,
+{
+    /// Returns whether `value` is contained in the range, treating NaN according to `policy`.
+    ///
+    /// This is only `None` under [`NanPolicy::Error`], if the value or a bound is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{NanPolicy, RangeFromExclusiveToExclusive};
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1.0, end: 2.0 };
+    ///
+    /// assert_eq!(range.contains_with(1.5, NanPolicy::Reject), Some(true));
+    /// assert_eq!(range.contains_with(std::f64::NAN, NanPolicy::Reject), Some(false));
+    /// assert_eq!(range.contains_with(std::f64::NAN, NanPolicy::Accept), Some(true));
+    /// assert_eq!(range.contains_with(std::f64::NAN, NanPolicy::Error), None);
+    /// ```
+    #[inline]
+    pub fn contains_with(&self, value: T, policy: NanPolicy) -> Option<bool> {
+        policy.decide(
+            &[value, self.start, self.end],
+            value > self.start && value < self.end,
+        )
+    }
+
+    /// Returns `true` if `value` is contained in the range under the IEEE 754 total ordering.
+    ///
+    /// Under this ordering, `-0.0` is less than `+0.0`, positive NaN is greater than positive
+    /// infinity, and negative NaN is less than negative infinity. This is the ordering of
+    /// `f64::total_cmp()`.
+    #[inline]
+    pub fn contains_total(&self, value: T) -> bool {
+        value.total_cmp(self.start) == Ordering::Greater
+            && value.total_cmp(self.end) == Ordering::Less
+    }
+
+    /// Returns `true` if either bound is not finite, that is, if it is infinite or NaN.
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        !self.start.is_finite() || !self.end.is_finite()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32;
+    use core::f64::{self, INFINITY, NAN, NEG_INFINITY};
+    use {
+        NanPolicy, RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+    };
+
+    #[test]
+    fn contains_with_no_nan() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 1.0,
+            end: 2.0,
+        };
+
+        for &policy in &[NanPolicy::Reject, NanPolicy::Accept, NanPolicy::Error] {
+            assert_some_eq!(range.contains_with(1.0, policy), false);
+            assert_some_eq!(range.contains_with(1.5, policy), true);
+            assert_some_eq!(range.contains_with(2.0, policy), true);
+            assert_some_eq!(range.contains_with(2.5, policy), false);
+        }
+    }
+
+    #[test]
+    fn contains_with_nan_value() {
+        let from = RangeFromExclusive { start: 1.0 };
+        let to_inclusive = RangeFromExclusiveToInclusive {
+            start: 1.0,
+            end: 2.0,
+        };
+        let to_exclusive = RangeFromExclusiveToExclusive {
+            start: 1.0,
+            end: 2.0,
+        };
+
+        assert_some_eq!(from.contains_with(NAN, NanPolicy::Reject), false);
+        assert_some_eq!(from.contains_with(NAN, NanPolicy::Accept), true);
+        assert_some_eq!(to_inclusive.contains_with(NAN, NanPolicy::Reject), false);
+        assert_some_eq!(to_inclusive.contains_with(NAN, NanPolicy::Accept), true);
+        assert_some_eq!(to_exclusive.contains_with(NAN, NanPolicy::Reject), false);
+        assert_some_eq!(to_exclusive.contains_with(NAN, NanPolicy::Accept), true);
+    }
+
+    #[test]
+    fn contains_with_nan_start() {
+        let from = RangeFromExclusive { start: NAN };
+        let to_inclusive = RangeFromExclusiveToInclusive {
+            start: NAN,
+            end: 2.0,
+        };
+
+        assert_some_eq!(from.contains_with(1.5, NanPolicy::Reject), false);
+        assert_some_eq!(from.contains_with(1.5, NanPolicy::Accept), true);
+        assert_some_eq!(to_inclusive.contains_with(1.5, NanPolicy::Reject), false);
+        assert_some_eq!(to_inclusive.contains_with(3.0, NanPolicy::Accept), true);
+    }
+
+    #[test]
+    fn contains_with_nan_end() {
+        let to_inclusive = RangeFromExclusiveToInclusive {
+            start: 1.0,
+            end: NAN,
+        };
+        let to_exclusive = RangeFromExclusiveToExclusive {
+            start: 1.0,
+            end: NAN,
+        };
+
+        assert_some_eq!(to_inclusive.contains_with(1.5, NanPolicy::Reject), false);
+        assert_some_eq!(to_inclusive.contains_with(0.0, NanPolicy::Accept), true);
+        assert_some_eq!(to_exclusive.contains_with(1.5, NanPolicy::Reject), false);
+        assert_some_eq!(to_exclusive.contains_with(0.0, NanPolicy::Accept), true);
+    }
+
+    #[test]
+    fn contains_with_error() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 1.0,
+            end: 2.0,
+        };
+
+        assert_some_eq!(range.contains_with(1.5, NanPolicy::Error), true);
+        assert_some_eq!(range.contains_with(2.0, NanPolicy::Error), false);
+        assert_none!(range.contains_with(NAN, NanPolicy::Error));
+        assert_none!(RangeFromExclusive { start: NAN }.contains_with(1.0, NanPolicy::Error));
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: 1.0,
+            end: NAN
+        }
+        .contains_with(1.5, NanPolicy::Error));
+    }
+
+    #[test]
+    fn contains_total() {
+        let range = RangeFromExclusiveToInclusive {
+            start: -0.0,
+            end: INFINITY,
+        };
+
+        assert!(!range.contains_total(-0.0));
+        assert!(range.contains_total(0.0));
+        assert!(range.contains_total(INFINITY));
+        assert!(!range.contains_total(NAN));
+        assert!(!range.contains_total(-NAN));
+    }
+
+    #[test]
+    fn contains_total_nan_bounds() {
+        let above_infinity = RangeFromExclusive { start: INFINITY };
+        let below_neg_infinity = RangeFromExclusiveToExclusive {
+            start: -NAN,
+            end: NEG_INFINITY,
+        };
+
+        assert!(above_infinity.contains_total(NAN));
+        assert!(!above_infinity.contains_total(-NAN));
+        assert!(!below_neg_infinity.contains_total(NEG_INFINITY));
+        assert!(!below_neg_infinity.contains_total(-NAN));
+        assert!(RangeFromExclusiveToExclusive {
+            start: -NAN,
+            end: f64::MIN,
+        }
+        .contains_total(NEG_INFINITY));
+    }
+
+    #[test]
+    fn contains_total_f32() {
+        let range = RangeFromExclusiveToExclusive {
+            start: f32::NEG_INFINITY,
+            end: f32::NAN,
+        };
+
+        assert!(range.contains_total(f32::MIN));
+        assert!(range.contains_total(f32::INFINITY));
+        assert!(!range.contains_total(f32::NAN));
+        assert!(!range.contains_total(f32::NEG_INFINITY));
+    }
+
+    #[test]
+    fn is_degenerate() {
+        assert!(!RangeFromExclusive { start: 1.0 }.is_degenerate());
+        assert!(RangeFromExclusive { start: NAN }.is_degenerate());
+        assert!(!RangeFromExclusiveToInclusive {
+            start: f64::MIN,
+            end: f64::MAX
+        }
+        .is_degenerate());
+        assert!(RangeFromExclusiveToInclusive {
+            start: NEG_INFINITY,
+            end: 1.0
+        }
+        .is_degenerate());
+        assert!(RangeFromExclusiveToExclusive {
+            start: 1.0,
+            end: INFINITY
+        }
+        .is_degenerate());
+        assert!(RangeFromExclusiveToExclusive {
+            start: 1.0f32,
+            end: f32::NAN
+        }
+        .is_degenerate());
+    }
+}
@@ -0,0 +1,411 @@
+//! Conversion to and from HTTP byte range headers.
+//!
+//! HTTP byte ranges, as used in the `Range` and `Content-Range` headers, are written as the
+//! inclusive positions of their first and last bytes. A range beginning at the first byte of a
+//! representation, position `0`, therefore has no exclusive `start` bound, and HTTP has no syntax
+//! for an empty byte range.
+
+use alloc::string::String;
+use core::fmt::{self, Write};
+#[cfg(feature = "std")]
+use std::error::Error;
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// An error parsing a `Content-Range` header value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseContentRangeError {
+    /// The value is not of the form `bytes first-last/length` or `bytes first-last/*`.
+    Malformed,
+    /// The value is of the form `bytes */length`, which reports an unsatisfiable range rather
+    /// than a range of bytes.
+    Unsatisfied,
+    /// A position or length does not fit in a `u64`.
+    Overflow,
+    /// The first byte position is `0`, so the range would need an exclusive `start` bound below
+    /// `0`.
+    OffsetIsZero,
+    /// The last byte position is less than the first byte position.
+    Inverted,
+    /// The last byte position is not less than the complete length.
+    OutOfBounds,
+}
+
+impl fmt::Display for ParseContentRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseContentRangeError::Malformed => f.write_str(
+                "content range is not of the form `bytes first-last/length` or `bytes first-last/*`",
+            ),
+            ParseContentRangeError::Unsatisfied => {
+                f.write_str("content range reports an unsatisfiable range")
+            }
+            ParseContentRangeError::Overflow => {
+                f.write_str("content range position or length does not fit in a `u64`")
+            }
+            ParseContentRangeError::OffsetIsZero => f.write_str(
+                "content range begins at byte 0 and cannot be represented with an exclusive start bound",
+            ),
+            ParseContentRangeError::Inverted => {
+                f.write_str("content range last byte position is less than its first")
+            }
+            ParseContentRangeError::OutOfBounds => {
+                f.write_str("content range last byte position is not less than its complete length")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseContentRangeError {}
+
+/// Parses a decimal position or length, which must consist only of ASCII digits.
+fn parse_u64(s: &str) -> Result<u64, ParseContentRangeError> {
+    if s.is_empty() || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ParseContentRangeError::Malformed);
+    }
+    // The only remaining way for parsing to fail is for the value to be too large.
+    s.parse().map_err(|_| ParseContentRangeError::Overflow)
+}
+
+/// Formats the `Range` header value for the inclusive byte positions `first` through `last`.
+fn range_header(first: u64, last: u64) -> String {
+    let mut header = String::new();
+    // Writing to a `String` never fails.
+    let _ = write!(header, "bytes={}-{}", first, last);
+    header
+}
+
+impl RangeFromExclusiveToInclusive<u64> {
+    /// Returns the value of an HTTP `Range` header requesting the bytes within the range.
+    ///
+    /// Returns `None` if the range is empty, since HTTP cannot express an empty byte range.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 99, end: 199 }.to_http_range_header(),
+    ///     Some("bytes=100-199".to_owned())
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 99, end: 99 }.to_http_range_header(),
+    ///     None
+    /// );
+    /// ```
+    pub fn to_http_range_header(&self) -> Option<String> {
+        if self.start >= self.end {
+            return None;
+        }
+        // `start` is less than `end`, so this cannot overflow.
+        Some(range_header(self.start + 1, self.end))
+    }
+
+    /// Parses the value of an HTTP `Content-Range` header, returning the range of bytes it
+    /// contains along with the complete length of the representation, if known.
+    ///
+    /// The value must be of the form `bytes first-last/length`, or `bytes first-last/*` if the
+    /// complete length is unknown.
+    ///
+    /// # Errors
+    /// Returns an error if the value is malformed or reports an unsatisfiable range, if a number
+    /// overflows a `u64`, or if the range is inverted or extends beyond the complete length. Since
+    /// the exclusive `start` bound of a range beginning at byte `0` cannot be represented, that is
+    /// an error as well.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{ParseContentRangeError, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive::from_http_content_range("bytes 100-199/1000"),
+    ///     Ok((RangeFromExclusiveToInclusive { start: 99, end: 199 }, Some(1000)))
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive::from_http_content_range("bytes 0-199/*"),
+    ///     Err(ParseContentRangeError::OffsetIsZero)
+    /// );
+    /// ```
+    pub fn from_http_content_range(
+        s: &str,
+    ) -> Result<(RangeFromExclusiveToInclusive<u64>, Option<u64>), ParseContentRangeError> {
+        if !s.starts_with("bytes ") {
+            return Err(ParseContentRangeError::Malformed);
+        }
+        let s = &s["bytes ".len()..];
+        let slash = s.find('/').ok_or(ParseContentRangeError::Malformed)?;
+        let (range, length) = (&s[..slash], &s[slash + 1..]);
+        let length = if length == "*" {
+            None
+        } else {
+            Some(parse_u64(length)?)
+        };
+        if range == "*" {
+            return if length.is_some() {
+                Err(ParseContentRangeError::Unsatisfied)
+            } else {
+                Err(ParseContentRangeError::Malformed)
+            };
+        }
+        let dash = range.find('-').ok_or(ParseContentRangeError::Malformed)?;
+        let first = parse_u64(&range[..dash])?;
+        let last = parse_u64(&range[dash + 1..])?;
+        if last < first {
+            return Err(ParseContentRangeError::Inverted);
+        }
+        if let Some(length) = length {
+            if last >= length {
+                return Err(ParseContentRangeError::OutOfBounds);
+            }
+        }
+        if first == 0 {
+            return Err(ParseContentRangeError::OffsetIsZero);
+        }
+        Ok((
+            RangeFromExclusiveToInclusive {
+                start: first - 1,
+                end: last,
+            },
+            length,
+        ))
+    }
+}
+
+impl RangeFromExclusiveToExclusive<u64> {
+    /// Returns the value of an HTTP `Range` header requesting the bytes within the range.
+    ///
+    /// Returns `None` if the range is empty, since HTTP cannot express an empty byte range.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 99, end: 200 }.to_http_range_header(),
+    ///     Some("bytes=100-199".to_owned())
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 99, end: 100 }.to_http_range_header(),
+    ///     None
+    /// );
+    /// ```
+    pub fn to_http_range_header(&self) -> Option<String> {
+        if self.start >= self.end || self.end - self.start == 1 {
+            return None;
+        }
+        // `start + 1` is less than `end`, so neither of these can overflow.
+        Some(range_header(self.start + 1, self.end - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::ParseContentRangeError;
+    use std::string::String;
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    /// Converts a `Range` header value into the `Content-Range` header value for the same bytes.
+    fn content_range(range_header: &str, length: &str) -> String {
+        let mut content_range = String::from("bytes ");
+        content_range.push_str(&range_header["bytes=".len()..]);
+        content_range.push('/');
+        content_range.push_str(length);
+        content_range
+    }
+
+    #[test]
+    fn to_inclusive_header() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive { start: 0, end: 499 }.to_http_range_header(),
+            "bytes=1-499"
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: u64::max_value() - 1,
+                end: u64::max_value(),
+            }
+            .to_http_range_header(),
+            "bytes=18446744073709551615-18446744073709551615"
+        );
+    }
+
+    #[test]
+    fn to_inclusive_header_empty() {
+        assert_none!(RangeFromExclusiveToInclusive { start: 5, end: 5 }.to_http_range_header());
+        assert_none!(RangeFromExclusiveToInclusive { start: 6, end: 5 }.to_http_range_header());
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: u64::max_value(),
+            end: u64::max_value(),
+        }
+        .to_http_range_header());
+    }
+
+    #[test]
+    fn to_exclusive_header() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive { start: 0, end: 500 }.to_http_range_header(),
+            "bytes=1-499"
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive { start: 4, end: 6 }.to_http_range_header(),
+            "bytes=5-5"
+        );
+    }
+
+    #[test]
+    fn to_exclusive_header_empty() {
+        assert_none!(RangeFromExclusiveToExclusive { start: 5, end: 5 }.to_http_range_header());
+        assert_none!(RangeFromExclusiveToExclusive { start: 5, end: 6 }.to_http_range_header());
+        assert_none!(RangeFromExclusiveToExclusive { start: 7, end: 5 }.to_http_range_header());
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: u64::max_value(),
+            end: 0,
+        }
+        .to_http_range_header());
+    }
+
+    #[test]
+    fn parse_content_range() {
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive::from_http_content_range("bytes 1-499/1234"),
+            (
+                RangeFromExclusiveToInclusive { start: 0, end: 499 },
+                Some(1234)
+            )
+        );
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive::from_http_content_range("bytes 42-42/*"),
+            (RangeFromExclusiveToInclusive { start: 41, end: 42 }, None)
+        );
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive::from_http_content_range(
+                "bytes 1-18446744073709551614/18446744073709551615"
+            ),
+            (
+                RangeFromExclusiveToInclusive {
+                    start: 0,
+                    end: u64::max_value() - 1,
+                },
+                Some(u64::max_value())
+            )
+        );
+    }
+
+    #[test]
+    fn parse_content_range_offset_zero() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::from_http_content_range("bytes 0-499/1234"),
+            Err(ParseContentRangeError::OffsetIsZero)
+        );
+    }
+
+    #[test]
+    fn parse_content_range_overflow() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::from_http_content_range(
+                "bytes 1-18446744073709551616/*"
+            ),
+            Err(ParseContentRangeError::Overflow)
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive::from_http_content_range(
+                "bytes 1-2/99999999999999999999999"
+            ),
+            Err(ParseContentRangeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn parse_content_range_invalid_range() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::from_http_content_range("bytes 5-4/10"),
+            Err(ParseContentRangeError::Inverted)
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive::from_http_content_range("bytes 5-10/10"),
+            Err(ParseContentRangeError::OutOfBounds)
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive::from_http_content_range("bytes */10"),
+            Err(ParseContentRangeError::Unsatisfied)
+        );
+    }
+
+    #[test]
+    fn parse_content_range_malformed() {
+        for &value in [
+            "",
+            "bytes",
+            "bytes ",
+            "bytes 1-2",
+            "bytes=1-2/3",
+            "items 1-2/3",
+            "bytes 1-/3",
+            "bytes -2/3",
+            "bytes 1 2/3",
+            "bytes 1-2/",
+            "bytes +1-2/3",
+            "bytes 1--2/3",
+            "bytes 1-2/3/4",
+            "bytes 1-2/-3",
+            "bytes  1-2/3",
+            "bytes 1-2 /3",
+            "bytes */*",
+            "bytes \u{661}-2/3",
+        ]
+        .iter()
+        {
+            assert_eq!(
+                RangeFromExclusiveToInclusive::from_http_content_range(value),
+                Err(ParseContentRangeError::Malformed),
+                "{:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for start in 0..20u64 {
+            for end in start + 1..20 {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                let header = assert_some!(range.to_http_range_header());
+                assert_ok_eq!(
+                    RangeFromExclusiveToInclusive::from_http_content_range(&content_range(
+                        &header, "20"
+                    )),
+                    (range, Some(20))
+                );
+                assert_ok_eq!(
+                    RangeFromExclusiveToInclusive::from_http_content_range(&content_range(
+                        &header, "*"
+                    )),
+                    (range, None)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_to_exclusive() {
+        for start in 0..20u64 {
+            for end in start + 2..21 {
+                let header = assert_some!(
+                    RangeFromExclusiveToExclusive { start, end }.to_http_range_header()
+                );
+                assert_ok_eq!(
+                    RangeFromExclusiveToInclusive::from_http_content_range(&content_range(
+                        &header, "*"
+                    )),
+                    (
+                        RangeFromExclusiveToInclusive {
+                            start,
+                            end: end - 1
+                        },
+                        None
+                    )
+                );
+            }
+        }
+    }
+}
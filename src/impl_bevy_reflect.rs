@@ -0,0 +1,197 @@
+//! Type registration helpers for [`bevy_reflect`](https://docs.rs/bevy_reflect).
+//!
+//! The `Reflect`, `Struct`, `FromReflect`, `TypePath`, and `GetTypeRegistration` implementations
+//! are derived on the range types themselves. Their reflected fields are named `start` and `end`,
+//! matching the struct fields.
+
+use bevy_reflect::TypeRegistry;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// Registers all three range types for the common index types in a [`TypeRegistry`].
+///
+/// The registered index types are the primitive integers (excluding the 128-bit integers), `f32`,
+/// `f64`, and `char`. Ranges over other index types can be registered individually with
+/// [`TypeRegistry::register`].
+///
+/// # Example
+/// ```
+/// extern crate bevy_reflect;
+/// # extern crate more_ranges;
+///
+/// use bevy_reflect::TypeRegistry;
+/// use std::any::TypeId;
+/// use more_ranges::{register_range_types, RangeFromExclusiveToInclusive};
+///
+/// let mut registry = TypeRegistry::new();
+/// register_range_types(&mut registry);
+///
+/// assert!(registry
+///     .get(TypeId::of::<RangeFromExclusiveToInclusive<f32>>())
+///     .is_some());
+/// ```
+///
+/// [`TypeRegistry`]: bevy_reflect::TypeRegistry
+/// [`TypeRegistry::register`]: bevy_reflect::TypeRegistry::register
+pub fn register_range_types(registry: &mut TypeRegistry) {
+    macro_rules! register {
+        ($($t:ty),*) => {
+            $(
+                registry.register::<RangeFromExclusive<$t>>();
+                registry.register::<RangeFromExclusiveToExclusive<$t>>();
+                registry.register::<RangeFromExclusiveToInclusive<$t>>();
+            )*
+        };
+    }
+
+    register!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, char);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::{
+        DynamicStruct, FromReflect, PartialReflect, Struct, TypePath, TypeRegistry,
+    };
+    use core::any::TypeId;
+    use {
+        register_range_types, RangeFromExclusive, RangeFromExclusiveToExclusive,
+        RangeFromExclusiveToInclusive,
+    };
+
+    #[test]
+    fn range_from_exclusive_fields() {
+        let range = RangeFromExclusive { start: 1u32 };
+
+        assert_eq!(range.field_len(), 1);
+        assert_some_eq!(range.name_at(0), "start");
+        assert_some_eq!(
+            range
+                .field("start")
+                .and_then(|field| field.try_downcast_ref::<u32>()),
+            &1
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_dynamic_round_trip() {
+        let range = RangeFromExclusive { start: 1u32 };
+
+        assert_some_eq!(
+            RangeFromExclusive::<u32>::from_reflect(&range.to_dynamic_struct()),
+            range
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_fields() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 1i64,
+            end: 4,
+        };
+
+        assert_eq!(range.field_len(), 2);
+        assert_some_eq!(range.name_at(0), "start");
+        assert_some_eq!(range.name_at(1), "end");
+        assert_some_eq!(
+            range
+                .field("end")
+                .and_then(|field| field.try_downcast_ref::<i64>()),
+            &4
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_dynamic_round_trip() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 1i64,
+            end: 4,
+        };
+
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive::<i64>::from_reflect(&range.to_dynamic_struct()),
+            range
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_fields() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 0.5f32,
+            end: 1.5,
+        };
+
+        assert_eq!(range.field_len(), 2);
+        assert_some_eq!(range.name_at(0), "start");
+        assert_some_eq!(range.name_at(1), "end");
+        assert_some_eq!(
+            range
+                .field("start")
+                .and_then(|field| field.try_downcast_ref::<f32>()),
+            &0.5
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_dynamic_round_trip() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 0.5f32,
+            end: 1.5,
+        };
+
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive::<f32>::from_reflect(&range.to_dynamic_struct()),
+            range
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_dynamic_struct() {
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("start", 0.5f32);
+        dynamic.insert("end", 1.5f32);
+
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive::<f32>::from_reflect(&dynamic),
+            RangeFromExclusiveToInclusive {
+                start: 0.5,
+                end: 1.5
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_dynamic_struct_missing_field() {
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("start", 0.5f32);
+
+        assert_none!(RangeFromExclusiveToInclusive::<f32>::from_reflect(&dynamic));
+    }
+
+    #[test]
+    fn apply_dynamic_struct() {
+        let mut range = RangeFromExclusiveToExclusive { start: 1u8, end: 4 };
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("end", 10u8);
+
+        range.apply(&dynamic);
+
+        assert_eq!(range, RangeFromExclusiveToExclusive { start: 1, end: 10 });
+    }
+
+    #[test]
+    fn type_path() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::<f32>::type_path(),
+            "more_ranges::RangeFromExclusiveToInclusive<f32>"
+        );
+    }
+
+    #[test]
+    fn register_range_types_registers() {
+        let mut registry = TypeRegistry::new();
+        register_range_types(&mut registry);
+
+        assert_some!(registry.get(TypeId::of::<RangeFromExclusive<usize>>()));
+        assert_some!(registry.get(TypeId::of::<RangeFromExclusiveToExclusive<i32>>()));
+        assert_some!(registry.get(TypeId::of::<RangeFromExclusiveToInclusive<f64>>()));
+    }
+}
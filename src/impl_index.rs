@@ -0,0 +1,531 @@
+//! Indexing of slices and string slices by the range types.
+//!
+//! Only ranges over `usize` can be used for indexing, matching the standard library's ranges.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(all(feature = "alloc", alloc_sync))]
+use alloc::sync::Arc;
+use core::ops::{Index, IndexMut};
+use resolve::Resolve;
+use {
+    AnyExclusiveRange, RangeFromExclusive, RangeFromExclusiveToExclusive,
+    RangeFromExclusiveToInclusive,
+};
+
+macro_rules! impl_index {
+    ($($range:ident),*) => {
+        $(
+            impl<T> Index<$range<usize>> for [T] {
+                type Output = [T];
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &[T] {
+                    &self[index.resolve(self.len())]
+                }
+            }
+
+            impl<T> IndexMut<$range<usize>> for [T] {
+                #[inline]
+                fn index_mut(&mut self, index: $range<usize>) -> &mut [T] {
+                    let len = self.len();
+                    &mut self[index.resolve(len)]
+                }
+            }
+
+            impl Index<$range<usize>> for str {
+                type Output = str;
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &str {
+                    &self[index.resolve(self.len())]
+                }
+            }
+
+            impl IndexMut<$range<usize>> for str {
+                #[inline]
+                fn index_mut(&mut self, index: $range<usize>) -> &mut str {
+                    let len = self.len();
+                    &mut self[index.resolve(len)]
+                }
+            }
+        )*
+    };
+}
+
+impl_index!(
+    AnyExclusiveRange,
+    RangeFromExclusive,
+    RangeFromExclusiveToExclusive,
+    RangeFromExclusiveToInclusive
+);
+
+/// Implements `Index` and `IndexMut` for arrays of each given length by delegating to the slice
+/// impls, as the standard library did before const generics.
+#[cfg(impl_index_arrays)]
+macro_rules! impl_index_arrays {
+    ($range:ident; $($n:expr),*) => {
+        $(
+            impl<T> Index<$range<usize>> for [T; $n] {
+                type Output = [T];
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &[T] {
+                    Index::index(&self[..], index)
+                }
+            }
+
+            impl<T> IndexMut<$range<usize>> for [T; $n] {
+                #[inline]
+                fn index_mut(&mut self, index: $range<usize>) -> &mut [T] {
+                    IndexMut::index_mut(&mut self[..], index)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(impl_index_arrays)]
+impl_index_arrays!(AnyExclusiveRange; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32);
+#[cfg(impl_index_arrays)]
+impl_index_arrays!(RangeFromExclusive; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32);
+#[cfg(impl_index_arrays)]
+impl_index_arrays!(RangeFromExclusiveToExclusive; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32);
+#[cfg(impl_index_arrays)]
+impl_index_arrays!(RangeFromExclusiveToInclusive; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32);
+
+/// Implements `Index` for `Cow`s of slices and string slices by delegating to the slice impls.
+///
+/// `Cow` already indexes through `Deref` in expression position, but these impls are needed for it
+/// to satisfy `Index` bounds. `IndexMut` is deliberately not implemented, as it would force borrowed
+/// data to be cloned.
+#[cfg(feature = "alloc")]
+macro_rules! impl_index_cow {
+    ($($range:ident),*) => {
+        $(
+            impl<'a, T> Index<$range<usize>> for Cow<'a, [T]>
+            where
+                T: Clone,
+            {
+                type Output = [T];
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &[T] {
+                    Index::index(&**self, index)
+                }
+            }
+
+            impl<'a> Index<$range<usize>> for Cow<'a, str> {
+                type Output = str;
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &str {
+                    Index::index(&**self, index)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_index_cow!(
+    AnyExclusiveRange,
+    RangeFromExclusive,
+    RangeFromExclusiveToExclusive,
+    RangeFromExclusiveToInclusive
+);
+
+/// Implements `Index` for boxed and reference-counted slices and string slices by delegating to the
+/// slice impls, so that they satisfy `Index` bounds.
+///
+/// `IndexMut` is only implemented for `Box`, as the reference-counted pointers do not provide
+/// unique access. `Arc` is only supported on targets providing `alloc::sync`.
+#[cfg(feature = "alloc")]
+macro_rules! impl_index_pointers {
+    ($($range:ident),*) => {
+        $(
+            impl<T> Index<$range<usize>> for Box<[T]> {
+                type Output = [T];
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &[T] {
+                    Index::index(&**self, index)
+                }
+            }
+
+            impl<T> IndexMut<$range<usize>> for Box<[T]> {
+                #[inline]
+                fn index_mut(&mut self, index: $range<usize>) -> &mut [T] {
+                    IndexMut::index_mut(&mut **self, index)
+                }
+            }
+
+            impl Index<$range<usize>> for Box<str> {
+                type Output = str;
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &str {
+                    Index::index(&**self, index)
+                }
+            }
+
+            impl IndexMut<$range<usize>> for Box<str> {
+                #[inline]
+                fn index_mut(&mut self, index: $range<usize>) -> &mut str {
+                    IndexMut::index_mut(&mut **self, index)
+                }
+            }
+
+            impl<T> Index<$range<usize>> for Rc<[T]> {
+                type Output = [T];
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &[T] {
+                    Index::index(&**self, index)
+                }
+            }
+
+            impl Index<$range<usize>> for Rc<str> {
+                type Output = str;
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &str {
+                    Index::index(&**self, index)
+                }
+            }
+
+            #[cfg(alloc_sync)]
+            impl<T> Index<$range<usize>> for Arc<[T]> {
+                type Output = [T];
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &[T] {
+                    Index::index(&**self, index)
+                }
+            }
+
+            #[cfg(alloc_sync)]
+            impl Index<$range<usize>> for Arc<str> {
+                type Output = str;
+
+                #[inline]
+                fn index(&self, index: $range<usize>) -> &str {
+                    Index::index(&**self, index)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_index_pointers!(
+    AnyExclusiveRange,
+    RangeFromExclusive,
+    RangeFromExclusiveToExclusive,
+    RangeFromExclusiveToInclusive
+);
+
+#[cfg(test)]
+mod tests {
+    use core::ops::{Index, IndexMut};
+    #[cfg(feature = "alloc")]
+    use std::borrow::Cow;
+    #[cfg(feature = "alloc")]
+    use std::boxed::Box;
+    #[cfg(feature = "alloc")]
+    use std::rc::Rc;
+    #[cfg(feature = "alloc")]
+    use std::string::String;
+    #[cfg(all(feature = "alloc", alloc_sync))]
+    use std::sync::Arc;
+    #[cfg(feature = "alloc")]
+    use std::vec::Vec;
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    fn assert_indexable<R>()
+    where
+        [u8]: IndexMut<R, Output = [u8]>,
+        str: IndexMut<R, Output = str>,
+    {
+    }
+
+    fn assert_array_indexable<R>()
+    where
+        [u8; 0]: IndexMut<R, Output = [u8]>,
+        [u8; 4]: IndexMut<R, Output = [u8]>,
+        [u8; 32]: IndexMut<R, Output = [u8]>,
+    {
+    }
+
+    #[test]
+    fn impls_exist() {
+        assert_indexable::<RangeFromExclusive<usize>>();
+        assert_indexable::<RangeFromExclusiveToExclusive<usize>>();
+        assert_indexable::<RangeFromExclusiveToInclusive<usize>>();
+    }
+
+    #[test]
+    fn array_impls_exist() {
+        assert_array_indexable::<RangeFromExclusive<usize>>();
+        assert_array_indexable::<RangeFromExclusiveToExclusive<usize>>();
+        assert_array_indexable::<RangeFromExclusiveToInclusive<usize>>();
+    }
+
+    #[test]
+    fn array_index() {
+        let array = [0, 1, 2, 3, 4];
+
+        assert_eq!(
+            Index::index(&array, RangeFromExclusiveToInclusive { start: 1, end: 3 }),
+            [2, 3]
+        );
+    }
+
+    #[test]
+    fn array_index_mut() {
+        let mut array = [0, 1, 2, 3, 4];
+
+        IndexMut::index_mut(&mut array, RangeFromExclusive { start: 2 }).copy_from_slice(&[5, 6]);
+
+        assert_eq!(array, [0, 1, 2, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn array_out_of_bounds() {
+        let array = [0, 1, 2, 3, 4];
+
+        Index::index(&array, RangeFromExclusiveToExclusive { start: 1, end: 6 });
+    }
+
+    #[test]
+    fn slice_range_from_exclusive() {
+        let slice = [0, 1, 2, 3, 4];
+
+        assert_eq!(slice[RangeFromExclusive { start: 1 }], [2, 3, 4]);
+    }
+
+    #[test]
+    fn slice_range_from_exclusive_to_exclusive() {
+        let slice = [0, 1, 2, 3, 4];
+
+        assert_eq!(
+            slice[RangeFromExclusiveToExclusive { start: 1, end: 4 }],
+            [2, 3]
+        );
+    }
+
+    #[test]
+    fn slice_range_from_exclusive_to_inclusive() {
+        let slice = [0, 1, 2, 3, 4];
+
+        assert_eq!(
+            slice[RangeFromExclusiveToInclusive { start: 1, end: 4 }],
+            [2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn slice_index_mut() {
+        let mut slice = [0, 1, 2, 3, 4];
+
+        slice[RangeFromExclusiveToInclusive { start: 0, end: 2 }].copy_from_slice(&[5, 6]);
+
+        assert_eq!(slice, [0, 5, 6, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn slice_out_of_bounds() {
+        let slice = [0, 1, 2, 3, 4];
+
+        slice.index(RangeFromExclusiveToInclusive { start: 1, end: 5 });
+    }
+
+    #[test]
+    fn str_range_from_exclusive() {
+        assert_eq!(&"hello"[RangeFromExclusive { start: 0 }], "ello");
+    }
+
+    #[test]
+    fn str_range_from_exclusive_to_exclusive() {
+        assert_eq!(
+            &"hello"[RangeFromExclusiveToExclusive { start: 0, end: 4 }],
+            "ell"
+        );
+    }
+
+    #[test]
+    fn str_range_from_exclusive_to_inclusive() {
+        assert_eq!(
+            &"hello"[RangeFromExclusiveToInclusive { start: 0, end: 4 }],
+            "ello"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a char boundary")]
+    fn str_not_char_boundary() {
+        let _ = &"héllo"[RangeFromExclusive { start: 1 }];
+    }
+
+    /// Returns the part of `s` after its first byte, through a generic `Index` bound.
+    #[cfg(feature = "alloc")]
+    fn tail<S>(s: &S) -> &str
+    where
+        S: Index<RangeFromExclusive<usize>, Output = str> + ?Sized,
+    {
+        &s[RangeFromExclusive { start: 0 }]
+    }
+
+    /// Returns the elements of `s` strictly between its first and last, through a generic `Index`
+    /// bound.
+    #[cfg(feature = "alloc")]
+    fn interior<S>(s: &S, len: usize) -> &[u8]
+    where
+        S: Index<RangeFromExclusiveToExclusive<usize>, Output = [u8]> + ?Sized,
+    {
+        &s[RangeFromExclusiveToExclusive {
+            start: 0,
+            end: len - 1,
+        }]
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cow_str_generic_bound() {
+        let borrowed: Cow<str> = Cow::Borrowed("hello");
+        let owned: Cow<str> = Cow::Owned(String::from("world"));
+
+        assert_eq!(tail(&borrowed), "ello");
+        assert_eq!(tail(&owned), "orld");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cow_slice_generic_bound() {
+        let borrowed: Cow<[u8]> = Cow::Borrowed(&[0, 1, 2, 3, 4]);
+        let owned: Cow<[u8]> = Cow::Owned((5..10).collect::<Vec<_>>());
+
+        assert_eq!(interior(&borrowed, 5), [1, 2, 3]);
+        assert_eq!(interior(&owned, 5), [6, 7, 8]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cow_index() {
+        let cow: Cow<[u8]> = Cow::Borrowed(&[0, 1, 2, 3, 4]);
+
+        assert_eq!(
+            Index::index(&cow, RangeFromExclusiveToInclusive { start: 1, end: 3 }),
+            [2, 3]
+        );
+        assert_eq!(
+            Index::index(
+                &Cow::Borrowed("hello"),
+                RangeFromExclusiveToInclusive { start: 1, end: 3 }
+            ),
+            "ll"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn cow_slice_out_of_bounds() {
+        let cow: Cow<[u8]> = Cow::Borrowed(&[0, 1, 2, 3, 4]);
+
+        Index::index(&cow, RangeFromExclusiveToExclusive { start: 1, end: 6 });
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "is not a char boundary")]
+    fn cow_str_not_char_boundary() {
+        let cow: Cow<str> = Cow::Owned(String::from("héllo"));
+
+        Index::index(&cow, RangeFromExclusive { start: 1 });
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn pointer_slice_generic_bound() {
+        let boxed: Box<[u8]> = (0..5).collect::<Vec<_>>().into_boxed_slice();
+        let rc: Rc<[u8]> = Rc::from((0..5).collect::<Vec<_>>());
+
+        assert_eq!(interior(&boxed, 5), [1, 2, 3]);
+        assert_eq!(interior(&rc, 5), [1, 2, 3]);
+    }
+
+    #[cfg(all(feature = "alloc", alloc_sync))]
+    #[test]
+    fn arc_slice_generic_bound() {
+        let arc: Arc<[u8]> = Arc::from((0..5).collect::<Vec<_>>());
+
+        assert_eq!(interior(&arc, 5), [1, 2, 3]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn pointer_str_generic_bound() {
+        let boxed: Box<str> = String::from("hello").into_boxed_str();
+        let rc: Rc<str> = Rc::from("hello");
+
+        assert_eq!(tail(&boxed), "ello");
+        assert_eq!(tail(&rc), "ello");
+    }
+
+    #[cfg(all(feature = "alloc", alloc_sync))]
+    #[test]
+    fn arc_str_generic_bound() {
+        let arc: Arc<str> = Arc::from("hello");
+
+        assert_eq!(tail(&arc), "ello");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn box_index_mut() {
+        let mut boxed: Box<[u8]> = (0..5).collect::<Vec<_>>().into_boxed_slice();
+
+        IndexMut::index_mut(
+            &mut boxed,
+            RangeFromExclusiveToInclusive { start: 0, end: 2 },
+        )
+        .copy_from_slice(&[5, 6]);
+
+        assert_eq!(&*boxed, [0, 5, 6, 3, 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn box_str_index_mut() {
+        let mut boxed: Box<str> = String::from("hello").into_boxed_str();
+
+        IndexMut::index_mut(&mut boxed, RangeFromExclusive { start: 0 }).make_ascii_uppercase();
+
+        assert_eq!(&*boxed, "hELLO");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn rc_slice_out_of_bounds() {
+        let rc: Rc<[u8]> = Rc::from((0..5).collect::<Vec<_>>());
+
+        Index::index(&rc, RangeFromExclusiveToInclusive { start: 1, end: 5 });
+    }
+
+    #[cfg(all(feature = "alloc", alloc_sync))]
+    #[test]
+    #[should_panic(expected = "is not a char boundary")]
+    fn arc_str_not_char_boundary() {
+        let arc: Arc<str> = Arc::from("héllo");
+
+        Index::index(&arc, RangeFromExclusive { start: 1 });
+    }
+}
@@ -0,0 +1,272 @@
+//! Interoperability with [`miette`](https://docs.rs/miette).
+//!
+//! A [`SourceSpan`] is an offset paired with a length. Conversions into a `SourceSpan` use the
+//! checked decomposition of [`to_offset_len()`], and never fail: a range that contains no bytes is
+//! converted into a zero-length span at the position where it would have started.
+//!
+//! [`SourceSpan`]: miette::SourceSpan
+//! [`to_offset_len()`]: ::RangeFromExclusiveToExclusive::to_offset_len
+
+use core::cmp;
+use core::convert::TryFrom;
+use core::fmt;
+use miette::SourceSpan;
+use std::error::Error;
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// An error converting a [`SourceSpan`] into a range.
+///
+/// [`SourceSpan`]: miette::SourceSpan
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromSourceSpanError {
+    /// The span begins at offset `0`, so the range would need an exclusive `start` bound below
+    /// `0`.
+    OffsetIsZero,
+    /// The end of the span cannot be represented in a `usize`.
+    EndOverflow,
+}
+
+impl fmt::Display for FromSourceSpanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FromSourceSpanError::OffsetIsZero => f.write_str(
+                "source span begins at offset 0 and cannot be represented with an exclusive start bound",
+            ),
+            FromSourceSpanError::EndOverflow => {
+                f.write_str("source span ends beyond the maximum `usize`")
+            }
+        }
+    }
+}
+
+impl Error for FromSourceSpanError {}
+
+impl From<RangeFromExclusiveToExclusive<usize>> for SourceSpan {
+    #[inline]
+    fn from(range: RangeFromExclusiveToExclusive<usize>) -> Self {
+        match range.to_offset_len() {
+            Some((offset, len)) => SourceSpan::new(offset.into(), len),
+            // An empty range sits at its first index, unless its bounds are equal, in which case it
+            // sits at its end. Inverted ranges are placed at their end.
+            None => SourceSpan::new(cmp::min(range.start.saturating_add(1), range.end).into(), 0),
+        }
+    }
+}
+
+impl From<RangeFromExclusiveToInclusive<usize>> for SourceSpan {
+    #[inline]
+    fn from(range: RangeFromExclusiveToInclusive<usize>) -> Self {
+        match range.to_offset_len() {
+            Some((offset, len)) => SourceSpan::new(offset.into(), len),
+            // An empty range sits at its first index. Inverted ranges are placed just past their
+            // end.
+            None => SourceSpan::new(
+                cmp::min(range.start.saturating_add(1), range.end.saturating_add(1)).into(),
+                0,
+            ),
+        }
+    }
+}
+
+impl TryFrom<SourceSpan> for RangeFromExclusiveToExclusive<usize> {
+    type Error = FromSourceSpanError;
+
+    #[inline]
+    fn try_from(span: SourceSpan) -> Result<Self, Self::Error> {
+        if span.offset().checked_add(span.len()).is_none() {
+            return Err(FromSourceSpanError::EndOverflow);
+        }
+        RangeFromExclusiveToExclusive::from_offset_len(span.offset(), span.len())
+            .ok_or(FromSourceSpanError::OffsetIsZero)
+    }
+}
+
+impl TryFrom<SourceSpan> for RangeFromExclusiveToInclusive<usize> {
+    type Error = FromSourceSpanError;
+
+    #[inline]
+    fn try_from(span: SourceSpan) -> Result<Self, Self::Error> {
+        if span.offset() == 0 {
+            return Err(FromSourceSpanError::OffsetIsZero);
+        }
+        RangeFromExclusiveToInclusive::from_offset_len(span.offset(), span.len())
+            .ok_or(FromSourceSpanError::EndOverflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+    use core::fmt;
+    use core::iter;
+    use impl_miette::FromSourceSpanError;
+    use miette::{Diagnostic, LabeledSpan, SourceCode, SourceSpan};
+    use std::boxed::Box;
+    use std::error::Error;
+    use std::vec::Vec;
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    /// A diagnostic pointing into a small source text.
+    #[derive(Debug)]
+    struct UnclosedString {
+        source: &'static str,
+        contents: SourceSpan,
+        quote: SourceSpan,
+    }
+
+    impl fmt::Display for UnclosedString {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("unclosed string")
+        }
+    }
+
+    impl Error for UnclosedString {}
+
+    impl Diagnostic for UnclosedString {
+        fn source_code(&self) -> Option<&dyn SourceCode> {
+            Some(&self.source)
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            Some(Box::new(
+                iter::once(LabeledSpan::at(self.contents, "string contents"))
+                    .chain(iter::once(LabeledSpan::at(self.quote, "opening quote"))),
+            ))
+        }
+    }
+
+    #[test]
+    fn diagnostic_labels() {
+        let source = r#"let s = "abc;"#;
+        let diagnostic = UnclosedString {
+            source,
+            // Exclusive of the opening quote at byte 8.
+            contents: RangeFromExclusiveToExclusive { start: 8, end: 13 }.into(),
+            quote: RangeFromExclusiveToInclusive { start: 7, end: 8 }.into(),
+        };
+
+        let labels = diagnostic.labels().unwrap().collect::<Vec<_>>();
+        assert_eq!(labels[0].offset(), 9);
+        assert_eq!(labels[0].len(), 4);
+        assert_eq!(labels[1].offset(), 8);
+        assert_eq!(labels[1].len(), 1);
+
+        let contents = diagnostic
+            .source_code()
+            .unwrap()
+            .read_span(&diagnostic.contents, 0, 0)
+            .unwrap();
+        assert_eq!(contents.data(), b"abc;");
+    }
+
+    #[test]
+    fn to_exclusive_into_span() {
+        let span = SourceSpan::from(RangeFromExclusiveToExclusive { start: 1, end: 5 });
+
+        assert_eq!(span, SourceSpan::new(2.into(), 3));
+    }
+
+    #[test]
+    fn to_exclusive_into_span_empty() {
+        assert_eq!(
+            SourceSpan::from(RangeFromExclusiveToExclusive { start: 4, end: 4 }),
+            SourceSpan::new(4.into(), 0)
+        );
+        assert_eq!(
+            SourceSpan::from(RangeFromExclusiveToExclusive { start: 3, end: 4 }),
+            SourceSpan::new(4.into(), 0)
+        );
+        assert_eq!(
+            SourceSpan::from(RangeFromExclusiveToExclusive {
+                start: usize::max_value(),
+                end: usize::max_value(),
+            }),
+            SourceSpan::new(usize::max_value().into(), 0)
+        );
+    }
+
+    #[test]
+    fn to_inclusive_into_span() {
+        let span = SourceSpan::from(RangeFromExclusiveToInclusive { start: 1, end: 5 });
+
+        assert_eq!(span, SourceSpan::new(2.into(), 4));
+    }
+
+    #[test]
+    fn to_inclusive_into_span_empty() {
+        assert_eq!(
+            SourceSpan::from(RangeFromExclusiveToInclusive { start: 4, end: 4 }),
+            SourceSpan::new(5.into(), 0)
+        );
+    }
+
+    #[test]
+    fn to_exclusive_try_from_span() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::try_from(SourceSpan::new(2.into(), 3)),
+            RangeFromExclusiveToExclusive { start: 1, end: 5 }
+        );
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::try_from(SourceSpan::new(0.into(), 0)),
+            RangeFromExclusiveToExclusive { start: 0, end: 0 }
+        );
+    }
+
+    #[test]
+    fn to_exclusive_try_from_span_errors() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive::try_from(SourceSpan::new(0.into(), 3)),
+            Err(FromSourceSpanError::OffsetIsZero)
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive::try_from(SourceSpan::new(usize::max_value().into(), 1)),
+            Err(FromSourceSpanError::EndOverflow)
+        );
+    }
+
+    #[test]
+    fn to_inclusive_try_from_span() {
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive::try_from(SourceSpan::new(2.into(), 4)),
+            RangeFromExclusiveToInclusive { start: 1, end: 5 }
+        );
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive::try_from(SourceSpan::new(usize::max_value().into(), 1)),
+            RangeFromExclusiveToInclusive {
+                start: usize::max_value() - 1,
+                end: usize::max_value(),
+            }
+        );
+    }
+
+    #[test]
+    fn to_inclusive_try_from_span_errors() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::try_from(SourceSpan::new(0.into(), 0)),
+            Err(FromSourceSpanError::OffsetIsZero)
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive::try_from(SourceSpan::new(usize::max_value().into(), 2)),
+            Err(FromSourceSpanError::EndOverflow)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for start in 0..10 {
+            for end in start + 2..10 {
+                let exclusive = RangeFromExclusiveToExclusive { start, end };
+                assert_ok_eq!(
+                    RangeFromExclusiveToExclusive::try_from(SourceSpan::from(exclusive)),
+                    exclusive
+                );
+
+                let inclusive = RangeFromExclusiveToInclusive { start, end };
+                assert_ok_eq!(
+                    RangeFromExclusiveToInclusive::try_from(SourceSpan::from(inclusive)),
+                    inclusive
+                );
+            }
+        }
+    }
+}
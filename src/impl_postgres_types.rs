@@ -0,0 +1,672 @@
+//! Conversions to and from PostgreSQL range values using
+//! [`postgres-types`](https://docs.rs/postgres-types).
+//!
+//! Each range type is encoded in the binary range wire format with an exclusive lower bound:
+//!
+//! - [`RangeFromExclusive`] encodes as `(start,)`.
+//! - [`RangeFromExclusiveToExclusive`] encodes as `(start,end)`.
+//! - [`RangeFromExclusiveToInclusive`] encodes as `(start,end]`.
+//!
+//! The impls are generic over the element type, so any element type implementing [`ToSql`] or
+//! [`FromSql`] is supported, including `i32`, `i64`, and the timestamp types provided through
+//! `postgres-types`' own features.
+//!
+//! Decoding only accepts a value if its bounds can be expressed by the target range type. PostgreSQL
+//! canonicalizes the discrete range types `int4range`, `int8range`, and `daterange` to the form
+//! `[start,end)`, so for those types an inclusive lower bound is converted to the exclusive bound
+//! one value below it, and an exclusive upper bound is converted to the inclusive bound one value
+//! below it when decoding into a [`RangeFromExclusiveToInclusive`]. Conversion fails if the
+//! adjusted bound would overflow. Continuous range types such as `tstzrange` and `numrange` keep
+//! the bounds they were written with, and an inclusive lower bound is rejected for them, as is the
+//! empty range for every type.
+//!
+//! [`ToSql`]: postgres_types::ToSql
+//! [`FromSql`]: postgres_types::FromSql
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, FromSqlOwned, IsNull, Kind, ToSql, Type};
+use std::boxed::Box;
+use std::error::Error;
+use std::vec::Vec;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+const RANGE_EMPTY: u8 = 0b0000_0001;
+const RANGE_LOWER_INCLUSIVE: u8 = 0b0000_0010;
+const RANGE_UPPER_INCLUSIVE: u8 = 0b0000_0100;
+const RANGE_LOWER_UNBOUNDED: u8 = 0b0000_1000;
+const RANGE_UPPER_UNBOUNDED: u8 = 0b0001_0000;
+
+/// One side of a decoded range value.
+enum RangeBound<T> {
+    Inclusive(T),
+    Exclusive(T),
+    Unbounded,
+}
+
+/// A single encoded bound of a range value, `None` if the bound is `NULL`.
+type RawBound<'a> = RangeBound<Option<&'a [u8]>>;
+
+/// Returns the element type of a range type.
+fn range_member(ty: &Type) -> Result<&Type, Box<dyn Error + Sync + Send>> {
+    match *ty.kind() {
+        Kind::Range(ref member) => Ok(member),
+        _ => Err("expected a range type".into()),
+    }
+}
+
+/// Encodes a range value with an exclusive lower bound of `start`, followed by `end`, if any.
+///
+/// `flags` describes the upper bound.
+fn encode_range<T>(
+    member: &Type,
+    flags: u8,
+    start: &T,
+    end: Option<&T>,
+    buf: &mut BytesMut,
+) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+where
+    T: ToSql,
+{
+    buf.extend_from_slice(&[flags]);
+    encode_bound(start, member, buf)?;
+    if let Some(end) = end {
+        encode_bound(end, member, buf)?;
+    }
+    Ok(IsNull::No)
+}
+
+/// Encodes a single bound of a range, prefixed by its length.
+fn encode_bound<T>(
+    value: &T,
+    member: &Type,
+    buf: &mut BytesMut,
+) -> Result<(), Box<dyn Error + Sync + Send>>
+where
+    T: ToSql,
+{
+    let base = buf.len();
+    buf.extend_from_slice(&[0; 4]);
+    let len = match value.to_sql(member, buf)? {
+        IsNull::No => {
+            let len = buf.len() - base - 4;
+            if len > i32::max_value() as usize {
+                return Err("value too large to transmit".into());
+            }
+            len as i32
+        }
+        IsNull::Yes => -1,
+    };
+    buf[base..base + 4].copy_from_slice(&len.to_be_bytes());
+    Ok(())
+}
+
+/// Returns whether `ty` is a range type whose elements are accepted by `accepts`.
+fn accepts_range<F>(ty: &Type, accepts: F) -> bool
+where
+    F: FnOnce(&Type) -> bool,
+{
+    match *ty.kind() {
+        Kind::Range(ref member) => accepts(member),
+        _ => false,
+    }
+}
+
+/// Decodes a range value, rejecting the empty range.
+fn decode_range(raw: &[u8]) -> Result<(RawBound<'_>, RawBound<'_>), Box<dyn Error + Sync + Send>> {
+    let (&flags, mut buf) = raw.split_first().ok_or("invalid message size")?;
+    if flags & RANGE_EMPTY != 0 {
+        return Err("cannot convert an empty range into an exclusive range".into());
+    }
+    let lower = decode_bound(
+        &mut buf,
+        flags,
+        RANGE_LOWER_UNBOUNDED,
+        RANGE_LOWER_INCLUSIVE,
+    )?;
+    let upper = decode_bound(
+        &mut buf,
+        flags,
+        RANGE_UPPER_UNBOUNDED,
+        RANGE_UPPER_INCLUSIVE,
+    )?;
+    if !buf.is_empty() {
+        return Err("invalid message size".into());
+    }
+    Ok((lower, upper))
+}
+
+/// Decodes a single length-prefixed bound of a range, advancing `buf` past it.
+fn decode_bound<'a>(
+    buf: &mut &'a [u8],
+    flags: u8,
+    unbounded: u8,
+    inclusive: u8,
+) -> Result<RawBound<'a>, Box<dyn Error + Sync + Send>> {
+    if flags & unbounded != 0 {
+        return Ok(RangeBound::Unbounded);
+    }
+    if buf.len() < 4 {
+        return Err("invalid message size".into());
+    }
+    let (len, rest) = buf.split_at(4);
+    let len = i32::from_be_bytes([len[0], len[1], len[2], len[3]]);
+    *buf = rest;
+    let value = if len < 0 {
+        None
+    } else {
+        let len = len as usize;
+        if buf.len() < len {
+            return Err("invalid message size".into());
+        }
+        let (value, rest) = buf.split_at(len);
+        *buf = rest;
+        Some(value)
+    };
+    if flags & inclusive != 0 {
+        Ok(RangeBound::Inclusive(value))
+    } else {
+        Ok(RangeBound::Exclusive(value))
+    }
+}
+
+/// Returns the encoding of the value one below the encoded `raw` value of a discrete range type, or
+/// `None` if `member` is not the element type of a discrete range type.
+///
+/// Fails if the value has no predecessor.
+fn decode_predecessor(
+    member: &Type,
+    raw: Option<&[u8]>,
+) -> Result<Option<Vec<u8>>, Box<dyn Error + Sync + Send>> {
+    let raw = raw.ok_or("cannot convert a range with a NULL bound")?;
+    if *member == Type::INT4 {
+        let value = i32::from_sql(member, raw)?;
+        let value = value.checked_sub(1).ok_or("range bound out of range")?;
+        Ok(Some(value.to_be_bytes().to_vec()))
+    } else if *member == Type::INT8 {
+        let value = i64::from_sql(member, raw)?;
+        let value = value.checked_sub(1).ok_or("range bound out of range")?;
+        Ok(Some(value.to_be_bytes().to_vec()))
+    } else if *member == Type::DATE {
+        // Dates are encoded as days since 2000-01-01, with the extremes reserved for `-infinity`
+        // and `infinity`.
+        let value = i32::from_sql(&Type::INT4, raw)?;
+        if value == i32::max_value() || value <= i32::min_value() + 1 {
+            return Err("range bound out of range".into());
+        }
+        Ok(Some((value - 1).to_be_bytes().to_vec()))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Decodes the exclusive lower bound of a range.
+///
+/// An inclusive lower bound is only accepted for discrete range types.
+fn decode_start<T>(member: &Type, lower: RawBound) -> Result<T, Box<dyn Error + Sync + Send>>
+where
+    T: FromSqlOwned,
+{
+    match lower {
+        RangeBound::Exclusive(raw) => T::from_sql_nullable(member, raw),
+        RangeBound::Inclusive(raw) => match decode_predecessor(member, raw)? {
+            Some(raw) => T::from_sql(member, &raw),
+            None => Err(
+                "cannot convert a range with an inclusive lower bound into an exclusive range"
+                    .into(),
+            ),
+        },
+        RangeBound::Unbounded => {
+            Err("cannot convert a range without a lower bound into an exclusive range".into())
+        }
+    }
+}
+
+impl<T> ToSql for RangeFromExclusive<T>
+where
+    T: ToSql,
+{
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        encode_range(
+            range_member(ty)?,
+            RANGE_UPPER_UNBOUNDED,
+            &self.start,
+            None,
+            out,
+        )
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_range(ty, T::accepts)
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<T> ToSql for RangeFromExclusiveToExclusive<T>
+where
+    T: ToSql,
+{
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        encode_range(range_member(ty)?, 0, &self.start, Some(&self.end), out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_range(ty, T::accepts)
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<T> ToSql for RangeFromExclusiveToInclusive<T>
+where
+    T: ToSql,
+{
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        encode_range(
+            range_member(ty)?,
+            RANGE_UPPER_INCLUSIVE,
+            &self.start,
+            Some(&self.end),
+            out,
+        )
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_range(ty, T::accepts)
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<'a, T> FromSql<'a> for RangeFromExclusive<T>
+where
+    T: FromSqlOwned,
+{
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let member = range_member(ty)?;
+        let (lower, upper) = decode_range(raw)?;
+        let start = decode_start(member, lower)?;
+        match upper {
+            RangeBound::Unbounded => Ok(RangeFromExclusive { start }),
+            _ => {
+                Err("cannot convert a range with an upper bound into a `RangeFromExclusive`".into())
+            }
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_range(ty, T::accepts)
+    }
+}
+
+impl<'a, T> FromSql<'a> for RangeFromExclusiveToExclusive<T>
+where
+    T: FromSqlOwned,
+{
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let member = range_member(ty)?;
+        let (lower, upper) = decode_range(raw)?;
+        let start = decode_start(member, lower)?;
+        match upper {
+            RangeBound::Exclusive(raw) => Ok(RangeFromExclusiveToExclusive {
+                start,
+                end: T::from_sql_nullable(member, raw)?,
+            }),
+            _ => Err(
+                "cannot convert a range without an exclusive upper bound into a `RangeFromExclusiveToExclusive`"
+                    .into(),
+            ),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_range(ty, T::accepts)
+    }
+}
+
+impl<'a, T> FromSql<'a> for RangeFromExclusiveToInclusive<T>
+where
+    T: FromSqlOwned,
+{
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let member = range_member(ty)?;
+        let (lower, upper) = decode_range(raw)?;
+        let start = decode_start(member, lower)?;
+        match upper {
+            RangeBound::Inclusive(raw) => Ok(RangeFromExclusiveToInclusive {
+                start,
+                end: T::from_sql_nullable(member, raw)?,
+            }),
+            RangeBound::Exclusive(raw) => match decode_predecessor(member, raw)? {
+                Some(raw) => Ok(RangeFromExclusiveToInclusive {
+                    start,
+                    end: T::from_sql(member, &raw)?,
+                }),
+                None => Err(
+                    "cannot convert a range with an exclusive upper bound into a `RangeFromExclusiveToInclusive`"
+                        .into(),
+                ),
+            },
+            RangeBound::Unbounded => Err(
+                "cannot convert a range without an inclusive upper bound into a `RangeFromExclusiveToInclusive`"
+                    .into(),
+            ),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_range(ty, T::accepts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+    use std::time::{Duration, SystemTime};
+    use std::vec::Vec;
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    const UPPER_UNBOUNDED: u8 = 0b0001_0000;
+    const LOWER_UNBOUNDED: u8 = 0b0000_1000;
+    const UPPER_INCLUSIVE: u8 = 0b0000_0100;
+    const LOWER_INCLUSIVE: u8 = 0b0000_0010;
+    const EMPTY: u8 = 0b0000_0001;
+
+    /// Builds a binary range value from a flags byte and its encoded bounds.
+    fn range_buf(flags: u8, bounds: &[&[u8]]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.push(flags);
+        for bound in bounds {
+            buf.extend_from_slice(&(bound.len() as i32).to_be_bytes());
+            buf.extend_from_slice(bound);
+        }
+        buf
+    }
+
+    fn to_sql<T>(value: &T, ty: &Type) -> Vec<u8>
+    where
+        T: ToSql,
+    {
+        let mut buf = BytesMut::new();
+        value.to_sql_checked(ty, &mut buf).unwrap();
+        buf.to_vec()
+    }
+
+    #[test]
+    fn range_from_exclusive_to_sql() {
+        assert_eq!(
+            to_sql(&RangeFromExclusive { start: 1i32 }, &Type::INT4_RANGE),
+            range_buf(UPPER_UNBOUNDED, &[&1i32.to_be_bytes()])
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_from_sql() {
+        assert_eq!(
+            RangeFromExclusive::<i32>::from_sql(
+                &Type::INT4_RANGE,
+                &range_buf(UPPER_UNBOUNDED, &[&1i32.to_be_bytes()])
+            )
+            .unwrap(),
+            RangeFromExclusive { start: 1 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_from_sql_with_upper_bound() {
+        assert!(RangeFromExclusive::<i32>::from_sql(
+            &Type::INT4_RANGE,
+            &range_buf(0, &[&1i32.to_be_bytes(), &4i32.to_be_bytes()])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_sql() {
+        assert_eq!(
+            to_sql(
+                &RangeFromExclusiveToExclusive {
+                    start: 1i64,
+                    end: 4
+                },
+                &Type::INT8_RANGE
+            ),
+            range_buf(0, &[&1i64.to_be_bytes(), &4i64.to_be_bytes()])
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_round_trip() {
+        let range = RangeFromExclusiveToExclusive {
+            start: -1i64,
+            end: 10,
+        };
+
+        assert_eq!(
+            RangeFromExclusiveToExclusive::<i64>::from_sql(
+                &Type::INT8_RANGE,
+                &to_sql(&range, &Type::INT8_RANGE)
+            )
+            .unwrap(),
+            range
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_sql_inclusive_upper() {
+        assert!(RangeFromExclusiveToExclusive::<i64>::from_sql(
+            &Type::INT8_RANGE,
+            &range_buf(UPPER_INCLUSIVE, &[&1i64.to_be_bytes(), &4i64.to_be_bytes()])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_to_sql() {
+        assert_eq!(
+            to_sql(
+                &RangeFromExclusiveToInclusive {
+                    start: 1i32,
+                    end: 4
+                },
+                &Type::INT4_RANGE
+            ),
+            range_buf(UPPER_INCLUSIVE, &[&1i32.to_be_bytes(), &4i32.to_be_bytes()])
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_round_trip() {
+        let range = RangeFromExclusiveToInclusive {
+            start: i32::min_value(),
+            end: i32::max_value(),
+        };
+
+        assert_eq!(
+            RangeFromExclusiveToInclusive::<i32>::from_sql(
+                &Type::INT4_RANGE,
+                &to_sql(&range, &Type::INT4_RANGE)
+            )
+            .unwrap(),
+            range
+        );
+    }
+
+    #[test]
+    fn timestamp_round_trip() {
+        let range = RangeFromExclusiveToInclusive {
+            start: SystemTime::UNIX_EPOCH,
+            end: SystemTime::UNIX_EPOCH + Duration::from_secs(60),
+        };
+
+        assert_eq!(
+            RangeFromExclusiveToInclusive::<SystemTime>::from_sql(
+                &Type::TSTZ_RANGE,
+                &to_sql(&range, &Type::TSTZ_RANGE)
+            )
+            .unwrap(),
+            range
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_from_sql_canonical() {
+        // `[2,)`, as PostgreSQL canonicalizes `(1,)`.
+        let raw = range_buf(LOWER_INCLUSIVE | UPPER_UNBOUNDED, &[&2i64.to_be_bytes()]);
+
+        assert_eq!(
+            RangeFromExclusive::<i64>::from_sql(&Type::INT8_RANGE, &raw).unwrap(),
+            RangeFromExclusive { start: 1 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_sql_canonical() {
+        // `[2,4)`, as PostgreSQL canonicalizes `(1,4)`.
+        let raw = range_buf(LOWER_INCLUSIVE, &[&2i32.to_be_bytes(), &4i32.to_be_bytes()]);
+
+        assert_eq!(
+            RangeFromExclusiveToExclusive::<i32>::from_sql(&Type::INT4_RANGE, &raw).unwrap(),
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_sql_canonical() {
+        // `[2,5)`, as PostgreSQL canonicalizes `(1,4]`.
+        let raw = range_buf(LOWER_INCLUSIVE, &[&2i32.to_be_bytes(), &5i32.to_be_bytes()]);
+
+        assert_eq!(
+            RangeFromExclusiveToInclusive::<i32>::from_sql(&Type::INT4_RANGE, &raw).unwrap(),
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_sql_canonical_round_trip() {
+        let range = RangeFromExclusiveToInclusive {
+            start: -1i64,
+            end: 10,
+        };
+        // The canonical form of the value written by `to_sql`.
+        let raw = range_buf(
+            LOWER_INCLUSIVE,
+            &[&0i64.to_be_bytes(), &11i64.to_be_bytes()],
+        );
+
+        assert_eq!(
+            to_sql(&range, &Type::INT8_RANGE),
+            range_buf(
+                UPPER_INCLUSIVE,
+                &[&(-1i64).to_be_bytes(), &10i64.to_be_bytes()]
+            )
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive::<i64>::from_sql(&Type::INT8_RANGE, &raw).unwrap(),
+            range
+        );
+    }
+
+    #[test]
+    fn date_from_sql_canonical() {
+        let raw = range_buf(LOWER_INCLUSIVE, &[&2i32.to_be_bytes(), &5i32.to_be_bytes()]);
+
+        assert_eq!(
+            RangeFromExclusiveToExclusive::<i32>::from_sql(&Type::DATE_RANGE, &raw).unwrap(),
+            RangeFromExclusiveToExclusive { start: 1, end: 5 }
+        );
+    }
+
+    #[test]
+    fn from_sql_inclusive_lower_overflow() {
+        let raw = range_buf(
+            LOWER_INCLUSIVE,
+            &[&i32::min_value().to_be_bytes(), &4i32.to_be_bytes()],
+        );
+
+        assert!(RangeFromExclusiveToExclusive::<i32>::from_sql(&Type::INT4_RANGE, &raw).is_err());
+    }
+
+    #[test]
+    fn from_sql_exclusive_upper_overflow() {
+        let raw = range_buf(
+            0,
+            &[
+                &i64::min_value().to_be_bytes(),
+                &i64::min_value().to_be_bytes(),
+            ],
+        );
+
+        assert!(RangeFromExclusiveToInclusive::<i64>::from_sql(&Type::INT8_RANGE, &raw).is_err());
+    }
+
+    #[test]
+    fn from_sql_date_infinity() {
+        let raw = range_buf(
+            LOWER_INCLUSIVE | UPPER_UNBOUNDED,
+            &[&i32::max_value().to_be_bytes()],
+        );
+
+        assert!(RangeFromExclusive::<i32>::from_sql(&Type::DATE_RANGE, &raw).is_err());
+    }
+
+    #[test]
+    fn from_sql_inclusive_lower_continuous() {
+        let start = to_sql(&SystemTime::UNIX_EPOCH, &Type::TIMESTAMPTZ);
+        let raw = range_buf(LOWER_INCLUSIVE | UPPER_UNBOUNDED, &[&start]);
+
+        assert!(RangeFromExclusive::<SystemTime>::from_sql(&Type::TSTZ_RANGE, &raw).is_err());
+    }
+
+    #[test]
+    fn from_sql_unbounded_lower() {
+        let raw = range_buf(LOWER_UNBOUNDED, &[&4i32.to_be_bytes()]);
+
+        assert!(RangeFromExclusiveToExclusive::<i32>::from_sql(&Type::INT4_RANGE, &raw).is_err());
+    }
+
+    #[test]
+    fn from_sql_empty() {
+        assert!(
+            RangeFromExclusiveToExclusive::<i32>::from_sql(&Type::INT4_RANGE, &[EMPTY]).is_err()
+        );
+    }
+
+    #[test]
+    fn accepts() {
+        assert!(<RangeFromExclusiveToExclusive<i32> as ToSql>::accepts(
+            &Type::INT4_RANGE
+        ));
+        assert!(!<RangeFromExclusiveToExclusive<i32> as ToSql>::accepts(
+            &Type::INT8_RANGE
+        ));
+        assert!(!<RangeFromExclusiveToExclusive<i32> as ToSql>::accepts(
+            &Type::INT4
+        ));
+        assert!(<RangeFromExclusive<i64> as FromSql>::accepts(
+            &Type::INT8_RANGE
+        ));
+    }
+
+    #[test]
+    fn to_sql_checked_wrong_type() {
+        let mut buf = BytesMut::new();
+
+        assert!(RangeFromExclusive { start: 1i32 }
+            .to_sql_checked(&Type::INT8_RANGE, &mut buf)
+            .is_err());
+    }
+}
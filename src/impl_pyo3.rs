@@ -0,0 +1,367 @@
+//! Conversions to and from Python objects using [`pyo3`](https://docs.rs/pyo3).
+//!
+//! Python has no exclusively-bounded range types, so each range in this crate is converted to the
+//! equivalent Python object with an inclusive start:
+//!
+//! - [`RangeFromExclusive`] converts to `slice(start + 1, None)`.
+//! - [`RangeFromExclusiveToExclusive`] converts to `range(start + 1, end)`.
+//! - [`RangeFromExclusiveToInclusive`] converts to `range(start + 1, end + 1)`.
+//!
+//! The adjustments are performed on Python integers, so they never overflow. Extraction accepts
+//! both `range` and `slice` objects and performs the reverse adjustments. A `TypeError` is raised
+//! if the object has a step other than `1`, or if it is missing a bound required by the range
+//! type. Note that slice bounds are taken literally: negative bounds are not interpreted relative
+//! to the end of a sequence.
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::types::{PyAnyMethods, PyRange, PySlice};
+use pyo3::{
+    Borrowed, Bound, FromPyObject, IntoPyObject, PyAny, PyErr, PyResult, PyTypeInfo, Python,
+};
+use std::format;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// Returns the `start` and `stop` of a Python `range` or `slice` object.
+///
+/// Raises a `TypeError` if `obj` is neither, if its step is not `1`, or if it has no start.
+fn extract_bounds<'py>(
+    obj: &Borrowed<'_, 'py, PyAny>,
+) -> PyResult<(Bound<'py, PyAny>, Option<Bound<'py, PyAny>>)> {
+    let kind = if obj.is_instance_of::<PyRange>() {
+        "range"
+    } else if obj.is_instance_of::<PySlice>() {
+        "slice"
+    } else {
+        return Err(PyTypeError::new_err(
+            "expected a `range` or `slice` object to convert into an exclusive range",
+        ));
+    };
+
+    let step = obj.getattr("step")?;
+    if !step.is_none() && !step.eq(1)? {
+        return Err(PyTypeError::new_err(format!(
+            "cannot convert a `{}` with step {} into an exclusive range; only a step of 1 is supported",
+            kind, step
+        )));
+    }
+
+    let start = obj.getattr("start")?;
+    if start.is_none() {
+        return Err(PyTypeError::new_err(format!(
+            "cannot convert a `{}` without a start bound into an exclusive range",
+            kind
+        )));
+    }
+    let stop = obj.getattr("stop")?;
+
+    Ok((start, if stop.is_none() { None } else { Some(stop) }))
+}
+
+/// Raises a `TypeError` for an object missing the stop bound required by `range_type`.
+fn missing_stop(range_type: &str) -> PyErr {
+    PyTypeError::new_err(format!(
+        "cannot convert an object without a stop bound into a `{}`",
+        range_type
+    ))
+}
+
+macro_rules! impl_pyo3 {
+    ($($t:ty),*) => {
+        $(
+            impl<'py> IntoPyObject<'py> for RangeFromExclusive<$t> {
+                type Target = PyAny;
+                type Output = Bound<'py, PyAny>;
+                type Error = PyErr;
+
+                fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+                    let start = self.start.into_pyobject(py)?.add(1)?;
+                    PySlice::type_object(py).call1((start, py.None()))
+                }
+            }
+
+            impl<'py> IntoPyObject<'py> for RangeFromExclusiveToExclusive<$t> {
+                type Target = PyAny;
+                type Output = Bound<'py, PyAny>;
+                type Error = PyErr;
+
+                fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+                    let start = self.start.into_pyobject(py)?.add(1)?;
+                    PyRange::type_object(py).call1((start, self.end))
+                }
+            }
+
+            impl<'py> IntoPyObject<'py> for RangeFromExclusiveToInclusive<$t> {
+                type Target = PyAny;
+                type Output = Bound<'py, PyAny>;
+                type Error = PyErr;
+
+                fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+                    let start = self.start.into_pyobject(py)?.add(1)?;
+                    let end = self.end.into_pyobject(py)?.add(1)?;
+                    PyRange::type_object(py).call1((start, end))
+                }
+            }
+
+            impl<'a, 'py> FromPyObject<'a, 'py> for RangeFromExclusive<$t> {
+                type Error = PyErr;
+
+                fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+                    let (start, stop) = extract_bounds(&obj)?;
+                    if stop.is_some() {
+                        return Err(PyTypeError::new_err(
+                            "cannot convert an object with a stop bound into a `RangeFromExclusive`",
+                        ));
+                    }
+                    Ok(RangeFromExclusive {
+                        start: start.sub(1)?.extract()?,
+                    })
+                }
+            }
+
+            impl<'a, 'py> FromPyObject<'a, 'py> for RangeFromExclusiveToExclusive<$t> {
+                type Error = PyErr;
+
+                fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+                    let (start, stop) = extract_bounds(&obj)?;
+                    let stop = stop.ok_or_else(|| missing_stop("RangeFromExclusiveToExclusive"))?;
+                    Ok(RangeFromExclusiveToExclusive {
+                        start: start.sub(1)?.extract()?,
+                        end: stop.extract()?,
+                    })
+                }
+            }
+
+            impl<'a, 'py> FromPyObject<'a, 'py> for RangeFromExclusiveToInclusive<$t> {
+                type Error = PyErr;
+
+                fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+                    let (start, stop) = extract_bounds(&obj)?;
+                    let stop = stop.ok_or_else(|| missing_stop("RangeFromExclusiveToInclusive"))?;
+                    Ok(RangeFromExclusiveToInclusive {
+                        start: start.sub(1)?.extract()?,
+                        end: stop.sub(1)?.extract()?,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_pyo3!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use pyo3::exceptions::{PyOverflowError, PyTypeError};
+    use pyo3::types::{PyAnyMethods, PyRange, PyRangeMethods, PySlice};
+    use pyo3::{IntoPyObject, PyTypeInfo, Python};
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    fn with_python<F>(f: F)
+    where
+        F: for<'py> FnOnce(Python<'py>),
+    {
+        Python::initialize();
+        Python::attach(f);
+    }
+
+    #[test]
+    fn range_from_exclusive_into_slice() {
+        with_python(|py| {
+            let slice = RangeFromExclusive { start: 1i32 }
+                .into_pyobject(py)
+                .unwrap();
+
+            assert!(slice.is_instance_of::<PySlice>());
+            assert_eq!(slice.getattr("start").unwrap().extract::<i32>().unwrap(), 2);
+            assert!(slice.getattr("stop").unwrap().is_none());
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_round_trip() {
+        with_python(|py| {
+            let range = RangeFromExclusive {
+                start: u64::max_value(),
+            };
+
+            assert_eq!(
+                range
+                    .into_pyobject(py)
+                    .unwrap()
+                    .extract::<RangeFromExclusive<u64>>()
+                    .unwrap(),
+                range
+            );
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_from_range() {
+        with_python(|py| {
+            let range = PyRange::new(py, 0, 10).unwrap();
+
+            let err = range.extract::<RangeFromExclusive<i64>>().unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_into_range() {
+        with_python(|py| {
+            let range = RangeFromExclusiveToExclusive {
+                start: 1i64,
+                end: 4,
+            }
+            .into_pyobject(py)
+            .unwrap()
+            .cast_into::<PyRange>()
+            .unwrap();
+
+            assert_eq!(range.start().unwrap(), 2);
+            assert_eq!(range.stop().unwrap(), 4);
+            assert_eq!(range.step().unwrap(), 1);
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_round_trip() {
+        with_python(|py| {
+            let range = RangeFromExclusiveToExclusive {
+                start: -1i64,
+                end: 10,
+            };
+
+            assert_eq!(
+                range
+                    .into_pyobject(py)
+                    .unwrap()
+                    .extract::<RangeFromExclusiveToExclusive<i64>>()
+                    .unwrap(),
+                range
+            );
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_slice() {
+        with_python(|py| {
+            let slice = PySlice::new(py, 0, 10, 1);
+
+            assert_eq!(
+                slice
+                    .extract::<RangeFromExclusiveToExclusive<i64>>()
+                    .unwrap(),
+                RangeFromExclusiveToExclusive { start: -1, end: 10 }
+            );
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_slice_without_stop() {
+        with_python(|py| {
+            let slice = PySlice::type_object(py).call1((0, py.None())).unwrap();
+
+            let err = slice
+                .extract::<RangeFromExclusiveToExclusive<i64>>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_slice_without_start() {
+        with_python(|py| {
+            let slice = PySlice::type_object(py).call1((py.None(), 10)).unwrap();
+
+            let err = slice
+                .extract::<RangeFromExclusiveToExclusive<i64>>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_rejects_step() {
+        with_python(|py| {
+            let range = PyRange::new_with_step(py, 0, 10, 2).unwrap();
+
+            let err = range
+                .extract::<RangeFromExclusiveToExclusive<i64>>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_underflow() {
+        with_python(|py| {
+            let range = PyRange::new(py, 0, 10).unwrap();
+
+            let err = range
+                .extract::<RangeFromExclusiveToExclusive<u64>>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyOverflowError>(py));
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_into_range() {
+        with_python(|py| {
+            let range = RangeFromExclusiveToInclusive {
+                start: 1i64,
+                end: 4,
+            }
+            .into_pyobject(py)
+            .unwrap()
+            .cast_into::<PyRange>()
+            .unwrap();
+
+            assert_eq!(range.start().unwrap(), 2);
+            assert_eq!(range.stop().unwrap(), 5);
+            assert_eq!(range.step().unwrap(), 1);
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_round_trip() {
+        with_python(|py| {
+            let range = RangeFromExclusiveToInclusive {
+                start: u8::max_value() - 1,
+                end: u8::max_value(),
+            };
+
+            assert_eq!(
+                range
+                    .into_pyobject(py)
+                    .unwrap()
+                    .extract::<RangeFromExclusiveToInclusive<u8>>()
+                    .unwrap(),
+                range
+            );
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_rejects_step() {
+        with_python(|py| {
+            let range = PyRange::new_with_step(py, 0, 10, 2).unwrap();
+
+            let err = range
+                .extract::<RangeFromExclusiveToInclusive<i64>>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_other_object() {
+        with_python(|py| {
+            let tuple = (0, 10).into_pyobject(py).unwrap();
+
+            let err = tuple
+                .extract::<RangeFromExclusiveToInclusive<i64>>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+}
@@ -0,0 +1,570 @@
+//! Random sub-range generation using [`rand`](https://docs.rs/rand).
+//!
+//! A range containing `n` values has `n + 1` cut positions, from before its first value to after
+//! its last. Every non-empty sub-range corresponds to exactly one pair of distinct cut positions,
+//! so sub-ranges are chosen uniformly by choosing a uniform pair of distinct cuts. Choosing the two
+//! bounds of the sub-range independently would not be uniform.
+//!
+//! The arithmetic is done on offsets from `start` in the unsigned type of the same width, so that
+//! ranges spanning the entire index type are supported.
+
+#[cfg(feature = "rand-alloc")]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "rand-alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "rand-alloc")]
+use rand::seq::index;
+use rand::{Rng, RngExt};
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+#[cold]
+#[inline(never)]
+fn empty_range_fail() -> ! {
+    panic!("attempted to choose a non-empty sub-range of an empty range");
+}
+
+/// Chooses cut positions `first <= second` uniformly from `0..=n`.
+///
+/// If `allow_equal` is `false`, the cuts are distinct, and `n` must be nonzero.
+macro_rules! choose_cuts {
+    ($rng:ident, $n:expr, $allow_equal:expr) => {
+        loop {
+            let first = $rng.random_range(0..=$n);
+            let second = $rng.random_range(0..=$n);
+            if first == second {
+                if $allow_equal {
+                    break (first, second);
+                }
+            } else if !$allow_equal || $rng.random::<bool>() {
+                // When equal cuts are allowed, each unordered pair of distinct cuts can be drawn in
+                // two orders. Only half of those draws are accepted so that every pair, distinct or
+                // not, is equally likely.
+                break if first < second {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+            }
+        }
+    };
+}
+
+/// Chooses `amount` distinct offsets uniformly from `0..len`, in an unspecified order.
+///
+/// `amount` must be no greater than `len`.
+#[cfg(feature = "rand-alloc")]
+fn sample_offsets<R>(rng: &mut R, len: u128, amount: usize) -> Vec<u128>
+where
+    R: Rng + ?Sized,
+{
+    if len <= usize::max_value() as u128 {
+        index::sample(rng, len as usize, amount)
+            .into_iter()
+            .map(|offset| offset as u128)
+            .collect()
+    } else {
+        // The range is larger than any number of values that could be requested, so repeated draws
+        // are rare.
+        let mut chosen = BTreeSet::new();
+        let mut offsets = Vec::with_capacity(amount);
+        while offsets.len() < amount {
+            let offset = rng.random_range(0..len);
+            if chosen.insert(offset) {
+                offsets.push(offset);
+            }
+        }
+        offsets
+    }
+}
+
+macro_rules! impl_sample_distinct {
+    ($range:ident, $t:ident, $u:ident, $excluded_end:expr) => {
+        #[cfg(feature = "rand-alloc")]
+        impl $range<$t> {
+            /// Returns `amount` distinct values chosen uniformly from the values contained in the
+            /// range, in an unspecified order.
+            ///
+            /// If `amount` is greater than the number of values in the range, every value in the
+            /// range is returned. The range is never materialized, so this is efficient for
+            /// arbitrarily large ranges.
+            pub fn sample_distinct<R>(&self, rng: &mut R, amount: usize) -> Vec<$t>
+            where
+                R: Rng + ?Sized,
+            {
+                let len = if self.start >= self.end {
+                    0
+                } else {
+                    (self.end.wrapping_sub(self.start) as $u) - $excluded_end
+                };
+                let amount = if (len as u128) < amount as u128 {
+                    len as usize
+                } else {
+                    amount
+                };
+                sample_offsets(rng, len as u128, amount)
+                    .into_iter()
+                    .map(|offset| self.start.wrapping_add(offset as $t).wrapping_add(1))
+                    .collect()
+            }
+        }
+    };
+}
+
+macro_rules! impl_rand {
+    ($($t:ident, $u:ident;)*) => {
+        $(
+            impl RangeFromExclusiveToInclusive<$t> {
+                /// Returns a uniformly chosen non-empty sub-range of the range.
+                ///
+                /// Every non-empty range containing only values contained in `self` is equally
+                /// likely.
+                ///
+                /// # Panics
+                /// Panics if the range is empty.
+                pub fn random_subrange<R>(&self, rng: &mut R) -> Self
+                where
+                    R: Rng + ?Sized,
+                {
+                    if self.start >= self.end {
+                        empty_range_fail();
+                    }
+                    let n = self.end.wrapping_sub(self.start) as $u;
+                    let (first, second) = choose_cuts!(rng, n, false);
+                    RangeFromExclusiveToInclusive {
+                        start: self.start.wrapping_add(first as $t),
+                        end: self.start.wrapping_add(second as $t),
+                    }
+                }
+
+                /// Returns a uniformly chosen sub-range of the range, which may be empty.
+                ///
+                /// Every non-empty sub-range is equally likely, as is an empty range at each
+                /// position within `self`, from before its first value to after its last. Empty
+                /// sub-ranges have equal bounds. If `self` is empty, the empty range at `start` is
+                /// returned.
+                pub fn random_subrange_or_empty<R>(&self, rng: &mut R) -> Self
+                where
+                    R: Rng + ?Sized,
+                {
+                    if self.start >= self.end {
+                        return RangeFromExclusiveToInclusive {
+                            start: self.start,
+                            end: self.start,
+                        };
+                    }
+                    let n = self.end.wrapping_sub(self.start) as $u;
+                    let (first, second) = choose_cuts!(rng, n, true);
+                    RangeFromExclusiveToInclusive {
+                        start: self.start.wrapping_add(first as $t),
+                        end: self.start.wrapping_add(second as $t),
+                    }
+                }
+            }
+
+            impl RangeFromExclusiveToExclusive<$t> {
+                /// Returns a uniformly chosen non-empty sub-range of the range.
+                ///
+                /// Every non-empty range containing only values contained in `self` is equally
+                /// likely.
+                ///
+                /// # Panics
+                /// Panics if the range is empty.
+                pub fn random_subrange<R>(&self, rng: &mut R) -> Self
+                where
+                    R: Rng + ?Sized,
+                {
+                    if self.start >= self.end || self.end.wrapping_sub(self.start) as $u == 1 {
+                        empty_range_fail();
+                    }
+                    let n = (self.end.wrapping_sub(self.start) as $u) - 1;
+                    let (first, second) = choose_cuts!(rng, n, false);
+                    RangeFromExclusiveToExclusive {
+                        start: self.start.wrapping_add(first as $t),
+                        end: self.start.wrapping_add(second as $t).wrapping_add(1),
+                    }
+                }
+
+                /// Returns a uniformly chosen sub-range of the range, which may be empty.
+                ///
+                /// Every non-empty sub-range is equally likely, as is an empty range at each
+                /// position within `self`, from before its first value to after its last. Empty
+                /// sub-ranges have equal bounds. If `self` is empty, the empty range at `start` is
+                /// returned.
+                pub fn random_subrange_or_empty<R>(&self, rng: &mut R) -> Self
+                where
+                    R: Rng + ?Sized,
+                {
+                    if self.start >= self.end || self.end.wrapping_sub(self.start) as $u == 1 {
+                        return RangeFromExclusiveToExclusive {
+                            start: self.start,
+                            end: self.start,
+                        };
+                    }
+                    let n = (self.end.wrapping_sub(self.start) as $u) - 1;
+                    let (first, second) = choose_cuts!(rng, n, true);
+                    if first == second {
+                        let start = self.start.wrapping_add(first as $t);
+                        RangeFromExclusiveToExclusive { start, end: start }
+                    } else {
+                        RangeFromExclusiveToExclusive {
+                            start: self.start.wrapping_add(first as $t),
+                            end: self.start.wrapping_add(second as $t).wrapping_add(1),
+                        }
+                    }
+                }
+            }
+
+            // An exclusive `end` contains one fewer value than an inclusive `end`.
+            impl_sample_distinct!(RangeFromExclusiveToInclusive, $t, $u, 0);
+            impl_sample_distinct!(RangeFromExclusiveToExclusive, $t, $u, 1);
+        )*
+    };
+}
+
+impl_rand! {
+    u8, u8;
+    u16, u16;
+    u32, u32;
+    u64, u64;
+    u128, u128;
+    usize, usize;
+    i8, u8;
+    i16, u16;
+    i32, u32;
+    i64, u64;
+    i128, u128;
+    isize, usize;
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+    #[cfg(feature = "rand-alloc")]
+    use std::collections::HashSet;
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    const SAMPLES: usize = 20_000;
+
+    #[test]
+    fn to_inclusive_subsets() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let range = RangeFromExclusiveToInclusive {
+            start: 2u32,
+            end: 6,
+        };
+        let mut counts = HashMap::new();
+
+        for _ in 0..SAMPLES {
+            let sub = range.random_subrange(&mut rng);
+            assert!(sub.start < sub.end);
+            assert!(sub.start >= range.start && sub.end <= range.end);
+            *counts.entry((sub.start, sub.end)).or_insert(0) += 1;
+        }
+
+        // 4 values give 10 non-empty sub-ranges, each of which should appear about 2000 times.
+        assert_eq!(counts.len(), 10);
+        for &count in counts.values() {
+            assert!(
+                count > 1700 && count < 2300,
+                "count {} is not uniform",
+                count
+            );
+        }
+        assert!(counts.contains_key(&(2, 3)));
+        assert!(counts.contains_key(&(5, 6)));
+        assert!(counts.contains_key(&(2, 6)));
+    }
+
+    #[test]
+    fn to_inclusive_subsets_or_empty() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let range = RangeFromExclusiveToInclusive {
+            start: 2u64,
+            end: 5,
+        };
+        let mut counts = HashMap::new();
+
+        for _ in 0..SAMPLES {
+            let sub = range.random_subrange_or_empty(&mut rng);
+            assert!(sub.start <= sub.end);
+            assert!(sub.start >= range.start && sub.end <= range.end);
+            *counts.entry((sub.start, sub.end)).or_insert(0) += 1;
+        }
+
+        // 3 values give 6 non-empty sub-ranges and 4 empty positions.
+        assert_eq!(counts.len(), 10);
+        for &count in counts.values() {
+            assert!(
+                count > 1700 && count < 2300,
+                "count {} is not uniform",
+                count
+            );
+        }
+        assert!(counts.contains_key(&(2, 2)));
+        assert!(counts.contains_key(&(5, 5)));
+    }
+
+    #[test]
+    fn to_exclusive_subsets() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        let range = RangeFromExclusiveToExclusive {
+            start: 2usize,
+            end: 7,
+        };
+        let mut counts = HashMap::new();
+
+        for _ in 0..SAMPLES {
+            let sub = range.random_subrange(&mut rng);
+            assert!(sub.end - sub.start >= 2);
+            assert!(sub.start >= range.start && sub.end <= range.end);
+            *counts.entry((sub.start, sub.end)).or_insert(0) += 1;
+        }
+
+        // 4 values give 10 non-empty sub-ranges.
+        assert_eq!(counts.len(), 10);
+        for &count in counts.values() {
+            assert!(
+                count > 1700 && count < 2300,
+                "count {} is not uniform",
+                count
+            );
+        }
+        assert!(counts.contains_key(&(2, 4)));
+        assert!(counts.contains_key(&(5, 7)));
+        assert!(counts.contains_key(&(2, 7)));
+    }
+
+    #[test]
+    fn to_exclusive_subsets_or_empty() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let range = RangeFromExclusiveToExclusive {
+            start: 2i16,
+            end: 6,
+        };
+        let mut counts = HashMap::new();
+
+        for _ in 0..SAMPLES {
+            let sub = range.random_subrange_or_empty(&mut rng);
+            assert!(sub.start == sub.end || sub.end - sub.start >= 2);
+            assert!(sub.start >= range.start && sub.end <= range.end);
+            *counts.entry((sub.start, sub.end)).or_insert(0) += 1;
+        }
+
+        // 3 values give 6 non-empty sub-ranges and 4 empty positions.
+        assert_eq!(counts.len(), 10);
+        for &count in counts.values() {
+            assert!(
+                count > 1700 && count < 2300,
+                "count {} is not uniform",
+                count
+            );
+        }
+        assert!(counts.contains_key(&(2, 2)));
+        assert!(counts.contains_key(&(5, 5)));
+    }
+
+    #[test]
+    fn full_range() {
+        let mut rng = SmallRng::seed_from_u64(4);
+
+        for _ in 0..1000 {
+            let sub = RangeFromExclusiveToInclusive::<i8>::FULL.random_subrange(&mut rng);
+            assert!(sub.start < sub.end);
+
+            let sub = RangeFromExclusiveToExclusive::<u8>::FULL.random_subrange(&mut rng);
+            assert!(sub.end - sub.start >= 2);
+
+            let sub =
+                RangeFromExclusiveToInclusive::<u128>::FULL.random_subrange_or_empty(&mut rng);
+            assert!(sub.start <= sub.end);
+        }
+    }
+
+    #[test]
+    fn single_value() {
+        let mut rng = SmallRng::seed_from_u64(5);
+
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -3i32,
+                end: -2
+            }
+            .random_subrange(&mut rng),
+            RangeFromExclusiveToInclusive { start: -3, end: -2 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: -3i64,
+                end: -1
+            }
+            .random_subrange(&mut rng),
+            RangeFromExclusiveToExclusive { start: -3, end: -1 }
+        );
+    }
+
+    #[test]
+    fn empty_or_empty() {
+        let mut rng = SmallRng::seed_from_u64(6);
+
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 3u8, end: 4 }.random_subrange_or_empty(&mut rng),
+            RangeFromExclusiveToExclusive { start: 3, end: 3 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 5isize,
+                end: 2
+            }
+            .random_subrange_or_empty(&mut rng),
+            RangeFromExclusiveToInclusive { start: 5, end: 5 }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to choose a non-empty sub-range of an empty range")]
+    fn to_inclusive_empty() {
+        let mut rng = SmallRng::seed_from_u64(7);
+
+        RangeFromExclusiveToInclusive {
+            start: 3u16,
+            end: 3,
+        }
+        .random_subrange(&mut rng);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to choose a non-empty sub-range of an empty range")]
+    fn to_exclusive_empty() {
+        let mut rng = SmallRng::seed_from_u64(8);
+
+        RangeFromExclusiveToExclusive { start: 3i8, end: 4 }.random_subrange(&mut rng);
+    }
+
+    #[cfg(feature = "rand-alloc")]
+    #[test]
+    fn sample_distinct_to_exclusive() {
+        let mut rng = SmallRng::seed_from_u64(9);
+        let range = RangeFromExclusiveToExclusive {
+            start: 10u32,
+            end: 30,
+        };
+
+        for _ in 0..100 {
+            let values = range.sample_distinct(&mut rng, 5);
+            assert_eq!(values.len(), 5);
+            assert!(values.iter().all(|&value| value > 10 && value < 30));
+            assert_eq!(values.iter().collect::<HashSet<_>>().len(), 5);
+        }
+    }
+
+    #[cfg(feature = "rand-alloc")]
+    #[test]
+    fn sample_distinct_to_inclusive() {
+        let mut rng = SmallRng::seed_from_u64(10);
+        let range = RangeFromExclusiveToInclusive {
+            start: -5i8,
+            end: 5,
+        };
+        let mut seen = HashSet::new();
+
+        for _ in 0..100 {
+            let values = range.sample_distinct(&mut rng, 3);
+            assert_eq!(values.len(), 3);
+            assert!(values.iter().all(|&value| value > -5 && value <= 5));
+            assert_eq!(values.iter().collect::<HashSet<_>>().len(), 3);
+            seen.extend(values);
+        }
+        // Every contained value is eventually chosen, and the exclusive start never is.
+        assert_eq!(seen, (-4..=5).collect());
+    }
+
+    #[cfg(feature = "rand-alloc")]
+    #[test]
+    fn sample_distinct_uniform() {
+        let mut rng = SmallRng::seed_from_u64(11);
+        let range = RangeFromExclusiveToExclusive { start: 0u8, end: 5 };
+        let mut counts = HashMap::new();
+
+        for _ in 0..SAMPLES {
+            for value in range.sample_distinct(&mut rng, 2) {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+        }
+
+        // Each of the 4 values should appear in about half of the samples.
+        assert_eq!(counts.len(), 4);
+        for &count in counts.values() {
+            assert!(
+                count > 9500 && count < 10500,
+                "count {} is not uniform",
+                count
+            );
+        }
+    }
+
+    #[cfg(feature = "rand-alloc")]
+    #[test]
+    fn sample_distinct_more_than_len() {
+        let mut rng = SmallRng::seed_from_u64(12);
+
+        let mut values = RangeFromExclusiveToExclusive {
+            start: 3u16,
+            end: 8,
+        }
+        .sample_distinct(&mut rng, 10);
+        values.sort();
+        assert_eq!(values, [4, 5, 6, 7]);
+
+        let mut values = RangeFromExclusiveToInclusive {
+            start: 250u8,
+            end: 255,
+        }
+        .sample_distinct(&mut rng, usize::max_value());
+        values.sort();
+        assert_eq!(values, [251, 252, 253, 254, 255]);
+    }
+
+    #[cfg(feature = "rand-alloc")]
+    #[test]
+    fn sample_distinct_empty() {
+        let mut rng = SmallRng::seed_from_u64(13);
+
+        assert!(RangeFromExclusiveToExclusive {
+            start: 3i64,
+            end: 4
+        }
+        .sample_distinct(&mut rng, 2)
+        .is_empty());
+        assert!(RangeFromExclusiveToInclusive {
+            start: 3u64,
+            end: 3
+        }
+        .sample_distinct(&mut rng, 2)
+        .is_empty());
+        assert!(RangeFromExclusiveToInclusive {
+            start: 3usize,
+            end: 9
+        }
+        .sample_distinct(&mut rng, 0)
+        .is_empty());
+    }
+
+    #[cfg(feature = "rand-alloc")]
+    #[test]
+    fn sample_distinct_huge() {
+        let mut rng = SmallRng::seed_from_u64(14);
+
+        let values = RangeFromExclusiveToInclusive::<i128>::FULL.sample_distinct(&mut rng, 50);
+        assert_eq!(values.len(), 50);
+        assert!(values.iter().all(|&value| value != i128::min_value()));
+        assert_eq!(values.iter().collect::<HashSet<_>>().len(), 50);
+
+        let values = RangeFromExclusiveToExclusive::<u64>::FULL.sample_distinct(&mut rng, 50);
+        assert!(values
+            .iter()
+            .all(|&value| value != 0 && value != u64::max_value()));
+        assert_eq!(values.iter().collect::<HashSet<_>>().len(), 50);
+    }
+}
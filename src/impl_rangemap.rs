@@ -0,0 +1,416 @@
+//! Interoperability with [`rangemap`](https://docs.rs/rangemap).
+//!
+//! [`RangeMap`] keys are half-open [`Range`]s, so each bounded range type in this crate must have
+//! its exclusive `start` (and, for [`RangeFromExclusiveToInclusive`], its inclusive `end`) moved up
+//! by one before it can be used as a key. These adjustments are checked, and fail at the numeric
+//! boundary of the index type rather than panicking or wrapping.
+
+use core::fmt;
+use core::ops::Range;
+use rangemap::map::Overlapping;
+use rangemap::RangeMap;
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// An error converting a range into a [`RangeMap`] key.
+///
+/// [`RangeMap`]: rangemap::RangeMap
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RangeMapKeyError {
+    /// The exclusive `start` bound is the maximum value of the index type, so the first value
+    /// contained in the range cannot be represented.
+    StartIsMax,
+    /// The inclusive `end` bound is the maximum value of the index type, so the half-open end of
+    /// the key cannot be represented.
+    EndIsMax,
+}
+
+impl fmt::Display for RangeMapKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RangeMapKeyError::StartIsMax => f.write_str(
+                "exclusive start bound is the maximum value of its type and cannot be converted to a half-open range key",
+            ),
+            RangeMapKeyError::EndIsMax => f.write_str(
+                "inclusive end bound is the maximum value of its type and cannot be converted to a half-open range key",
+            ),
+        }
+    }
+}
+
+/// A range that can be converted into a half-open [`RangeMap`] key.
+///
+/// This is implemented for [`RangeFromExclusiveToExclusive`] and [`RangeFromExclusiveToInclusive`]
+/// over the primitive integer types.
+///
+/// # Example
+/// ```
+/// use more_ranges::{RangeFromExclusiveToInclusive, RangeMapKey, RangeMapKeyError};
+///
+/// assert_eq!(
+///     RangeFromExclusiveToInclusive { start: 1u8, end: 4 }.to_range_map_key(),
+///     Ok(2..5)
+/// );
+/// assert_eq!(
+///     RangeFromExclusiveToInclusive { start: 1u8, end: 255 }.to_range_map_key(),
+///     Err(RangeMapKeyError::EndIsMax)
+/// );
+/// ```
+///
+/// [`RangeMap`]: rangemap::RangeMap
+pub trait RangeMapKey<K> {
+    /// Converts `self` into the equivalent half-open [`Range`].
+    ///
+    /// # Errors
+    /// Returns an error if either adjusted bound is not representable in `K`.
+    ///
+    /// [`Range`]: core::ops::Range
+    fn to_range_map_key(self) -> Result<Range<K>, RangeMapKeyError>;
+}
+
+macro_rules! impl_range_map_key {
+    ($($t:ty),*) => {
+        $(
+            impl RangeMapKey<$t> for RangeFromExclusiveToExclusive<$t> {
+                #[inline]
+                fn to_range_map_key(self) -> Result<Range<$t>, RangeMapKeyError> {
+                    let start = self.start.checked_add(1).ok_or(RangeMapKeyError::StartIsMax)?;
+                    Ok(start..self.end)
+                }
+            }
+
+            impl RangeMapKey<$t> for RangeFromExclusiveToInclusive<$t> {
+                #[inline]
+                fn to_range_map_key(self) -> Result<Range<$t>, RangeMapKeyError> {
+                    let start = self.start.checked_add(1).ok_or(RangeMapKeyError::StartIsMax)?;
+                    let end = self.end.checked_add(1).ok_or(RangeMapKeyError::EndIsMax)?;
+                    Ok(start..end)
+                }
+            }
+        )*
+    };
+}
+
+impl_range_map_key!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Extension methods for using the bounded ranges in this crate as [`RangeMap`] keys.
+///
+/// # Example
+/// ```
+/// extern crate rangemap;
+/// # extern crate more_ranges;
+///
+/// use more_ranges::{RangeFromExclusiveToExclusive, RangeMapExclusiveExt};
+/// use rangemap::RangeMap;
+///
+/// let mut map = RangeMap::new();
+/// map.insert_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 4 }, "a")
+///     .unwrap();
+///
+/// assert_eq!(map.get(&1), None);
+/// assert_eq!(map.get(&2), Some(&"a"));
+/// assert_eq!(map.get(&4), None);
+/// ```
+///
+/// [`RangeMap`]: rangemap::RangeMap
+pub trait RangeMapExclusiveExt<K, V> {
+    /// Inserts `value` for every key contained in `range`.
+    ///
+    /// Inserting an empty range is a no-op.
+    ///
+    /// # Errors
+    /// Returns an error if `range` cannot be converted into a half-open key.
+    fn insert_exclusive<R>(&mut self, range: R, value: V) -> Result<(), RangeMapKeyError>
+    where
+        R: RangeMapKey<K>;
+
+    /// Returns the value covering every key contained in `range`.
+    ///
+    /// Returns `None` if `range` is empty or if it is not entirely covered by a single stored
+    /// range.
+    ///
+    /// # Errors
+    /// Returns an error if `range` cannot be converted into a half-open key.
+    fn get_exclusive<R>(&self, range: R) -> Result<Option<&V>, RangeMapKeyError>
+    where
+        R: RangeMapKey<K>;
+
+    /// Returns an iterator over the stored ranges partially or completely overlapped by `range`.
+    ///
+    /// # Errors
+    /// Returns an error if `range` cannot be converted into a half-open key.
+    fn overlapping_exclusive<R>(
+        &self,
+        range: R,
+    ) -> Result<Overlapping<'_, K, V, Range<K>>, RangeMapKeyError>
+    where
+        R: RangeMapKey<K>;
+}
+
+impl<K, V> RangeMapExclusiveExt<K, V> for RangeMap<K, V>
+where
+    K: Ord + Clone,
+    V: Eq + Clone,
+{
+    fn insert_exclusive<R>(&mut self, range: R, value: V) -> Result<(), RangeMapKeyError>
+    where
+        R: RangeMapKey<K>,
+    {
+        let key = range.to_range_map_key()?;
+        if key.start < key.end {
+            self.insert(key, value);
+        }
+        Ok(())
+    }
+
+    fn get_exclusive<R>(&self, range: R) -> Result<Option<&V>, RangeMapKeyError>
+    where
+        R: RangeMapKey<K>,
+    {
+        let key = range.to_range_map_key()?;
+        if key.start >= key.end {
+            return Ok(None);
+        }
+        Ok(self.get_key_value(&key.start).and_then(|(stored, value)| {
+            if stored.end >= key.end {
+                Some(value)
+            } else {
+                None
+            }
+        }))
+    }
+
+    fn overlapping_exclusive<R>(
+        &self,
+        range: R,
+    ) -> Result<Overlapping<'_, K, V, Range<K>>, RangeMapKeyError>
+    where
+        R: RangeMapKey<K>,
+    {
+        Ok(self.overlapping(range.to_range_map_key()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rangemap::RangeMap;
+    use std::vec::Vec;
+    use {
+        RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive, RangeMapExclusiveExt,
+        RangeMapKey, RangeMapKeyError,
+    };
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_key() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive { start: 1u8, end: 4 }.to_range_map_key(),
+            2..4
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_key_start_max() {
+        assert_matches!(
+            RangeFromExclusiveToExclusive {
+                start: u8::max_value(),
+                end: u8::max_value()
+            }
+            .to_range_map_key(),
+            Err(RangeMapKeyError::StartIsMax)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_key_end_max() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 1u8,
+                end: u8::max_value()
+            }
+            .to_range_map_key(),
+            2..u8::max_value()
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_key() {
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -1i8,
+                end: 4
+            }
+            .to_range_map_key(),
+            0..5
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_key_start_max() {
+        assert_matches!(
+            RangeFromExclusiveToInclusive {
+                start: u64::max_value(),
+                end: 0
+            }
+            .to_range_map_key(),
+            Err(RangeMapKeyError::StartIsMax)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_key_end_max() {
+        assert_matches!(
+            RangeFromExclusiveToInclusive {
+                start: 1u64,
+                end: u64::max_value()
+            }
+            .to_range_map_key(),
+            Err(RangeMapKeyError::EndIsMax)
+        );
+    }
+
+    #[test]
+    fn insert_exclusive_round_trip() {
+        let mut map = RangeMap::new();
+
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 4 }, 'a'));
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToInclusive { start: 5, end: 8 }, 'b'));
+
+        assert_ok_eq!(
+            map.get_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 4 }),
+            Some(&'a')
+        );
+        assert_ok_eq!(
+            map.get_exclusive(RangeFromExclusiveToInclusive { start: 5, end: 8 }),
+            Some(&'b')
+        );
+    }
+
+    #[test]
+    fn insert_exclusive_excludes_start() {
+        let mut map = RangeMap::new();
+
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToInclusive { start: 1, end: 4 }, 'a'));
+
+        assert_none!(map.get(&1));
+        assert_some_eq!(map.get(&2), &'a');
+        assert_some_eq!(map.get(&4), &'a');
+        assert_none!(map.get(&5));
+    }
+
+    #[test]
+    fn insert_exclusive_empty() {
+        let mut map = RangeMap::new();
+
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 2 }, 'a'));
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToInclusive { start: 3, end: 3 }, 'a'));
+
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn insert_exclusive_max() {
+        let mut map = RangeMap::new();
+
+        assert_matches!(
+            map.insert_exclusive(
+                RangeFromExclusiveToInclusive {
+                    start: 0u8,
+                    end: u8::max_value()
+                },
+                'a'
+            ),
+            Err(RangeMapKeyError::EndIsMax)
+        );
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn get_exclusive_partially_covered() {
+        let mut map = RangeMap::new();
+
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 4 }, 'a'));
+
+        assert_ok_eq!(
+            map.get_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 5 }),
+            None
+        );
+        assert_ok_eq!(
+            map.get_exclusive(RangeFromExclusiveToExclusive { start: 0, end: 4 }),
+            None
+        );
+    }
+
+    #[test]
+    fn get_exclusive_sub_range() {
+        let mut map = RangeMap::new();
+
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 10 }, 'a'));
+
+        assert_ok_eq!(
+            map.get_exclusive(RangeFromExclusiveToInclusive { start: 3, end: 5 }),
+            Some(&'a')
+        );
+    }
+
+    #[test]
+    fn get_exclusive_empty() {
+        let mut map = RangeMap::new();
+
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 10 }, 'a'));
+
+        assert_ok_eq!(
+            map.get_exclusive(RangeFromExclusiveToExclusive { start: 3, end: 4 }),
+            None
+        );
+    }
+
+    #[test]
+    fn get_exclusive_max() {
+        let map = RangeMap::<u8, char>::new();
+
+        assert_matches!(
+            map.get_exclusive(RangeFromExclusiveToExclusive {
+                start: u8::max_value(),
+                end: 0
+            }),
+            Err(RangeMapKeyError::StartIsMax)
+        );
+    }
+
+    #[test]
+    fn overlapping_exclusive() {
+        let mut map = RangeMap::new();
+
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 4 }, 'a'));
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToInclusive { start: 5, end: 8 }, 'b'));
+
+        let overlapping = map
+            .overlapping_exclusive(RangeFromExclusiveToInclusive { start: 2, end: 6 })
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(overlapping, [(&(2..4), &'a'), (&(6..9), &'b')]);
+    }
+
+    #[test]
+    fn overlapping_exclusive_excludes_start() {
+        let mut map = RangeMap::new();
+
+        assert_ok!(map.insert_exclusive(RangeFromExclusiveToExclusive { start: 1, end: 4 }, 'a'));
+
+        assert_none!(map
+            .overlapping_exclusive(RangeFromExclusiveToExclusive { start: 3, end: 10 })
+            .unwrap()
+            .next());
+    }
+
+    #[test]
+    fn overlapping_exclusive_max() {
+        let map = RangeMap::<i32, char>::new();
+
+        assert!(map
+            .overlapping_exclusive(RangeFromExclusiveToInclusive {
+                start: 0,
+                end: i32::max_value()
+            })
+            .is_err());
+    }
+}
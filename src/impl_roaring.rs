@@ -0,0 +1,396 @@
+//! Interoperability with [`roaring`](https://docs.rs/roaring).
+//!
+//! The bounded range types are converted into the equivalent inclusive range before being passed to
+//! the bitmap. Ranges that contain no values, including those whose exclusive `start` is the
+//! maximum value of the index type, are handled as empty rather than panicking.
+
+use core::ops::RangeInclusive;
+use roaring::{RoaringBitmap, RoaringTreemap};
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// Extension methods for inserting, removing, and querying bounded ranges in roaring bitmaps.
+///
+/// This is implemented for [`RoaringBitmap`] with ranges over `u32` and for [`RoaringTreemap`] with
+/// ranges over `u64`.
+///
+/// # Example
+/// ```
+/// extern crate roaring;
+/// # extern crate more_ranges;
+///
+/// use more_ranges::{RangeFromExclusiveToInclusive, RoaringExclusiveExt};
+/// use roaring::RoaringBitmap;
+///
+/// let checkpoint = 10;
+/// let high_water_mark = 20;
+///
+/// let mut bitmap = RoaringBitmap::new();
+/// bitmap.insert_exclusive_range(RangeFromExclusiveToInclusive {
+///     start: checkpoint,
+///     end: high_water_mark,
+/// });
+///
+/// assert!(!bitmap.contains(10));
+/// assert!(bitmap.contains(11));
+/// assert!(bitmap.contains(20));
+/// assert_eq!(bitmap.len(), 10);
+/// ```
+///
+/// [`RoaringBitmap`]: roaring::RoaringBitmap
+/// [`RoaringTreemap`]: roaring::RoaringTreemap
+pub trait RoaringExclusiveExt<R> {
+    /// Inserts every value contained in `range`, returning the number of values that were not
+    /// already present.
+    ///
+    /// An empty range inserts nothing and returns `0`.
+    fn insert_exclusive_range(&mut self, range: R) -> u64;
+
+    /// Removes every value contained in `range`, returning the number of values that were
+    /// present.
+    ///
+    /// An empty range removes nothing and returns `0`.
+    fn remove_exclusive_range(&mut self, range: R) -> u64;
+
+    /// Returns whether every value contained in `range` is present.
+    ///
+    /// An empty range is always contained.
+    fn contains_exclusive_range(&self, range: R) -> bool;
+}
+
+macro_rules! impl_roaring {
+    ($($bitmap:ty, $t:ty);*) => {
+        $(
+            impl RoaringExclusiveExt<RangeFromExclusiveToExclusive<$t>> for $bitmap {
+                #[inline]
+                fn insert_exclusive_range(&mut self, range: RangeFromExclusiveToExclusive<$t>) -> u64 {
+                    to_inclusive_exclusive!(range).map_or(0, |range| self.insert_range(range))
+                }
+
+                #[inline]
+                fn remove_exclusive_range(&mut self, range: RangeFromExclusiveToExclusive<$t>) -> u64 {
+                    to_inclusive_exclusive!(range).map_or(0, |range| self.remove_range(range))
+                }
+
+                #[inline]
+                fn contains_exclusive_range(&self, range: RangeFromExclusiveToExclusive<$t>) -> bool {
+                    to_inclusive_exclusive!(range).map_or(true, |range| self.contains_range(range))
+                }
+            }
+
+            impl RoaringExclusiveExt<RangeFromExclusiveToInclusive<$t>> for $bitmap {
+                #[inline]
+                fn insert_exclusive_range(&mut self, range: RangeFromExclusiveToInclusive<$t>) -> u64 {
+                    to_inclusive_inclusive!(range).map_or(0, |range| self.insert_range(range))
+                }
+
+                #[inline]
+                fn remove_exclusive_range(&mut self, range: RangeFromExclusiveToInclusive<$t>) -> u64 {
+                    to_inclusive_inclusive!(range).map_or(0, |range| self.remove_range(range))
+                }
+
+                #[inline]
+                fn contains_exclusive_range(&self, range: RangeFromExclusiveToInclusive<$t>) -> bool {
+                    to_inclusive_inclusive!(range).map_or(true, |range| self.contains_range(range))
+                }
+            }
+        )*
+    };
+}
+
+/// Converts a `RangeFromExclusiveToExclusive` into the equivalent non-empty inclusive range, or
+/// `None` if it is empty.
+macro_rules! to_inclusive_exclusive {
+    ($range:expr) => {{
+        let range = $range;
+        match (range.start.checked_add(1), range.end.checked_sub(1)) {
+            (Some(start), Some(end)) if start <= end => Some(RangeInclusive::new(start, end)),
+            _ => None,
+        }
+    }};
+}
+
+/// Converts a `RangeFromExclusiveToInclusive` into the equivalent non-empty inclusive range, or
+/// `None` if it is empty.
+macro_rules! to_inclusive_inclusive {
+    ($range:expr) => {{
+        let range = $range;
+        match range.start.checked_add(1) {
+            Some(start) if start <= range.end => Some(RangeInclusive::new(start, range.end)),
+            _ => None,
+        }
+    }};
+}
+
+impl_roaring!(RoaringBitmap, u32; RoaringTreemap, u64);
+
+#[cfg(test)]
+mod tests {
+    use roaring::{RoaringBitmap, RoaringTreemap};
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive, RoaringExclusiveExt};
+
+    #[test]
+    fn bitmap_insert_range_from_exclusive_to_exclusive() {
+        let mut bitmap = RoaringBitmap::new();
+
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToExclusive { start: 1, end: 100 }),
+            98
+        );
+        assert_eq!(bitmap.len(), 98);
+        assert_some_eq!(bitmap.min(), 2);
+        assert_some_eq!(bitmap.max(), 99);
+    }
+
+    #[test]
+    fn bitmap_insert_range_from_exclusive_to_inclusive() {
+        let mut bitmap = RoaringBitmap::new();
+
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToInclusive {
+                start: 1,
+                end: 100_000
+            }),
+            99_999
+        );
+        assert_eq!(bitmap.len(), 99_999);
+        assert_some_eq!(bitmap.min(), 2);
+        assert_some_eq!(bitmap.max(), 100_000);
+    }
+
+    #[test]
+    fn bitmap_insert_already_present() {
+        let mut bitmap = RoaringBitmap::new();
+        bitmap.insert_range(0..10);
+
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToInclusive { start: 4, end: 14 }),
+            5
+        );
+        assert_eq!(bitmap.len(), 15);
+    }
+
+    #[test]
+    fn bitmap_insert_empty() {
+        let mut bitmap = RoaringBitmap::new();
+
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToExclusive { start: 4, end: 4 }),
+            0
+        );
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToExclusive { start: 4, end: 5 }),
+            0
+        );
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToInclusive { start: 4, end: 4 }),
+            0
+        );
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToInclusive { start: 5, end: 4 }),
+            0
+        );
+        assert!(bitmap.is_empty());
+    }
+
+    #[test]
+    fn bitmap_insert_at_max() {
+        let mut bitmap = RoaringBitmap::new();
+
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToInclusive {
+                start: u32::max_value() - 2,
+                end: u32::max_value()
+            }),
+            2
+        );
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToInclusive {
+                start: u32::max_value(),
+                end: u32::max_value()
+            }),
+            0
+        );
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToExclusive {
+                start: u32::max_value(),
+                end: u32::max_value()
+            }),
+            0
+        );
+        assert_eq!(bitmap.len(), 2);
+        assert_some_eq!(bitmap.max(), u32::max_value());
+    }
+
+    #[test]
+    fn bitmap_insert_at_min() {
+        let mut bitmap = RoaringBitmap::new();
+
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToExclusive { start: 0, end: 0 }),
+            0
+        );
+        assert_eq!(
+            bitmap.insert_exclusive_range(RangeFromExclusiveToExclusive { start: 0, end: 2 }),
+            1
+        );
+        assert_eq!(bitmap.len(), 1);
+        assert_some_eq!(bitmap.min(), 1);
+    }
+
+    #[test]
+    fn bitmap_remove() {
+        let mut bitmap = RoaringBitmap::new();
+        bitmap.insert_range(0..10);
+
+        assert_eq!(
+            bitmap.remove_exclusive_range(RangeFromExclusiveToExclusive { start: 2, end: 6 }),
+            3
+        );
+        assert_eq!(
+            bitmap.remove_exclusive_range(RangeFromExclusiveToInclusive { start: 7, end: 20 }),
+            2
+        );
+        assert_eq!(
+            bitmap.remove_exclusive_range(RangeFromExclusiveToExclusive { start: 8, end: 8 }),
+            0
+        );
+        assert_eq!(bitmap.iter().collect::<std::vec::Vec<_>>(), [0, 1, 2, 6, 7]);
+    }
+
+    #[test]
+    fn bitmap_contains() {
+        let mut bitmap = RoaringBitmap::new();
+        bitmap.insert_range(2..10);
+
+        assert!(
+            bitmap.contains_exclusive_range(RangeFromExclusiveToExclusive { start: 1, end: 10 })
+        );
+        assert!(
+            !bitmap.contains_exclusive_range(RangeFromExclusiveToExclusive { start: 0, end: 10 })
+        );
+        assert!(bitmap.contains_exclusive_range(RangeFromExclusiveToInclusive { start: 1, end: 9 }));
+        assert!(
+            !bitmap.contains_exclusive_range(RangeFromExclusiveToInclusive { start: 1, end: 10 })
+        );
+    }
+
+    #[test]
+    fn bitmap_contains_empty() {
+        let bitmap = RoaringBitmap::new();
+
+        assert!(bitmap.contains_exclusive_range(RangeFromExclusiveToExclusive { start: 5, end: 5 }));
+        assert!(
+            bitmap.contains_exclusive_range(RangeFromExclusiveToInclusive {
+                start: u32::max_value(),
+                end: u32::max_value()
+            })
+        );
+    }
+
+    #[test]
+    fn bitmap_len_matches_range_len() {
+        let max = u32::max_value();
+        for &(start, end) in &[
+            (1, 100),
+            (4, 4),
+            (4, 5),
+            (5, 4),
+            (0, 2),
+            (max - 3, max),
+            (max, max),
+        ] {
+            let range = RangeFromExclusiveToExclusive { start, end };
+            let mut bitmap = RoaringBitmap::new();
+            bitmap.insert_exclusive_range(range);
+            assert_eq!(bitmap.len(), range.len() as u64);
+
+            let range = RangeFromExclusiveToInclusive { start, end };
+            let mut bitmap = RoaringBitmap::new();
+            bitmap.insert_exclusive_range(range);
+            assert_eq!(bitmap.len(), range.len() as u64);
+        }
+    }
+
+    #[test]
+    fn treemap_insert_range_from_exclusive_to_exclusive() {
+        let mut treemap = RoaringTreemap::new();
+        let start = u64::from(u32::max_value()) - 5;
+
+        assert_eq!(
+            treemap.insert_exclusive_range(RangeFromExclusiveToExclusive {
+                start,
+                end: start + 11
+            }),
+            10
+        );
+        assert_eq!(treemap.len(), 10);
+        assert_some_eq!(treemap.min(), start + 1);
+        assert_some_eq!(treemap.max(), start + 10);
+    }
+
+    #[test]
+    fn treemap_insert_range_from_exclusive_to_inclusive() {
+        let mut treemap = RoaringTreemap::new();
+
+        assert_eq!(
+            treemap.insert_exclusive_range(RangeFromExclusiveToInclusive {
+                start: u64::max_value() - 3,
+                end: u64::max_value()
+            }),
+            3
+        );
+        assert_eq!(
+            treemap.insert_exclusive_range(RangeFromExclusiveToInclusive {
+                start: u64::max_value(),
+                end: u64::max_value()
+            }),
+            0
+        );
+        assert_eq!(treemap.len(), 3);
+    }
+
+    #[test]
+    fn treemap_len_matches_range_len() {
+        let max = u64::max_value();
+        let boundary = u64::from(u32::max_value());
+        for &(start, end) in &[
+            (1, 100),
+            (4, 4),
+            (5, 4),
+            (boundary - 5, boundary + 6),
+            (max - 3, max),
+            (max, max),
+        ] {
+            let range = RangeFromExclusiveToExclusive { start, end };
+            let mut treemap = RoaringTreemap::new();
+            treemap.insert_exclusive_range(range);
+            assert_eq!(treemap.len(), range.len() as u64);
+
+            let range = RangeFromExclusiveToInclusive { start, end };
+            let mut treemap = RoaringTreemap::new();
+            treemap.insert_exclusive_range(range);
+            assert_eq!(treemap.len(), range.len() as u64);
+        }
+    }
+
+    #[test]
+    fn treemap_remove_and_contains() {
+        let mut treemap = RoaringTreemap::new();
+        treemap.insert_range(0..10);
+
+        assert!(
+            treemap.contains_exclusive_range(RangeFromExclusiveToInclusive { start: 0, end: 9 })
+        );
+        assert_eq!(
+            treemap.remove_exclusive_range(RangeFromExclusiveToExclusive { start: 0, end: 9 }),
+            8
+        );
+        assert!(
+            !treemap.contains_exclusive_range(RangeFromExclusiveToInclusive { start: 0, end: 9 })
+        );
+        assert!(
+            treemap.contains_exclusive_range(RangeFromExclusiveToExclusive { start: 4, end: 4 })
+        );
+        assert_eq!(treemap.len(), 2);
+    }
+}
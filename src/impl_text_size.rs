@@ -0,0 +1,342 @@
+//! Interoperability with [`text-size`](https://docs.rs/text-size).
+//!
+//! A [`TextRange`] is a half-open range of `u32` offsets. Converting a
+//! [`RangeFromExclusiveToExclusive`] into a `TextRange` moves its `start` up by one, and converting
+//! back moves it down by one, so a non-empty `TextRange` beginning at offset `0` has no exclusive
+//! counterpart. Empty ranges are converted into empty ranges at the same position, matching
+//! [`from_offset_len()`].
+//!
+//! [`TextRange`]: text_size::TextRange
+//! [`from_offset_len()`]: ::RangeFromExclusiveToExclusive::from_offset_len
+
+use core::convert::TryFrom;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+use text_size::{TextRange, TextSize};
+use RangeFromExclusiveToExclusive;
+
+/// An error converting between a [`TextRange`] and a [`RangeFromExclusiveToExclusive`].
+///
+/// [`TextRange`]: text_size::TextRange
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextRangeError {
+    /// The `start` bound of the range is greater than its `end` bound.
+    Inverted,
+    /// A bound of the range cannot be represented as a `u32` [`TextSize`].
+    ///
+    /// [`TextSize`]: text_size::TextSize
+    Overflow,
+    /// The non-empty `TextRange` begins at offset `0`, so the range would need an exclusive `start`
+    /// bound below `0`.
+    OffsetIsZero,
+}
+
+impl fmt::Display for TextRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextRangeError::Inverted => {
+                f.write_str("range start bound is greater than its end bound")
+            }
+            TextRangeError::Overflow => {
+                f.write_str("range bound cannot be represented as a `u32` text size")
+            }
+            TextRangeError::OffsetIsZero => f.write_str(
+                "text range begins at offset 0 and cannot be represented with an exclusive start bound",
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TextRangeError {}
+
+/// Converts the bounds of a `RangeFromExclusiveToExclusive<u32>` into a `TextRange`.
+fn to_text_range(start: u32, end: u32) -> Result<TextRange, TextRangeError> {
+    if start > end {
+        Err(TextRangeError::Inverted)
+    } else if start == end {
+        Ok(TextRange::empty(TextSize::from(end)))
+    } else {
+        // `start` is less than `end`, so this cannot overflow.
+        Ok(TextRange::new(
+            TextSize::from(start + 1),
+            TextSize::from(end),
+        ))
+    }
+}
+
+impl TryFrom<RangeFromExclusiveToExclusive<u32>> for TextRange {
+    type Error = TextRangeError;
+
+    #[inline]
+    fn try_from(range: RangeFromExclusiveToExclusive<u32>) -> Result<Self, Self::Error> {
+        to_text_range(range.start, range.end)
+    }
+}
+
+impl TryFrom<RangeFromExclusiveToExclusive<usize>> for TextRange {
+    type Error = TextRangeError;
+
+    #[inline]
+    fn try_from(range: RangeFromExclusiveToExclusive<usize>) -> Result<Self, Self::Error> {
+        if range.start > range.end {
+            return Err(TextRangeError::Inverted);
+        }
+        let end = u32::try_from(range.end).map_err(|_| TextRangeError::Overflow)?;
+        // `start` is no greater than `end`, so it fits as well.
+        to_text_range(range.start as u32, end)
+    }
+}
+
+macro_rules! impl_try_from_text_range {
+    ($($t:ident),*) => {
+        $(
+            impl TryFrom<TextRange> for RangeFromExclusiveToExclusive<$t> {
+                type Error = TextRangeError;
+
+                #[inline]
+                fn try_from(range: TextRange) -> Result<Self, Self::Error> {
+                    let start = $t::from(range.start());
+                    let end = $t::from(range.end());
+                    if range.is_empty() {
+                        Ok(RangeFromExclusiveToExclusive { start, end })
+                    } else if start == 0 {
+                        Err(TextRangeError::OffsetIsZero)
+                    } else {
+                        Ok(RangeFromExclusiveToExclusive {
+                            start: start - 1,
+                            end,
+                        })
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_text_range!(u32, usize);
+
+/// Extension trait for combining a [`TextRange`] with exclusive ranges.
+///
+/// # Example
+/// ```
+/// extern crate text_size;
+/// # extern crate more_ranges;
+///
+/// use more_ranges::{RangeFromExclusiveToExclusive, TextRangeExclusiveExt};
+/// use text_size::{TextRange, TextSize};
+///
+/// let range = TextRange::new(TextSize::from(2), TextSize::from(8));
+///
+/// assert_eq!(
+///     range.intersect_exclusive(RangeFromExclusiveToExclusive { start: 5, end: 10 }),
+///     Some(TextRange::new(TextSize::from(6), TextSize::from(8)))
+/// );
+/// ```
+///
+/// [`TextRange`]: text_size::TextRange
+pub trait TextRangeExclusiveExt {
+    /// Returns the intersection of `self` and `range`, or `None` if they do not overlap.
+    ///
+    /// As with [`TextRange::intersect()`], ranges that only touch intersect in an empty range.
+    /// Returns `None` if `range` is inverted.
+    ///
+    /// [`TextRange::intersect()`]: text_size::TextRange::intersect
+    fn intersect_exclusive(self, range: RangeFromExclusiveToExclusive<u32>) -> Option<TextRange>;
+}
+
+impl TextRangeExclusiveExt for TextRange {
+    #[inline]
+    fn intersect_exclusive(self, range: RangeFromExclusiveToExclusive<u32>) -> Option<TextRange> {
+        self.intersect(TextRange::try_from(range).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+    use impl_text_size::TextRangeError;
+    use text_size::{TextRange, TextSize};
+    use {RangeFromExclusiveToExclusive, TextRangeExclusiveExt};
+
+    fn text_range(start: u32, end: u32) -> TextRange {
+        TextRange::new(TextSize::from(start), TextSize::from(end))
+    }
+
+    #[test]
+    fn from_u32() {
+        assert_ok_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: 1u32,
+                end: 5
+            }),
+            text_range(2, 5)
+        );
+    }
+
+    #[test]
+    fn from_u32_empty() {
+        assert_ok_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: 4u32,
+                end: 4
+            }),
+            TextRange::empty(TextSize::from(4))
+        );
+        assert_ok_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: 3u32,
+                end: 4
+            }),
+            TextRange::empty(TextSize::from(4))
+        );
+        assert_ok_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: u32::max_value(),
+                end: u32::max_value(),
+            }),
+            TextRange::empty(TextSize::from(u32::max_value()))
+        );
+    }
+
+    #[test]
+    fn from_u32_inverted() {
+        assert_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: 5u32,
+                end: 4
+            }),
+            Err(TextRangeError::Inverted)
+        );
+    }
+
+    #[test]
+    fn from_usize() {
+        assert_ok_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: 1usize,
+                end: 5
+            }),
+            text_range(2, 5)
+        );
+        assert_ok_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: 0usize,
+                end: u32::max_value() as usize,
+            }),
+            text_range(1, u32::max_value())
+        );
+    }
+
+    #[test]
+    fn from_usize_errors() {
+        assert_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: 5usize,
+                end: 4
+            }),
+            Err(TextRangeError::Inverted)
+        );
+        assert_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: 0usize,
+                end: u32::max_value() as usize + 1,
+            }),
+            Err(TextRangeError::Overflow)
+        );
+        assert_eq!(
+            TextRange::try_from(RangeFromExclusiveToExclusive {
+                start: u32::max_value() as usize + 1,
+                end: u32::max_value() as usize + 1,
+            }),
+            Err(TextRangeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn to_exclusive() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::<u32>::try_from(text_range(2, 5)),
+            RangeFromExclusiveToExclusive { start: 1, end: 5 }
+        );
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::<usize>::try_from(text_range(1, u32::max_value())),
+            RangeFromExclusiveToExclusive {
+                start: 0,
+                end: u32::max_value() as usize,
+            }
+        );
+    }
+
+    #[test]
+    fn to_exclusive_empty() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::<u32>::try_from(TextRange::empty(TextSize::from(0))),
+            RangeFromExclusiveToExclusive { start: 0, end: 0 }
+        );
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::<usize>::try_from(TextRange::empty(TextSize::from(7))),
+            RangeFromExclusiveToExclusive { start: 7, end: 7 }
+        );
+    }
+
+    #[test]
+    fn to_exclusive_offset_zero() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive::<u32>::try_from(text_range(0, 3)),
+            Err(TextRangeError::OffsetIsZero)
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive::<usize>::try_from(TextRange::up_to(TextSize::from(1))),
+            Err(TextRangeError::OffsetIsZero)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for start in 0..10u32 {
+            for end in start..10 {
+                let exclusive = RangeFromExclusiveToExclusive { start, end };
+                let text_range = assert_ok!(TextRange::try_from(exclusive));
+                let round_tripped =
+                    assert_ok!(RangeFromExclusiveToExclusive::<u32>::try_from(text_range));
+                if start + 1 == end {
+                    // Both bounds of an empty range are moved to its position.
+                    assert_eq!(
+                        round_tripped,
+                        RangeFromExclusiveToExclusive { start: end, end }
+                    );
+                } else {
+                    assert_eq!(round_tripped, exclusive);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn intersect_exclusive() {
+        let range = text_range(2, 8);
+
+        assert_some_eq!(
+            range.intersect_exclusive(RangeFromExclusiveToExclusive { start: 0, end: 5 }),
+            text_range(2, 5)
+        );
+        assert_some_eq!(
+            range.intersect_exclusive(RangeFromExclusiveToExclusive { start: 3, end: 6 }),
+            text_range(4, 6)
+        );
+        assert_some_eq!(
+            range.intersect_exclusive(RangeFromExclusiveToExclusive { start: 7, end: 10 }),
+            TextRange::empty(TextSize::from(8))
+        );
+    }
+
+    #[test]
+    fn intersect_exclusive_disjoint() {
+        let range = text_range(2, 8);
+
+        assert_none!(range.intersect_exclusive(RangeFromExclusiveToExclusive { start: 8, end: 10 }));
+        assert_none!(range.intersect_exclusive(RangeFromExclusiveToExclusive { start: 6, end: 3 }));
+    }
+}
@@ -0,0 +1,196 @@
+//! Slicing strings by grapheme clusters using
+//! [`unicode-segmentation`](https://docs.rs/unicode-segmentation).
+//!
+//! Slicing by bytes or by `char`s can split a user-perceived character, such as a flag emoji or a
+//! letter followed by a combining accent. The bounds used here count extended grapheme clusters
+//! instead, so the returned slices always begin and end on grapheme boundaries.
+
+use resolve::Resolve;
+use unicode_segmentation::UnicodeSegmentation;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// Returns the slice of `s` containing the graphemes within `range`, or `None` if `range` is out of
+/// bounds of the number of graphemes in `s`.
+fn slice_graphemes<R>(s: &str, range: R) -> Option<&str>
+where
+    R: Resolve,
+{
+    let range = range.try_resolve(s.graphemes(true).count())?;
+    let offset = |index| {
+        s.grapheme_indices(true)
+            .nth(index)
+            .map_or(s.len(), |(offset, _)| offset)
+    };
+    Some(&s[offset(range.start)..offset(range.end)])
+}
+
+/// Extension trait for slicing string slices by extended grapheme clusters.
+///
+/// The bounds of each range count graphemes rather than bytes, and are interpreted the same way as
+/// when indexing a slice. For example, `RangeFromExclusive { start: 0 }` selects every grapheme
+/// after the first.
+///
+/// # Example
+/// ```
+/// use more_ranges::{GraphemeSliceExt, RangeFromExclusive};
+///
+/// // The thumbs-up is followed by a skin tone modifier, which is part of the same grapheme.
+/// let s = "\u{1F44D}\u{1F3FD} great";
+///
+/// assert_eq!(s.graphemes_after(RangeFromExclusive { start: 0 }), Some(" great"));
+/// ```
+pub trait GraphemeSliceExt {
+    /// Returns the graphemes after the grapheme at index `range.start`.
+    ///
+    /// Returns `None` if there are not more than `range.start` graphemes.
+    fn graphemes_after(&self, range: RangeFromExclusive<usize>) -> Option<&str>;
+
+    /// Returns the graphemes strictly between the graphemes at indices `range.start` and
+    /// `range.end`.
+    ///
+    /// Returns `None` if `range` is inverted, or if `range.end` is greater than the number of
+    /// graphemes.
+    fn graphemes_between(&self, range: RangeFromExclusiveToExclusive<usize>) -> Option<&str>;
+
+    /// Returns the graphemes after the grapheme at index `range.start`, up to and including the
+    /// grapheme at index `range.end`.
+    ///
+    /// Returns `None` if `range` is inverted, or if `range.end` is not less than the number of
+    /// graphemes.
+    fn graphemes_through(&self, range: RangeFromExclusiveToInclusive<usize>) -> Option<&str>;
+}
+
+impl GraphemeSliceExt for str {
+    #[inline]
+    fn graphemes_after(&self, range: RangeFromExclusive<usize>) -> Option<&str> {
+        slice_graphemes(self, range)
+    }
+
+    #[inline]
+    fn graphemes_between(&self, range: RangeFromExclusiveToExclusive<usize>) -> Option<&str> {
+        slice_graphemes(self, range)
+    }
+
+    #[inline]
+    fn graphemes_through(&self, range: RangeFromExclusiveToInclusive<usize>) -> Option<&str> {
+        slice_graphemes(self, range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::String;
+    use {
+        GraphemeSliceExt, RangeFromExclusive, RangeFromExclusiveToExclusive,
+        RangeFromExclusiveToInclusive,
+    };
+
+    /// The flags of Norway, Sweden, and Denmark, each made of two regional indicator `char`s.
+    const FLAGS: &str = "\u{1F1F3}\u{1F1F4}\u{1F1F8}\u{1F1EA}\u{1F1E9}\u{1F1F0}";
+    /// A waving hand and a thumbs-up, each followed by a skin tone modifier.
+    const SKIN_TONES: &str = "\u{1F44B}\u{1F3FB}\u{1F44D}\u{1F3FF}";
+    /// "résumé" with each accent written as a combining acute accent.
+    const COMBINING: &str = "re\u{301}sume\u{301}";
+
+    #[test]
+    fn after_flags() {
+        assert_some_eq!(
+            FLAGS.graphemes_after(RangeFromExclusive { start: 0 }),
+            "\u{1F1F8}\u{1F1EA}\u{1F1E9}\u{1F1F0}"
+        );
+        // Skipping the first `char` splits the Norwegian flag instead.
+        assert_eq!(
+            FLAGS.chars().skip(1).collect::<String>(),
+            "\u{1F1F4}\u{1F1F8}\u{1F1EA}\u{1F1E9}\u{1F1F0}"
+        );
+    }
+
+    #[test]
+    fn after_skin_tones() {
+        assert_some_eq!(
+            SKIN_TONES.graphemes_after(RangeFromExclusive { start: 0 }),
+            "\u{1F44D}\u{1F3FF}"
+        );
+        // Skipping the first `char` leaves a lone skin tone modifier.
+        assert_eq!(
+            SKIN_TONES.chars().skip(1).collect::<String>(),
+            "\u{1F3FB}\u{1F44D}\u{1F3FF}"
+        );
+    }
+
+    #[test]
+    fn after_combining() {
+        assert_some_eq!(
+            COMBINING.graphemes_after(RangeFromExclusive { start: 1 }),
+            "sume\u{301}"
+        );
+        // Skipping the first two `char`s leaves a lone combining accent.
+        assert_eq!(
+            COMBINING.chars().skip(2).collect::<String>(),
+            "\u{301}sume\u{301}"
+        );
+    }
+
+    #[test]
+    fn after_last() {
+        assert_some_eq!(FLAGS.graphemes_after(RangeFromExclusive { start: 2 }), "");
+        assert_none!(FLAGS.graphemes_after(RangeFromExclusive { start: 3 }));
+    }
+
+    #[test]
+    fn between() {
+        assert_some_eq!(
+            FLAGS.graphemes_between(RangeFromExclusiveToExclusive { start: 0, end: 2 }),
+            "\u{1F1F8}\u{1F1EA}"
+        );
+        assert_some_eq!(
+            COMBINING.graphemes_between(RangeFromExclusiveToExclusive { start: 0, end: 5 }),
+            "e\u{301}sum"
+        );
+    }
+
+    #[test]
+    fn between_empty() {
+        assert_some_eq!(
+            SKIN_TONES.graphemes_between(RangeFromExclusiveToExclusive { start: 2, end: 2 }),
+            ""
+        );
+    }
+
+    #[test]
+    fn between_out_of_bounds() {
+        assert_none!(FLAGS.graphemes_between(RangeFromExclusiveToExclusive { start: 0, end: 4 }));
+        assert_none!(FLAGS.graphemes_between(RangeFromExclusiveToExclusive { start: 2, end: 1 }));
+    }
+
+    #[test]
+    fn through() {
+        assert_some_eq!(
+            SKIN_TONES.graphemes_through(RangeFromExclusiveToInclusive { start: 0, end: 1 }),
+            "\u{1F44D}\u{1F3FF}"
+        );
+        assert_some_eq!(
+            COMBINING.graphemes_through(RangeFromExclusiveToInclusive { start: 3, end: 5 }),
+            "me\u{301}"
+        );
+    }
+
+    #[test]
+    fn through_out_of_bounds() {
+        assert_none!(
+            SKIN_TONES.graphemes_through(RangeFromExclusiveToInclusive { start: 0, end: 2 })
+        );
+        assert_none!(
+            SKIN_TONES.graphemes_through(RangeFromExclusiveToInclusive { start: 1, end: 0 })
+        );
+    }
+
+    #[test]
+    fn empty_string() {
+        assert_none!("".graphemes_after(RangeFromExclusive { start: 0 }));
+        assert_some_eq!(
+            "".graphemes_between(RangeFromExclusiveToExclusive { start: 0, end: 0 }),
+            ""
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Iterating over the integers contained in any [`RangeBounds`].
+//!
+//! [`RangeBounds`]: core::ops::RangeBounds
+
+use core::iter;
+use core::ops::{Bound, RangeBounds};
+use StepsBetween;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive integer type.
+///
+/// This is implemented for the primitive integers, and cannot be implemented outside of this crate.
+/// It provides the extremes and successors needed to turn an arbitrary [`RangeBounds`] into an
+/// iterator.
+///
+/// # Example
+/// ```
+/// use std::ops::RangeBounds;
+/// use more_ranges::{iter_bounds, Integer};
+///
+/// fn count<T: Integer, R: RangeBounds<T>>(range: R) -> usize {
+///     iter_bounds(range).count()
+/// }
+///
+/// assert_eq!(count(1u8..4), 3);
+/// ```
+pub trait Integer: sealed::Sealed + Copy + Ord + StepsBetween {
+    /// The smallest value of the type.
+    const MIN: Self;
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// Returns the successor of the value, or `None` if it is the maximum value.
+    fn checked_succ(self) -> Option<Self>;
+
+    /// Returns the predecessor of the value, or `None` if it is the minimum value.
+    fn checked_pred(self) -> Option<Self>;
+
+    /// Returns `self + rhs`, or `None` if it overflows.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Returns `self - rhs`, or `None` if it overflows.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Returns `self + rhs`, saturating at the numeric bounds.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Returns `self - rhs`, saturating at the numeric bounds.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Returns `self + rhs`, wrapping around at the numeric bounds.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Returns `self - rhs`, wrapping around at the numeric bounds.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Returns the value `n` successor steps after `self`, or `None` if it overflows.
+    fn checked_forward(self, n: u128) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ident),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Integer for $t {
+                const MIN: Self = ::core::$t::MIN;
+                const MAX: Self = ::core::$t::MAX;
+
+                #[inline]
+                fn checked_succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                #[inline]
+                fn checked_pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    $t::checked_add(self, rhs)
+                }
+
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    $t::checked_sub(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_add(self, rhs: Self) -> Self {
+                    $t::saturating_add(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    $t::saturating_sub(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    $t::wrapping_add(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    $t::wrapping_sub(self, rhs)
+                }
+
+                #[inline]
+                fn checked_forward(self, n: u128) -> Option<Self> {
+                    match Self::steps_between_wide(&self, &<Self as Integer>::MAX) {
+                        // `n` is at most the distance to the maximum, so adding its truncation
+                        // wraps around to the correct value.
+                        Some(remaining) if n <= remaining => {
+                            Some(self.wrapping_add(n as $t))
+                        }
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// An iterator over the integers contained in a [`RangeBounds`].
+///
+/// This is created by [`iter_bounds()`].
+///
+/// [`RangeBounds`]: core::ops::RangeBounds
+/// [`iter_bounds()`]: iter_bounds
+#[derive(Clone, Debug)]
+pub struct BoundsIter<T> {
+    /// The first and last values remaining, or `None` if iteration has finished.
+    remaining: Option<(T, T)>,
+}
+
+impl<T> Iterator for BoundsIter<T>
+where
+    T: Integer,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let (first, last) = self.remaining?;
+        self.remaining = if first < last {
+            first.checked_succ().map(|next| (next, last))
+        } else {
+            None
+        };
+        Some(first)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some((first, last)) => {
+                match T::steps_between(&first, &last).and_then(|steps| steps.checked_add(1)) {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::max_value(), None),
+                }
+            }
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for BoundsIter<T>
+where
+    T: Integer,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        let (first, last) = self.remaining?;
+        self.remaining = if first < last {
+            last.checked_pred().map(|previous| (first, previous))
+        } else {
+            None
+        };
+        Some(last)
+    }
+}
+
+impl<T> iter::FusedIterator for BoundsIter<T> where T: Integer {}
+
+/// Returns an iterator over the integers contained in `range`.
+///
+/// This accepts any [`RangeBounds`], including the standard library's ranges, the ranges in this
+/// crate, and `(Bound<T>, Bound<T>)` tuples. An unbounded start begins iteration at the minimum
+/// value of `T`, and an unbounded end ends iteration at its maximum value, which is yielded.
+///
+/// # Example
+/// ```
+/// use more_ranges::{iter_bounds, RangeFromExclusive, RangeFromExclusiveToInclusive};
+/// use std::ops::Bound;
+///
+/// assert!(iter_bounds(RangeFromExclusiveToInclusive { start: 1, end: 4 }).eq(2..=4));
+/// assert!(iter_bounds(1..4).eq(1..4));
+/// assert!(iter_bounds((Bound::Excluded(1), Bound::Excluded(4))).eq(2..4));
+/// // Iteration stops at the maximum value.
+/// assert!(iter_bounds(RangeFromExclusive { start: 252u8 }).eq(253..=255));
+/// ```
+///
+/// [`RangeBounds`]: core::ops::RangeBounds
+#[inline]
+pub fn iter_bounds<T, R>(range: R) -> BoundsIter<T>
+where
+    T: Integer,
+    R: RangeBounds<T>,
+{
+    let first = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        Bound::Excluded(&start) => start.checked_succ(),
+        Bound::Unbounded => Some(T::MIN),
+    };
+    let last = match range.end_bound() {
+        Bound::Included(&end) => Some(end),
+        Bound::Excluded(&end) => end.checked_pred(),
+        Bound::Unbounded => Some(T::MAX),
+    };
+    BoundsIter {
+        remaining: match (first, last) {
+            (Some(first), Some(last)) if first <= last => Some((first, last)),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::{Bound, RangeBounds};
+    use std::vec::Vec;
+    use {
+        iter_bounds, RangeFromExclusive, RangeFromExclusiveToExclusive,
+        RangeFromExclusiveToInclusive,
+    };
+
+    /// Values at and around the edges and middle of `u8`.
+    const VALUES: [u8; 9] = [0, 1, 2, 3, 127, 128, 253, 254, 255];
+
+    fn bounds() -> Vec<Bound<u8>> {
+        VALUES
+            .iter()
+            .map(|&value| Bound::Included(value))
+            .chain(VALUES.iter().map(|&value| Bound::Excluded(value)))
+            .chain(Some(Bound::Unbounded))
+            .collect()
+    }
+
+    #[test]
+    fn every_bound_combination() {
+        for start in bounds() {
+            for end in bounds() {
+                let range = (start, end);
+                let expected = (0..=u8::max_value())
+                    .filter(|value| range.contains(value))
+                    .collect::<Vec<_>>();
+
+                let iter = iter_bounds(range);
+                assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+                assert_eq!(iter.clone().collect::<Vec<_>>(), expected);
+                assert_eq!(
+                    iter.rev().collect::<Vec<_>>(),
+                    expected.into_iter().rev().collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn exclusive_ranges() {
+        assert!(iter_bounds(RangeFromExclusive { start: -3i8 }).eq(-2..=i8::max_value()));
+        assert!(iter_bounds(RangeFromExclusiveToInclusive {
+            start: -3i32,
+            end: 2
+        })
+        .eq(-2..=2));
+        assert!(iter_bounds(RangeFromExclusiveToExclusive {
+            start: 5u64,
+            end: 9
+        })
+        .eq(6..9));
+    }
+
+    #[test]
+    fn standard_ranges() {
+        assert!(iter_bounds(3u16..7).eq(3..7));
+        assert!(iter_bounds(3u16..=7).eq(3..=7));
+        assert!(iter_bounds(..3u16).eq(0..3));
+        assert!(iter_bounds(..=3u16).eq(0..=3));
+        assert!(iter_bounds(65533u16..).eq(65533..=65535));
+        assert!(iter_bounds::<i8, _>(..).eq(-128..=127));
+    }
+
+    #[test]
+    fn empty() {
+        assert_none!(iter_bounds(RangeFromExclusiveToExclusive { start: 5, end: 6 }).next());
+        assert_none!(iter_bounds(RangeFromExclusive {
+            start: u8::max_value()
+        })
+        .next());
+        assert_none!(iter_bounds(RangeFromExclusiveToInclusive { start: 4, end: 2 }).next());
+        assert_eq!(iter_bounds(5..5).size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn unbounded_wide() {
+        let mut iter = iter_bounds(RangeFromExclusive {
+            start: u128::max_value() - 3,
+        });
+
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_some_eq!(iter.next(), u128::max_value() - 2);
+        assert_some_eq!(iter.next_back(), u128::max_value());
+        assert_some_eq!(iter.next(), u128::max_value() - 1);
+        assert_none!(iter.next());
+        assert_none!(iter.next_back());
+    }
+
+    #[test]
+    fn size_hint_overflow() {
+        assert_eq!(
+            iter_bounds::<u128, _>(..).size_hint(),
+            (usize::max_value(), None)
+        );
+        assert_eq!(
+            iter_bounds::<usize, _>(..).size_hint(),
+            (usize::max_value(), None)
+        );
+        assert_eq!(
+            iter_bounds(RangeFromExclusive { start: 0usize }).size_hint(),
+            (usize::max_value(), Some(usize::max_value()))
+        );
+    }
+
+    #[test]
+    fn fused() {
+        let mut iter = iter_bounds(RangeFromExclusiveToInclusive { start: 1, end: 2 });
+
+        assert_some_eq!(iter.next(), 2);
+        assert_none!(iter.next());
+        assert_none!(iter.next());
+    }
+}
@@ -0,0 +1,512 @@
+//! Counting the values in the bounded range types on stable Rust.
+
+use steps::StepsBetween;
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: PartialOrd + StepsBetween,
+{
+    /// Returns the number of values in the range, or `None` if it does not fit in a `usize`.
+    ///
+    /// This is `Some(0)` if `start >= end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 1, end: 4 }.len_checked(), Some(3));
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 4, end: 1 }.len_checked(), Some(0));
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 0, end: u128::max_value() }.len_checked(),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn len_checked(&self) -> Option<usize> {
+        if self.start >= self.end {
+            Some(0)
+        } else {
+            StepsBetween::steps_between(&self.start, &self.end)
+        }
+    }
+
+    /// Returns the number of values in the range, as a `u128`.
+    ///
+    /// Unlike [`len_checked()`], this can express the length of every range over the primitive
+    /// integers and `char`. This is `0` if `start >= end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 0, end: u128::max_value() }.len_u128(),
+    ///     u128::max_value()
+    /// );
+    /// ```
+    ///
+    /// [`len_checked()`]: RangeFromExclusiveToInclusive::len_checked
+    #[inline]
+    pub fn len_u128(&self) -> u128 {
+        if self.start >= self.end {
+            0
+        } else {
+            StepsBetween::steps_between_wide(&self.start, &self.end).unwrap_or(0)
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: PartialOrd + StepsBetween,
+{
+    /// Returns the number of values in the range, or `None` if it does not fit in a `usize`.
+    ///
+    /// This is `Some(0)` if `start >= end`, or if the bounds are adjacent.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 1, end: 4 }.len_checked(), Some(2));
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 1, end: 2 }.len_checked(), Some(0));
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 0, end: u128::max_value() }.len_checked(),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn len_checked(&self) -> Option<usize> {
+        if self.start >= self.end {
+            Some(0)
+        } else {
+            // `start < end`, so there is at least one step between them. The steps are counted
+            // as a `u128`, since they may not fit in a `usize` even when the length does.
+            StepsBetween::steps_between_wide(&self.start, &self.end).and_then(|steps| {
+                let len = steps - 1;
+                if len <= usize::max_value() as u128 {
+                    Some(len as usize)
+                } else {
+                    None
+                }
+            })
+        }
+    }
+
+    /// Returns the number of values in the range, as a `u128`.
+    ///
+    /// Unlike [`len_checked()`], this can express the length of every range over the primitive
+    /// integers and `char`. This is `0` if `start >= end`, or if the bounds are adjacent.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 0, end: u128::max_value() }.len_u128(),
+    ///     u128::max_value() - 1
+    /// );
+    /// ```
+    ///
+    /// [`len_checked()`]: RangeFromExclusiveToExclusive::len_checked
+    #[inline]
+    pub fn len_u128(&self) -> u128 {
+        if self.start >= self.end {
+            0
+        } else {
+            StepsBetween::steps_between_wide(&self.start, &self.end).map_or(0, |steps| steps - 1)
+        }
+    }
+}
+
+macro_rules! impl_len {
+    ($($t:ty),*) => {
+        $(
+            impl RangeFromExclusiveToInclusive<$t> {
+                /// Returns the number of values in the range.
+                ///
+                /// This is `0` if `start >= end`.
+                ///
+                /// # Panics
+                /// Panics if the number of values does not fit in a `usize`.
+                #[inline]
+                pub fn len(&self) -> usize {
+                    self.len_checked().expect("range length overflows `usize`")
+                }
+            }
+
+            impl RangeFromExclusiveToExclusive<$t> {
+                /// Returns the number of values in the range.
+                ///
+                /// This is `0` if `start >= end`, or if the bounds are adjacent.
+                ///
+                /// # Panics
+                /// Panics if the number of values does not fit in a `usize`.
+                #[inline]
+                pub fn len(&self) -> usize {
+                    self.len_checked().expect("range length overflows `usize`")
+                }
+            }
+        )*
+    };
+}
+
+impl_len!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, char);
+
+#[cfg(test)]
+mod tests {
+    use iter_bounds::iter_bounds;
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_len() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 1u32,
+                end: 4
+            }
+            .len(),
+            3
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 4u32,
+                end: 4
+            }
+            .len(),
+            0
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 5u32,
+                end: 4
+            }
+            .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_len_agrees_with_count() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in i8::min_value()..=i8::max_value() {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                assert_eq!(range.len(), iter_bounds(range).count());
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_len_full() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: i16::min_value(),
+                end: i16::max_value()
+            }
+            .len(),
+            65535
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 0,
+                end: usize::max_value()
+            }
+            .len(),
+            usize::max_value()
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_len_char() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 'a',
+                end: 'd'
+            }
+            .len(),
+            3
+        );
+        // The surrogate code points are skipped.
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: '\0',
+                end: ::core::char::MAX
+            }
+            .len(),
+            0x10FFFF - 0x0800
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_len_checked() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 1u128,
+                end: 4
+            }
+            .len_checked(),
+            3
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 4u128,
+                end: 1
+            }
+            .len_checked(),
+            0
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 0u128,
+                end: usize::max_value() as u128
+            }
+            .len_checked(),
+            usize::max_value()
+        );
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: 0u128,
+            end: usize::max_value() as u128 + 1
+        }
+        .len_checked());
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: i128::min_value(),
+            end: i128::max_value()
+        }
+        .len_checked());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_len_u128() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 1u64,
+                end: 4
+            }
+            .len_u128(),
+            3
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 4u64,
+                end: 1
+            }
+            .len_u128(),
+            0
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 0u64,
+                end: u64::max_value()
+            }
+            .len_u128(),
+            u128::from(u64::max_value())
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: i128::min_value(),
+                end: i128::max_value()
+            }
+            .len_u128(),
+            u128::max_value()
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_len_u128_agrees_with_len() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in i8::min_value()..=i8::max_value() {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                assert_eq!(range.len_u128(), range.len() as u128);
+            }
+        }
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    #[should_panic(expected = "range length overflows `usize`")]
+    fn range_from_exclusive_to_inclusive_len_overflow() {
+        RangeFromExclusiveToInclusive {
+            start: 0,
+            end: u64::max_value(),
+        }
+        .len();
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_len() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 1u32,
+                end: 4
+            }
+            .len(),
+            2
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 3u32,
+                end: 4
+            }
+            .len(),
+            0
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 4u32,
+                end: 4
+            }
+            .len(),
+            0
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 5u32,
+                end: 4
+            }
+            .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_len_agrees_with_count() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in i8::min_value()..=i8::max_value() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                assert_eq!(range.len(), iter_bounds(range).count());
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_len_full() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 0,
+                end: usize::max_value()
+            }
+            .len(),
+            usize::max_value() - 1
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_len_char() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 'a',
+                end: 'd'
+            }
+            .len(),
+            2
+        );
+        // The surrogate code points are skipped.
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: '\u{D7FF}',
+                end: '\u{E000}'
+            }
+            .len(),
+            0
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: '\0',
+                end: ::core::char::MAX
+            }
+            .len(),
+            0x10FFFF - 0x0800 - 1
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_len_checked() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 1u128,
+                end: 4
+            }
+            .len_checked(),
+            2
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 3u128,
+                end: 4
+            }
+            .len_checked(),
+            0
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 0u128,
+                end: usize::max_value() as u128 + 1
+            }
+            .len_checked(),
+            usize::max_value()
+        );
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: 0u128,
+            end: usize::max_value() as u128 + 2
+        }
+        .len_checked());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_len_u128() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 1u64,
+                end: 4
+            }
+            .len_u128(),
+            2
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 3u64,
+                end: 4
+            }
+            .len_u128(),
+            0
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: i128::min_value(),
+                end: i128::max_value()
+            }
+            .len_u128(),
+            u128::max_value() - 1
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: '\0',
+                end: ::core::char::MAX
+            }
+            .len_u128(),
+            0x10FFFF - 0x0800 - 1
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_len_u128_agrees_with_len() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in i8::min_value()..=i8::max_value() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                assert_eq!(range.len_u128(), range.len() as u128);
+            }
+        }
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    #[should_panic(expected = "range length overflows `usize`")]
+    fn range_from_exclusive_to_exclusive_len_overflow() {
+        RangeFromExclusiveToExclusive {
+            start: 0,
+            end: u64::max_value(),
+        }
+        .len();
+    }
+}
@@ -0,0 +1,535 @@
+//! Interpolation across the bounded range types over primitive integers and floats.
+//!
+//! A range is treated as a parameter interval: `lerp()` maps a parameter `t` in `[0, 1]` to a value
+//! in the range, and `inv_lerp()` maps a value back to its parameter. The computation is done in
+//! `f64`, so for 64-bit and 128-bit integers, values far from the first value of the range may be
+//! rounded to a nearby value representable as an `f64`.
+
+use float::Float;
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+#[cold]
+#[inline(never)]
+fn empty_range_fail() -> ! {
+    panic!("attempted to interpolate across an empty range");
+}
+
+/// Maps `t` onto the integers from `$first` to `$last`, inclusive, rounding to the nearest.
+macro_rules! lerp_integer {
+    ($first:expr, $last:expr, $t:expr, $int:ident, $unsigned:ident) => {{
+        let (first, last, t) = ($first, $last, $t);
+        let span = last.wrapping_sub(first) as $unsigned as f64;
+        let offset = span * t + 0.5;
+        if t.is_nan() || t <= 0.0 {
+            first
+        } else if offset >= span {
+            last
+        } else {
+            first.wrapping_add(offset as $unsigned as $int)
+        }
+    }};
+}
+
+/// Maps `$value` onto the parameter of the integers from `$first` to `$last`, inclusive.
+macro_rules! inv_lerp_integer {
+    ($first:expr, $last:expr, $value:expr, $unsigned:ident) => {{
+        let (first, last, value) = ($first, $last, $value);
+        if first == last {
+            return 0.0;
+        }
+        let span = last.wrapping_sub(first) as $unsigned as f64;
+        if value < first {
+            -(first.wrapping_sub(value) as $unsigned as f64) / span
+        } else {
+            (value.wrapping_sub(first) as $unsigned as f64) / span
+        }
+    }};
+}
+
+/// Attaches a doc example, built from `concat!()`, between the leading and trailing doc comments
+/// of an item.
+///
+/// Doc attributes only accept string literals on older compilers, so the example has to be passed
+/// through an `expr` fragment to be spelled in terms of the macro's index type.
+macro_rules! with_example {
+    ($(#[$leading:meta])* example: $example:expr; $($item:tt)*) => {
+        $(#[$leading])*
+        #[doc = $example]
+        $($item)*
+    };
+}
+
+macro_rules! impl_lerp_integer {
+    ($($t:ident, $unsigned:ident;)*) => {
+        $(
+            impl RangeFromExclusiveToInclusive<$t> {
+                with_example! {
+                    /// Returns the value at parameter `t` across the values of the range.
+                    ///
+                    /// A `t` of `0.0` maps to the first value contained in the range, `start + 1`,
+                    /// and a `t` of `1.0` maps to `end`. Values in between are rounded to the
+                    /// nearest integer, and a `t` outside of `[0, 1]`, or `NaN`, is clamped to the
+                    /// nearest endpoint.
+                    ///
+                    /// # Panics
+                    /// Panics if the range is empty.
+                    ///
+                    example: concat!(
+                        "# Example\n",
+                        "```\n",
+                        "use more_ranges::RangeFromExclusiveToInclusive;\n",
+                        "\n",
+                        "let range = RangeFromExclusiveToInclusive { start: 0",
+                        stringify!($t),
+                        ", end: 10 };\n",
+                        "\n",
+                        "assert_eq!(range.lerp(0.0), 1);\n",
+                        "// Values in between are rounded to the nearest integer.\n",
+                        "assert_eq!(range.lerp(0.25), 3);\n",
+                        "assert_eq!(range.lerp(0.5), 6);\n",
+                        "assert_eq!(range.lerp(1.0), 10);\n",
+                        "\n",
+                        "// `t` is clamped to `[0, 1]`.\n",
+                        "assert_eq!(range.lerp(-0.5), 1);\n",
+                        "assert_eq!(range.lerp(2.0), 10);\n",
+                        "```",
+                    );
+                    #[inline]
+                    pub fn lerp(&self, t: f64) -> $t {
+                        match self.start.checked_add(1) {
+                            Some(first) if first <= self.end => {
+                                lerp_integer!(first, self.end, t, $t, $unsigned)
+                            }
+                            _ => empty_range_fail(),
+                        }
+                    }
+                }
+
+                with_example! {
+                    /// Returns the parameter of `value` across the values of the range.
+                    ///
+                    /// This is the inverse of [`lerp()`]: the first value contained in the range,
+                    /// `start + 1`, maps to `0.0`, and `end` maps to `1.0`. Values outside of the
+                    /// range map to parameters outside of `[0, 1]`. If the range contains a single
+                    /// value, every value maps to `0.0`.
+                    ///
+                    /// # Panics
+                    /// Panics if the range is empty.
+                    ///
+                    example: concat!(
+                        "# Example\n",
+                        "```\n",
+                        "use more_ranges::RangeFromExclusiveToInclusive;\n",
+                        "\n",
+                        "let range = RangeFromExclusiveToInclusive { start: 0",
+                        stringify!($t),
+                        ", end: 5 };\n",
+                        "\n",
+                        "assert_eq!(range.inv_lerp(1), 0.0);\n",
+                        "assert_eq!(range.inv_lerp(2), 0.25);\n",
+                        "assert_eq!(range.inv_lerp(5), 1.0);\n",
+                        "assert_eq!(range.inv_lerp(7), 1.5);\n",
+                        "```",
+                    );
+                    ///
+                    /// [`lerp()`]: RangeFromExclusiveToInclusive::lerp
+                    #[inline]
+                    pub fn inv_lerp(&self, value: $t) -> f64 {
+                        match self.start.checked_add(1) {
+                            Some(first) if first <= self.end => {
+                                inv_lerp_integer!(first, self.end, value, $unsigned)
+                            }
+                            _ => empty_range_fail(),
+                        }
+                    }
+                }
+            }
+
+            impl RangeFromExclusiveToExclusive<$t> {
+                with_example! {
+                    /// Returns the value at parameter `t` across the values of the range.
+                    ///
+                    /// A `t` of `0.0` maps to the first value contained in the range, `start + 1`,
+                    /// and a `t` of `1.0` maps to the last, `end - 1`. Values in between are
+                    /// rounded to the nearest integer, and a `t` outside of `[0, 1]`, or `NaN`, is
+                    /// clamped to the nearest endpoint.
+                    ///
+                    /// # Panics
+                    /// Panics if the range is empty.
+                    ///
+                    example: concat!(
+                        "# Example\n",
+                        "```\n",
+                        "use more_ranges::RangeFromExclusiveToExclusive;\n",
+                        "\n",
+                        "let range = RangeFromExclusiveToExclusive { start: 0",
+                        stringify!($t),
+                        ", end: 10 };\n",
+                        "\n",
+                        "assert_eq!(range.lerp(0.0), 1);\n",
+                        "// Values in between are rounded to the nearest integer.\n",
+                        "assert_eq!(range.lerp(0.3), 3);\n",
+                        "assert_eq!(range.lerp(0.5), 5);\n",
+                        "assert_eq!(range.lerp(1.0), 9);\n",
+                        "\n",
+                        "// `t` is clamped to `[0, 1]`.\n",
+                        "assert_eq!(range.lerp(-0.5), 1);\n",
+                        "assert_eq!(range.lerp(2.0), 9);\n",
+                        "```",
+                    );
+                    #[inline]
+                    pub fn lerp(&self, t: f64) -> $t {
+                        match self.start.checked_add(1) {
+                            Some(first) if first < self.end => {
+                                lerp_integer!(first, self.end - 1, t, $t, $unsigned)
+                            }
+                            _ => empty_range_fail(),
+                        }
+                    }
+                }
+
+                with_example! {
+                    /// Returns the parameter of `value` across the values of the range.
+                    ///
+                    /// This is the inverse of [`lerp()`]: the first value contained in the range,
+                    /// `start + 1`, maps to `0.0`, and the last, `end - 1`, maps to `1.0`. Values
+                    /// outside of the range map to parameters outside of `[0, 1]`. If the range
+                    /// contains a single value, every value maps to `0.0`.
+                    ///
+                    /// # Panics
+                    /// Panics if the range is empty.
+                    ///
+                    example: concat!(
+                        "# Example\n",
+                        "```\n",
+                        "use more_ranges::RangeFromExclusiveToExclusive;\n",
+                        "\n",
+                        "let range = RangeFromExclusiveToExclusive { start: 0",
+                        stringify!($t),
+                        ", end: 6 };\n",
+                        "\n",
+                        "assert_eq!(range.inv_lerp(1), 0.0);\n",
+                        "assert_eq!(range.inv_lerp(2), 0.25);\n",
+                        "assert_eq!(range.inv_lerp(5), 1.0);\n",
+                        "assert_eq!(range.inv_lerp(7), 1.5);\n",
+                        "```",
+                    );
+                    ///
+                    /// [`lerp()`]: RangeFromExclusiveToExclusive::lerp
+                    #[inline]
+                    pub fn inv_lerp(&self, value: $t) -> f64 {
+                        match self.start.checked_add(1) {
+                            Some(first) if first < self.end => {
+                                inv_lerp_integer!(first, self.end - 1, value, $unsigned)
+                            }
+                            _ => empty_range_fail(),
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_lerp_integer! {
+    u8, u8;
+    u16, u16;
+    u32, u32;
+    u64, u64;
+    u128, u128;
+    usize, usize;
+    i8, u8;
+    i16, u16;
+    i32, u32;
+    i64, u64;
+    i128, u128;
+    isize, usize;
+}
+
+impl<T> RangeFromExclusiveToInclusive<T>
+where
+    T: Float,
+{
+    /// Returns the value at parameter `t` between the bounds.
+    ///
+    /// A `t` of `0.0` maps exactly to `start`, even though it is not contained in the range, and a
+    /// `t` of `1.0` maps exactly to `end`. A `t` outside of `[0, 1]` extrapolates beyond the bounds,
+    /// and a `NaN` bound or parameter produces `NaN`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 1.0, end: 3.0 };
+    ///
+    /// assert_eq!(range.lerp(0.0), 1.0);
+    /// assert_eq!(range.lerp(0.25), 1.5);
+    /// assert_eq!(range.lerp(1.0), 3.0);
+    /// ```
+    #[inline]
+    pub fn lerp(&self, t: f64) -> T {
+        T::from_f64((1.0 - t) * self.start.to_f64() + t * self.end.to_f64())
+    }
+
+    /// Returns the parameter of `value` between the bounds.
+    ///
+    /// This is the inverse of [`lerp()`]: `start` maps to `0.0`, and `end` maps to `1.0`. If the
+    /// bounds are equal, the result is infinite or `NaN`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 1.0, end: 3.0 };
+    ///
+    /// assert_eq!(range.inv_lerp(1.5), 0.25);
+    /// ```
+    ///
+    /// [`lerp()`]: RangeFromExclusiveToInclusive::lerp
+    #[inline]
+    pub fn inv_lerp(&self, value: T) -> f64 {
+        let start = self.start.to_f64();
+        (value.to_f64() - start) / (self.end.to_f64() - start)
+    }
+}
+
+impl<T> RangeFromExclusiveToExclusive<T>
+where
+    T: Float,
+{
+    /// Returns the value at parameter `t` between the bounds.
+    ///
+    /// A `t` of `0.0` maps exactly to `start`, and a `t` of `1.0` maps exactly to `end`, even though
+    /// neither is contained in the range. A `t` outside of `[0, 1]` extrapolates beyond the bounds,
+    /// and a `NaN` bound or parameter produces `NaN`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1.0, end: 3.0 };
+    ///
+    /// assert_eq!(range.lerp(0.0), 1.0);
+    /// assert_eq!(range.lerp(0.25), 1.5);
+    /// assert_eq!(range.lerp(1.0), 3.0);
+    /// ```
+    #[inline]
+    pub fn lerp(&self, t: f64) -> T {
+        T::from_f64((1.0 - t) * self.start.to_f64() + t * self.end.to_f64())
+    }
+
+    /// Returns the parameter of `value` between the bounds.
+    ///
+    /// This is the inverse of [`lerp()`]: `start` maps to `0.0`, and `end` maps to `1.0`. If the
+    /// bounds are equal, the result is infinite or `NaN`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1.0, end: 3.0 };
+    ///
+    /// assert_eq!(range.inv_lerp(1.5), 0.25);
+    /// ```
+    ///
+    /// [`lerp()`]: RangeFromExclusiveToExclusive::lerp
+    #[inline]
+    pub fn inv_lerp(&self, value: T) -> f64 {
+        let start = self.start.to_f64();
+        (value.to_f64() - start) / (self.end.to_f64() - start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_lerp_endpoints() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        assert_eq!(range.lerp(0.0), 1);
+        assert_eq!(range.lerp(1.0), 10);
+        assert_eq!(range.lerp(0.5), 6);
+        assert_eq!(range.lerp(-1.0), 1);
+        assert_eq!(range.lerp(2.0), 10);
+        assert_eq!(range.lerp(::core::f64::NAN), 1);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_lerp_full() {
+        let range = RangeFromExclusiveToInclusive {
+            start: i8::min_value(),
+            end: i8::max_value(),
+        };
+
+        assert_eq!(range.lerp(0.0), -127);
+        assert_eq!(range.lerp(1.0), 127);
+        assert_eq!(range.lerp(0.5), 0);
+
+        let range = RangeFromExclusiveToInclusive {
+            start: 0u128,
+            end: u128::max_value(),
+        };
+
+        assert_eq!(range.lerp(0.0), 1);
+        assert_eq!(range.lerp(1.0), u128::max_value());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_lerp_single() {
+        let range = RangeFromExclusiveToInclusive { start: 4i8, end: 5 };
+
+        assert_eq!(range.lerp(0.0), 5);
+        assert_eq!(range.lerp(1.0), 5);
+        assert_eq!(range.inv_lerp(5), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to interpolate across an empty range")]
+    fn range_from_exclusive_to_inclusive_lerp_empty() {
+        RangeFromExclusiveToInclusive { start: 5u8, end: 5 }.lerp(0.5);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_inv_lerp_endpoints() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        assert_eq!(range.inv_lerp(1), 0.0);
+        assert_eq!(range.inv_lerp(10), 1.0);
+        assert_eq!(range.inv_lerp(0), -1.0 / 9.0);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_inv_lerp_inverts_lerp() {
+        let range = RangeFromExclusiveToInclusive {
+            start: -8i8,
+            end: 8,
+        };
+
+        for value in -7..=8 {
+            assert_eq!(range.lerp(range.inv_lerp(value)), value);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_lerp_float_endpoints() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 0.1f32,
+            end: 0.7,
+        };
+
+        assert_eq!(range.lerp(0.0), 0.1);
+        assert_eq!(range.lerp(1.0), 0.7);
+        assert_eq!(range.inv_lerp(0.1), 0.0);
+        assert_eq!(range.inv_lerp(0.7), 1.0);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_lerp_float_extrapolate() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 1.0f64,
+            end: 3.0,
+        };
+
+        assert_eq!(range.lerp(0.5), 2.0);
+        assert_eq!(range.lerp(2.0), 5.0);
+        assert_eq!(range.inv_lerp(0.0), -0.5);
+        assert!(range.lerp(::core::f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_lerp_endpoints() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        assert_eq!(range.lerp(0.0), 1);
+        assert_eq!(range.lerp(1.0), 9);
+        assert_eq!(range.lerp(0.5), 5);
+        assert_eq!(range.lerp(-1.0), 1);
+        assert_eq!(range.lerp(2.0), 9);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_lerp_full() {
+        let range = RangeFromExclusiveToExclusive {
+            start: i64::min_value(),
+            end: i64::max_value(),
+        };
+
+        assert_eq!(range.lerp(0.0), i64::min_value() + 1);
+        assert_eq!(range.lerp(1.0), i64::max_value() - 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to interpolate across an empty range")]
+    fn range_from_exclusive_to_exclusive_lerp_empty() {
+        RangeFromExclusiveToExclusive { start: 4u8, end: 5 }.lerp(0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to interpolate across an empty range")]
+    fn range_from_exclusive_to_exclusive_inv_lerp_empty() {
+        RangeFromExclusiveToExclusive {
+            start: u8::max_value(),
+            end: u8::max_value(),
+        }
+        .inv_lerp(0);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_inv_lerp_endpoints() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        assert_eq!(range.inv_lerp(1), 0.0);
+        assert_eq!(range.inv_lerp(9), 1.0);
+        assert_eq!(range.inv_lerp(10), 1.125);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_inv_lerp_inverts_lerp() {
+        let range = RangeFromExclusiveToExclusive {
+            start: -8i8,
+            end: 8,
+        };
+
+        for value in -7..8 {
+            assert_eq!(range.lerp(range.inv_lerp(value)), value);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_lerp_float_endpoints() {
+        let range = RangeFromExclusiveToExclusive {
+            start: -0.3,
+            end: 0.9,
+        };
+
+        assert_eq!(range.lerp(0.0), -0.3);
+        assert_eq!(range.lerp(1.0), 0.9);
+        assert_eq!(range.inv_lerp(-0.3), 0.0);
+        assert_eq!(range.inv_lerp(0.9), 1.0);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_inv_lerp_float_equal_bounds() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 1.0,
+            end: 1.0,
+        };
+
+        assert!(range.inv_lerp(1.0).is_nan());
+        assert_eq!(range.inv_lerp(2.0), ::core::f64::INFINITY);
+    }
+}
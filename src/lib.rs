@@ -2,7 +2,8 @@
 //!
 //! Specifically, these are ranges which are bounded exclusively below.
 //!
-//! These ranges currently do not function as [`Iterator`]s and cannot be used in indexing.
+//! These ranges currently do not function as [`Iterator`]s. Ranges over `usize` can be used to
+//! index slices and string slices on Rust 1.41 and newer.
 //!
 //! # Example
 //! While each range type in the standard library is either bounded inclusively below or unbounded
@@ -31,14 +32,179 @@
 //! [`RangeFrom`]: core::ops::RangeFrom
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "bevy_reflect")]
+extern crate bevy_reflect;
+#[cfg(feature = "postgres")]
+extern crate bytes;
 #[cfg(test)]
 #[macro_use]
 extern crate claim;
+#[cfg(feature = "miette")]
+extern crate miette;
+#[cfg(feature = "postgres")]
+extern crate postgres_types;
+#[cfg(feature = "pyo3")]
+extern crate pyo3;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rangemap")]
+extern crate rangemap;
+#[cfg(feature = "roaring")]
+extern crate roaring;
+#[cfg(feature = "serde")]
+#[cfg_attr(test, macro_use)]
+extern crate serde;
+#[cfg(any(
+    test,
+    feature = "miette",
+    feature = "postgres",
+    feature = "pyo3",
+    feature = "std"
+))]
+extern crate std;
+#[cfg(feature = "text-size")]
+extern crate text_size;
+#[cfg(feature = "unicode-segmentation")]
+extern crate unicode_segmentation;
 
+mod accessors;
+mod any;
+mod arith;
+#[cfg(const_range)]
+#[clippy::msrv = "1.57"]
+mod const_range;
+mod consts;
+mod contains;
+mod convert;
+#[cfg(feature = "alloc")]
+mod drain;
+mod duration;
+mod ext;
+mod float;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "bevy_reflect")]
+mod impl_bevy_reflect;
+#[cfg(impl_index)]
+mod impl_index;
+#[cfg(feature = "miette")]
+mod impl_miette;
+#[cfg(feature = "postgres")]
+#[clippy::msrv = "1.32"]
+mod impl_postgres_types;
+#[cfg(feature = "pyo3")]
+mod impl_pyo3;
+#[cfg(feature = "rand")]
+mod impl_rand;
+#[cfg(feature = "rangemap")]
+mod impl_rangemap;
+#[cfg(feature = "roaring")]
+mod impl_roaring;
+#[cfg(feature = "text-size")]
+#[clippy::msrv = "1.34"]
+mod impl_text_size;
+#[cfg(feature = "unicode-segmentation")]
+mod impl_unicode_segmentation;
+mod iter_bounds;
+mod len;
+mod lerp;
+mod map;
+mod mask;
+mod offset_len;
+#[cfg(feature = "pointer-ranges")]
+mod pointer;
+mod resize;
+mod resolve;
+#[cfg(feature = "alloc")]
+mod retain_range;
+#[cfg(all(test, feature = "serde"))]
+mod serde_tokens;
+mod set_values;
+mod shift;
+#[cfg(get_disjoint_range_mut)]
+#[clippy::msrv = "1.55"]
+mod slice;
+mod snap;
+mod std_range;
+mod steps;
+mod sub_range;
+#[cfg(try_from)]
+#[clippy::msrv = "1.34"]
+mod try_cast;
+#[cfg(try_from)]
+#[clippy::msrv = "1.34"]
+mod try_from_bounds;
+mod validate;
+mod values;
+mod width;
+
+pub use accessors::{ExclusiveLowerBound, UpperBound};
+pub use any::AnyExclusiveRange;
+#[cfg(const_range)]
+pub use const_range::{ConstRangeFromExclusiveToExclusive, ConstRangeFromExclusiveToInclusive};
+pub use contains::RangePosition;
+#[cfg(feature = "alloc")]
+pub use drain::{StringExclusiveExt, VecExclusiveExt};
+pub use duration::DurationSteps;
+pub use ext::ExclusiveStartExt;
+pub use float::{Float, NanPolicy};
+#[cfg(feature = "http")]
+pub use http::ParseContentRangeError;
+#[cfg(feature = "bevy_reflect")]
+pub use impl_bevy_reflect::register_range_types;
+#[cfg(feature = "miette")]
+pub use impl_miette::FromSourceSpanError;
+#[cfg(feature = "rangemap")]
+pub use impl_rangemap::{RangeMapExclusiveExt, RangeMapKey, RangeMapKeyError};
+#[cfg(feature = "roaring")]
+pub use impl_roaring::RoaringExclusiveExt;
+#[cfg(feature = "text-size")]
+pub use impl_text_size::{TextRangeError, TextRangeExclusiveExt};
+#[cfg(feature = "unicode-segmentation")]
+pub use impl_unicode_segmentation::GraphemeSliceExt;
+pub use iter_bounds::{iter_bounds, BoundsIter, Integer};
+#[cfg(feature = "pointer-ranges")]
+pub use pointer::PointerSteps;
+pub use resolve::{resolve_bounds, try_resolve_bounds, Resolve, ResolveError};
+#[cfg(feature = "alloc")]
+pub use retain_range::RetainRangeExt;
+pub use set_values::{DifferenceValues, IntersectionValues, UnionValues};
+#[cfg(get_disjoint_range_mut)]
+pub use slice::SliceExclusiveExt;
+pub use snap::SnapMode;
+pub use std_range::ToExclusiveStart;
+pub use steps::{steps_between, steps_between_wide, StepsBetween};
+#[cfg(try_from)]
+pub use try_from_bounds::TryFromBoundsError;
+pub use validate::InvalidRange;
+pub use width::Width;
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+// The code generated by `derive(Reflect)` assumes the 2021 prelude.
+#[cfg(feature = "bevy_reflect")]
+use core::iter::FromIterator;
 use core::ops::{
     Bound::{self, Excluded, Included, Unbounded},
     RangeBounds,
 };
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Defines a function that is a `const fn` on compilers supporting it, and a plain `fn` otherwise.
+macro_rules! const_fn {
+    ($(#[$attr:meta])* pub fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty $body:block) => {
+        #[cfg(const_new)]
+        $(#[$attr])*
+        pub const fn $name($($arg: $ty),*) -> $ret $body
+
+        #[cfg(not(const_new))]
+        $(#[$attr])*
+        pub fn $name($($arg: $ty),*) -> $ret $body
+    };
+}
 
 /// A range only bounded exclusively below.
 ///
@@ -51,6 +217,10 @@ use core::ops::{
 /// happens earlier than you might assume: the overflow happens in the call to next that yields the
 /// maximum value, as the range must be set to a state to yield the next value.
 ///
+/// This type is `Copy` when `Idx` is, unlike the standard library's ranges, since it does not
+/// implement [`Iterator`]. If it ever does, each copy will iterate independently, so iterate over
+/// `&mut range` to advance the original.
+///
 /// # Example
 /// `RangeFromExclusive`s can be created directly, as follows:
 ///
@@ -64,12 +234,36 @@ use core::ops::{
 ///
 /// [`Iterator`]: core::iter::Iterator
 /// [`Step`]: core::iter::Step
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RangeFromExclusive<Idx> {
     /// The lower bound of the range (exclusive).
     pub start: Idx,
 }
 
+impl<Idx> RangeFromExclusive<Idx> {
+    const_fn! {
+        /// Creates a new range bounded exclusively below by `start`.
+        ///
+        /// When compiled with Rust 1.31 or newer, this is a `const fn`.
+        ///
+        /// # Example
+        /// ```
+        /// use more_ranges::RangeFromExclusive;
+        ///
+        /// assert_eq!(
+        ///     RangeFromExclusive::new(1),
+        ///     RangeFromExclusive { start: 1 }
+        /// );
+        /// ```
+        #[inline]
+        pub fn new(start: Idx) -> Self {
+            RangeFromExclusive { start }
+        }
+    }
+}
+
 impl<T> RangeBounds<T> for RangeFromExclusive<T> {
     #[inline]
     fn start_bound(&self) -> Bound<&T> {
@@ -81,7 +275,10 @@ impl<T> RangeBounds<T> for RangeFromExclusive<T> {
     }
 }
 
-impl<'a, T> RangeBounds<T> for RangeFromExclusive<&'a T> {
+impl<'a, T> RangeBounds<T> for RangeFromExclusive<&'a T>
+where
+    T: 'a + ?Sized,
+{
     #[inline]
     fn start_bound(&self) -> Bound<&T> {
         Excluded(self.start)
@@ -97,6 +294,10 @@ impl<'a, T> RangeBounds<T> for RangeFromExclusive<&'a T> {
 /// The `RangeFromExclusiveToInclusive` contains all values with `x > start` and `x <= end`. It is
 /// empty unless `start < end`.
 ///
+/// This type is `Copy` when `Idx` is, unlike the standard library's ranges, since it does not
+/// implement [`Iterator`]. If it ever does, each copy will iterate independently, so iterate over
+/// `&mut range` to advance the original.
+///
 /// # Example
 /// `RangeFromExclusiveToInclusive`s can be created directly, as follows:
 ///
@@ -108,7 +309,11 @@ impl<'a, T> RangeBounds<T> for RangeFromExclusive<&'a T> {
 ///     end: 4,
 /// };
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// [`Iterator`]: core::iter::Iterator
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RangeFromExclusiveToInclusive<Idx> {
     /// The lower bound of the range (exclusive).
     pub start: Idx,
@@ -116,6 +321,63 @@ pub struct RangeFromExclusiveToInclusive<Idx> {
     pub end: Idx,
 }
 
+impl<Idx> RangeFromExclusiveToInclusive<Idx> {
+    const_fn! {
+        /// Creates a new range bounded exclusively below by `start` and inclusively above by `end`.
+        ///
+        /// When compiled with Rust 1.31 or newer, this is a `const fn`.
+        ///
+        /// The bounds are not checked, not even in debug builds, since a `const fn` cannot compare
+        /// them. Use [`new_checked()`] to catch swapped bounds in debug builds, or [`try_new()`] to
+        /// reject them.
+        ///
+        /// # Example
+        /// ```
+        /// use more_ranges::RangeFromExclusiveToInclusive;
+        ///
+        /// assert_eq!(
+        ///     RangeFromExclusiveToInclusive::new(0, 100),
+        ///     RangeFromExclusiveToInclusive { start: 0, end: 100 }
+        /// );
+        /// ```
+        ///
+        /// [`new_checked()`]: RangeFromExclusiveToInclusive::new_checked
+        /// [`try_new()`]: RangeFromExclusiveToInclusive::try_new
+        #[inline]
+        pub fn new(start: Idx, end: Idx) -> Self {
+            RangeFromExclusiveToInclusive { start, end }
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: PartialOrd,
+{
+    /// Creates a new range bounded exclusively below by `start` and inclusively above by `end`,
+    /// checking the bounds in debug builds.
+    ///
+    /// # Panics
+    /// Panics in debug builds if `start > end`. Such a range is empty, and is most likely the
+    /// result of swapping the bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive::new_checked(1, 4),
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn new_checked(start: Idx, end: Idx) -> Self {
+        debug_assert!(start <= end, "range start is greater than range end");
+        RangeFromExclusiveToInclusive { start, end }
+    }
+}
+
+#[allow(unused_attributes)]
 impl<T> RangeBounds<T> for RangeFromExclusiveToInclusive<T> {
     #[inline]
     #[must_use]
@@ -129,7 +391,11 @@ impl<T> RangeBounds<T> for RangeFromExclusiveToInclusive<T> {
     }
 }
 
-impl<'a, T> RangeBounds<T> for RangeFromExclusiveToInclusive<&'a T> {
+#[allow(unused_attributes)]
+impl<'a, T> RangeBounds<T> for RangeFromExclusiveToInclusive<&'a T>
+where
+    T: 'a + ?Sized,
+{
     #[inline]
     #[must_use]
     fn start_bound(&self) -> Bound<&T> {
@@ -147,6 +413,10 @@ impl<'a, T> RangeBounds<T> for RangeFromExclusiveToInclusive<&'a T> {
 /// The `RangeFromExclusiveToExclusive` contains all values with `x > start` and x < end`. It is
 /// empty unless `start < end + 1`.
 ///
+/// This type is `Copy` when `Idx` is, unlike the standard library's ranges, since it does not
+/// implement [`Iterator`]. If it ever does, each copy will iterate independently, so iterate over
+/// `&mut range` to advance the original.
+///
 /// # Example
 /// `RangeFromExclusiveToExclusive`s can be created directly, as follows:
 ///
@@ -158,7 +428,11 @@ impl<'a, T> RangeBounds<T> for RangeFromExclusiveToInclusive<&'a T> {
 ///     end: 4,
 /// };
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// [`Iterator`]: core::iter::Iterator
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RangeFromExclusiveToExclusive<Idx> {
     /// The lower bound of the range (exclusive).
     pub start: Idx,
@@ -166,6 +440,63 @@ pub struct RangeFromExclusiveToExclusive<Idx> {
     pub end: Idx,
 }
 
+impl<Idx> RangeFromExclusiveToExclusive<Idx> {
+    const_fn! {
+        /// Creates a new range bounded exclusively below by `start` and exclusively above by `end`.
+        ///
+        /// When compiled with Rust 1.31 or newer, this is a `const fn`.
+        ///
+        /// The bounds are not checked, not even in debug builds, since a `const fn` cannot compare
+        /// them. Use [`new_checked()`] to catch swapped bounds in debug builds, or [`try_new()`] to
+        /// reject them.
+        ///
+        /// # Example
+        /// ```
+        /// use more_ranges::RangeFromExclusiveToExclusive;
+        ///
+        /// assert_eq!(
+        ///     RangeFromExclusiveToExclusive::new(0, 100),
+        ///     RangeFromExclusiveToExclusive { start: 0, end: 100 }
+        /// );
+        /// ```
+        ///
+        /// [`new_checked()`]: RangeFromExclusiveToExclusive::new_checked
+        /// [`try_new()`]: RangeFromExclusiveToExclusive::try_new
+        #[inline]
+        pub fn new(start: Idx, end: Idx) -> Self {
+            RangeFromExclusiveToExclusive { start, end }
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: PartialOrd,
+{
+    /// Creates a new range bounded exclusively below by `start` and exclusively above by `end`,
+    /// checking the bounds in debug builds.
+    ///
+    /// # Panics
+    /// Panics in debug builds if `start > end`. Such a range is empty, and is most likely the
+    /// result of swapping the bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive::new_checked(1, 4),
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn new_checked(start: Idx, end: Idx) -> Self {
+        debug_assert!(start <= end, "range start is greater than range end");
+        RangeFromExclusiveToExclusive { start, end }
+    }
+}
+
+#[allow(unused_attributes)]
 impl<T> RangeBounds<T> for RangeFromExclusiveToExclusive<T> {
     #[inline]
     #[must_use]
@@ -179,7 +510,11 @@ impl<T> RangeBounds<T> for RangeFromExclusiveToExclusive<T> {
     }
 }
 
-impl<'a, T> RangeBounds<T> for RangeFromExclusiveToExclusive<&'a T> {
+#[allow(unused_attributes)]
+impl<'a, T> RangeBounds<T> for RangeFromExclusiveToExclusive<&'a T>
+where
+    T: 'a + ?Sized,
+{
     #[inline]
     #[must_use]
     fn start_bound(&self) -> Bound<&T> {
@@ -198,6 +533,11 @@ mod tests {
         Bound::{Excluded, Included, Unbounded},
         RangeBounds,
     };
+    #[cfg(feature = "serde")]
+    use serde_tokens::{assert_de_tokens_error, assert_tokens, Token};
+    use std::collections::BTreeMap;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
     use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
 
     #[test]
@@ -247,4 +587,287 @@ mod tests {
         assert_matches!(RangeBounds::<usize>::start_bound(&range), Excluded(1));
         assert_matches!(RangeBounds::<usize>::end_bound(&range), Included(3));
     }
+
+    /// Returns a map with `String` keys `"a"` through `"e"`.
+    fn string_map() -> BTreeMap<String, usize> {
+        ["a", "b", "c", "d", "e"]
+            .iter()
+            .enumerate()
+            .map(|(index, key)| (key.to_string(), index))
+            .collect()
+    }
+
+    /// Returns a map with byte string keys `[0]` through `[4]`.
+    fn bytes_map() -> BTreeMap<Vec<u8>, usize> {
+        (0..5u8)
+            .map(|key| (Some(key).into_iter().collect(), key as usize))
+            .collect()
+    }
+
+    #[test]
+    fn range_from_exclusive_range_bounds_unsized() {
+        let range = RangeFromExclusive { start: "b" };
+
+        assert_matches!(RangeBounds::<str>::start_bound(&range), Excluded("b"));
+        assert_matches!(RangeBounds::<str>::end_bound(&range), Unbounded);
+        assert_eq!(
+            string_map()
+                .range::<str, _>(range)
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>(),
+            [2, 3, 4]
+        );
+        assert_eq!(
+            bytes_map()
+                .range::<[u8], _>(RangeFromExclusive { start: &[2][..] })
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>(),
+            [3, 4]
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_range_bounds_unsized() {
+        let range = RangeFromExclusiveToExclusive {
+            start: "a",
+            end: "d",
+        };
+
+        assert_matches!(RangeBounds::<str>::start_bound(&range), Excluded("a"));
+        assert_matches!(RangeBounds::<str>::end_bound(&range), Excluded("d"));
+        assert_eq!(
+            string_map()
+                .range::<str, _>(range)
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(
+            bytes_map()
+                .range::<[u8], _>(RangeFromExclusiveToExclusive {
+                    start: &[0][..],
+                    end: &[3][..],
+                })
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_range_bounds_unsized() {
+        let range = RangeFromExclusiveToInclusive {
+            start: "a",
+            end: "d",
+        };
+
+        assert_matches!(RangeBounds::<str>::start_bound(&range), Excluded("a"));
+        assert_matches!(RangeBounds::<str>::end_bound(&range), Included("d"));
+        assert_eq!(
+            string_map()
+                .range::<str, _>(range)
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            bytes_map()
+                .range::<[u8], _>(RangeFromExclusiveToInclusive {
+                    start: &[0][..],
+                    end: &[3][..],
+                })
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+    }
+
+    #[cfg(const_new)]
+    const AFTER_ONE: RangeFromExclusive<u32> = RangeFromExclusive::new(1);
+    #[cfg(const_new)]
+    const ONE_TO_FOUR: RangeFromExclusiveToInclusive<u32> =
+        RangeFromExclusiveToInclusive::new(1, 4);
+    #[cfg(const_new)]
+    static ONE_UNTIL_FOUR: RangeFromExclusiveToExclusive<u32> =
+        RangeFromExclusiveToExclusive::new(1, 4);
+
+    #[cfg(const_new)]
+    #[test]
+    fn range_from_exclusive_const_new() {
+        assert_eq!(AFTER_ONE, RangeFromExclusive { start: 1 });
+    }
+
+    #[cfg(const_new)]
+    #[test]
+    fn range_from_exclusive_to_inclusive_const_new() {
+        assert_eq!(
+            ONE_TO_FOUR,
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[cfg(const_new)]
+    #[test]
+    fn range_from_exclusive_to_exclusive_const_new() {
+        assert_eq!(
+            ONE_UNTIL_FOUR,
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn copy() {
+        let range = RangeFromExclusiveToInclusive { start: 1, end: 4 };
+        let copy = range;
+
+        assert_eq!(range, copy);
+    }
+
+    #[test]
+    fn range_from_exclusive_new() {
+        assert_eq!(RangeFromExclusive::new(1), RangeFromExclusive { start: 1 });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_new() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive::new(1, 3),
+            RangeFromExclusiveToExclusive { start: 1, end: 3 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_new_empty() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive::new(2, 2),
+            RangeFromExclusiveToExclusive { start: 2, end: 2 }
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "range start is greater than range end")]
+    fn range_from_exclusive_to_exclusive_new_checked_reversed() {
+        RangeFromExclusiveToExclusive::new_checked(3, 1);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_new_reversed() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive::new(3, 1),
+            RangeFromExclusiveToExclusive { start: 3, end: 1 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_new() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::new("a", "d"),
+            RangeFromExclusiveToInclusive {
+                start: "a",
+                end: "d"
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_new_empty() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::new(2.5, 2.5),
+            RangeFromExclusiveToInclusive {
+                start: 2.5,
+                end: 2.5
+            }
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "range start is greater than range end")]
+    fn range_from_exclusive_to_inclusive_new_checked_reversed() {
+        RangeFromExclusiveToInclusive::new_checked(3, 1);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_new_reversed() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive::new(3, 1),
+            RangeFromExclusiveToInclusive { start: 3, end: 1 }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn range_from_exclusive_serde() {
+        assert_tokens(
+            &RangeFromExclusive { start: 1u32 },
+            &[
+                Token::Struct {
+                    name: "RangeFromExclusive",
+                    len: 1,
+                },
+                Token::Str("start"),
+                Token::U32(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn range_from_exclusive_to_exclusive_serde() {
+        assert_tokens(
+            &RangeFromExclusiveToExclusive {
+                start: -1i64,
+                end: 4,
+            },
+            &[
+                Token::Struct {
+                    name: "RangeFromExclusiveToExclusive",
+                    len: 2,
+                },
+                Token::Str("start"),
+                Token::I64(-1),
+                Token::Str("end"),
+                Token::I64(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn range_from_exclusive_to_inclusive_serde() {
+        assert_tokens(
+            &RangeFromExclusiveToInclusive { start: 1u8, end: 4 },
+            &[
+                Token::Struct {
+                    name: "RangeFromExclusiveToInclusive",
+                    len: 2,
+                },
+                Token::Str("start"),
+                Token::U8(1),
+                Token::Str("end"),
+                Token::U8(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn range_from_exclusive_to_inclusive_serde_missing_end() {
+        assert_de_tokens_error::<RangeFromExclusiveToInclusive<u8>>(
+            &[
+                Token::Struct {
+                    name: "RangeFromExclusiveToInclusive",
+                    len: 1,
+                },
+                Token::Str("start"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+            "missing field `end`",
+        );
+    }
 }
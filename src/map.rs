@@ -0,0 +1,498 @@
+//! Transforming the bounds of ranges.
+
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl<Idx> RangeFromExclusive<Idx> {
+    /// Maps the range to a range over another type by applying `f` to its bound.
+    ///
+    /// `f` should be monotonically increasing: the resulting range only contains the mapped values
+    /// of the original range if `f` preserves their order.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 1u32 }.map(u64::from),
+    ///     RangeFromExclusive { start: 1u64 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn map<U, F>(self, mut f: F) -> RangeFromExclusive<U>
+    where
+        F: FnMut(Idx) -> U,
+    {
+        RangeFromExclusive {
+            start: f(self.start),
+        }
+    }
+
+    /// Converts from `&RangeFromExclusive<Idx>` to `RangeFromExclusive<&Idx>`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// let range = RangeFromExclusive { start: 1 };
+    ///
+    /// assert_eq!(range.as_ref(), RangeFromExclusive { start: &1 });
+    /// ```
+    #[inline]
+    pub fn as_ref(&self) -> RangeFromExclusive<&Idx> {
+        RangeFromExclusive { start: &self.start }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx> {
+    /// Maps the range to a range over another type by applying `f` to each bound.
+    ///
+    /// `f` is applied to `start` and then to `end`. It should be monotonically increasing: the
+    /// resulting range only contains the mapped values of the original range if `f` preserves their
+    /// order.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }.map(|bound| bound * 10),
+    ///     RangeFromExclusiveToInclusive { start: 10, end: 40 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn map<U, F>(self, mut f: F) -> RangeFromExclusiveToInclusive<U>
+    where
+        F: FnMut(Idx) -> U,
+    {
+        RangeFromExclusiveToInclusive {
+            start: f(self.start),
+            end: f(self.end),
+        }
+    }
+
+    /// Converts from `&RangeFromExclusiveToInclusive<Idx>` to `RangeFromExclusiveToInclusive<&Idx>`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.as_ref(), RangeFromExclusiveToInclusive { start: &1, end: &4 });
+    /// ```
+    #[inline]
+    pub fn as_ref(&self) -> RangeFromExclusiveToInclusive<&Idx> {
+        RangeFromExclusiveToInclusive {
+            start: &self.start,
+            end: &self.end,
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx> {
+    /// Maps the range to a range over another type by applying `f` to each bound.
+    ///
+    /// `f` is applied to `start` and then to `end`. It should be monotonically increasing: the
+    /// resulting range only contains the mapped values of the original range if `f` preserves their
+    /// order.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Offset(u32);
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }.map(Offset),
+    ///     RangeFromExclusiveToExclusive { start: Offset(1), end: Offset(4) }
+    /// );
+    /// ```
+    #[inline]
+    pub fn map<U, F>(self, mut f: F) -> RangeFromExclusiveToExclusive<U>
+    where
+        F: FnMut(Idx) -> U,
+    {
+        RangeFromExclusiveToExclusive {
+            start: f(self.start),
+            end: f(self.end),
+        }
+    }
+
+    /// Converts from `&RangeFromExclusiveToExclusive<Idx>` to `RangeFromExclusiveToExclusive<&Idx>`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.as_ref(), RangeFromExclusiveToExclusive { start: &1, end: &4 });
+    /// ```
+    #[inline]
+    pub fn as_ref(&self) -> RangeFromExclusiveToExclusive<&Idx> {
+        RangeFromExclusiveToExclusive {
+            start: &self.start,
+            end: &self.end,
+        }
+    }
+}
+
+impl<'a, Idx> RangeFromExclusive<&'a Idx>
+where
+    Idx: 'a,
+{
+    /// Maps a `RangeFromExclusive<&Idx>` to a `RangeFromExclusive<Idx>` by copying the bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// let range = RangeFromExclusive { start: &1u64 };
+    ///
+    /// assert_eq!(range.copied(), RangeFromExclusive { start: 1 });
+    /// ```
+    #[inline]
+    pub fn copied(self) -> RangeFromExclusive<Idx>
+    where
+        Idx: Copy,
+    {
+        RangeFromExclusive { start: *self.start }
+    }
+
+    /// Maps a `RangeFromExclusive<&Idx>` to a `RangeFromExclusive<Idx>` by cloning the bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// let range = RangeFromExclusive { start: &1u64 };
+    ///
+    /// assert_eq!(range.cloned(), RangeFromExclusive { start: 1 });
+    /// ```
+    #[inline]
+    pub fn cloned(self) -> RangeFromExclusive<Idx>
+    where
+        Idx: Clone,
+    {
+        RangeFromExclusive {
+            start: self.start.clone(),
+        }
+    }
+}
+
+impl<'a, Idx> RangeFromExclusiveToInclusive<&'a Idx>
+where
+    Idx: 'a,
+{
+    /// Maps a `RangeFromExclusiveToInclusive<&Idx>` to a `RangeFromExclusiveToInclusive<Idx>` by copying the bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: &1u64, end: &4 };
+    ///
+    /// assert_eq!(range.copied(), RangeFromExclusiveToInclusive { start: 1, end: 4 });
+    /// ```
+    #[inline]
+    pub fn copied(self) -> RangeFromExclusiveToInclusive<Idx>
+    where
+        Idx: Copy,
+    {
+        RangeFromExclusiveToInclusive {
+            start: *self.start,
+            end: *self.end,
+        }
+    }
+
+    /// Maps a `RangeFromExclusiveToInclusive<&Idx>` to a `RangeFromExclusiveToInclusive<Idx>` by cloning the bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: &1u64, end: &4 };
+    ///
+    /// assert_eq!(range.cloned(), RangeFromExclusiveToInclusive { start: 1, end: 4 });
+    /// ```
+    #[inline]
+    pub fn cloned(self) -> RangeFromExclusiveToInclusive<Idx>
+    where
+        Idx: Clone,
+    {
+        RangeFromExclusiveToInclusive {
+            start: self.start.clone(),
+            end: self.end.clone(),
+        }
+    }
+}
+
+impl<'a, Idx> RangeFromExclusiveToExclusive<&'a Idx>
+where
+    Idx: 'a,
+{
+    /// Maps a `RangeFromExclusiveToExclusive<&Idx>` to a `RangeFromExclusiveToExclusive<Idx>` by copying the bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: &1u64, end: &4 };
+    ///
+    /// assert_eq!(range.copied(), RangeFromExclusiveToExclusive { start: 1, end: 4 });
+    /// ```
+    #[inline]
+    pub fn copied(self) -> RangeFromExclusiveToExclusive<Idx>
+    where
+        Idx: Copy,
+    {
+        RangeFromExclusiveToExclusive {
+            start: *self.start,
+            end: *self.end,
+        }
+    }
+
+    /// Maps a `RangeFromExclusiveToExclusive<&Idx>` to a `RangeFromExclusiveToExclusive<Idx>` by cloning the bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: &1u64, end: &4 };
+    ///
+    /// assert_eq!(range.cloned(), RangeFromExclusiveToExclusive { start: 1, end: 4 });
+    /// ```
+    #[inline]
+    pub fn cloned(self) -> RangeFromExclusiveToExclusive<Idx>
+    where
+        Idx: Clone,
+    {
+        RangeFromExclusiveToExclusive {
+            start: self.start.clone(),
+            end: self.end.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[derive(Debug, PartialEq)]
+    struct Id(u64);
+
+    #[test]
+    fn range_from_exclusive_map_widen() {
+        assert_eq!(
+            RangeFromExclusive {
+                start: u32::max_value()
+            }
+            .map(u64::from),
+            RangeFromExclusive {
+                start: 4_294_967_295u64
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_map_wrapper() {
+        assert_eq!(
+            RangeFromExclusive { start: 3 }.map(Id),
+            RangeFromExclusive { start: Id(3) }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_map_widen() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -128i8,
+                end: 127
+            }
+            .map(i16::from),
+            RangeFromExclusiveToInclusive {
+                start: -128i16,
+                end: 127
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_map_wrapper() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }.map(Id),
+            RangeFromExclusiveToInclusive {
+                start: Id(1),
+                end: Id(4)
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_map_narrow() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 1u64,
+                end: 4
+            }
+            .map(|bound| bound as u8),
+            RangeFromExclusiveToExclusive { start: 1u8, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_map_wrapper() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }.map(Id),
+            RangeFromExclusiveToExclusive {
+                start: Id(1),
+                end: Id(4)
+            }
+        );
+    }
+
+    /// Returns a map with `String` keys `"a"` through `"e"`.
+    fn string_map() -> BTreeMap<String, usize> {
+        ["a", "b", "c", "d", "e"]
+            .iter()
+            .enumerate()
+            .map(|(index, key)| (key.to_string(), index))
+            .collect()
+    }
+
+    #[test]
+    fn range_from_exclusive_as_ref() {
+        let range = RangeFromExclusive {
+            start: "c".to_string(),
+        };
+
+        assert_eq!(
+            string_map()
+                .range::<String, _>(range.as_ref())
+                .map(|(_, &value)| value)
+                .collect::<Vec<_>>(),
+            [3, 4]
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_as_ref() {
+        let range = RangeFromExclusiveToInclusive {
+            start: "a".to_string(),
+            end: "c".to_string(),
+        };
+
+        assert_eq!(
+            string_map()
+                .range::<String, _>(range.as_ref())
+                .map(|(_, &value)| value)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(range.start, "a");
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_as_ref() {
+        let range = RangeFromExclusiveToExclusive {
+            start: "a".to_string(),
+            end: "d".to_string(),
+        };
+
+        assert_eq!(
+            string_map()
+                .range::<String, _>(range.as_ref())
+                .map(|(_, &value)| value)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_copied() {
+        let start = 5u64;
+
+        assert_eq!(
+            RangeFromExclusive { start: &start }.copied(),
+            RangeFromExclusive { start: 5 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_cloned() {
+        let start = "a".to_string();
+
+        assert_eq!(
+            RangeFromExclusive { start: &start }.cloned(),
+            RangeFromExclusive {
+                start: "a".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_copied() {
+        let (start, end) = (1u64, u64::max_value());
+
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: &start,
+                end: &end
+            }
+            .copied(),
+            RangeFromExclusiveToInclusive {
+                start: 1,
+                end: u64::max_value()
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_cloned() {
+        let range = RangeFromExclusiveToInclusive {
+            start: "a".to_string(),
+            end: "c".to_string(),
+        };
+
+        assert_eq!(range.as_ref().cloned(), range);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_copied() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 1u64,
+            end: 4,
+        };
+
+        assert_eq!(range.as_ref().copied(), range);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_cloned() {
+        let (start, end) = ("a".to_string(), "d".to_string());
+
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: &start,
+                end: &end
+            }
+            .cloned(),
+            RangeFromExclusiveToExclusive {
+                start: "a".to_string(),
+                end: "d".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn map_order() {
+        let mut calls = Vec::new();
+        RangeFromExclusiveToExclusive { start: 1, end: 4 }.map(|bound| calls.push(bound));
+
+        assert_eq!(calls, [1, 4]);
+    }
+}
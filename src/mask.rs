@@ -0,0 +1,349 @@
+//! Boolean selection masks of the indices contained in a range.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp;
+use core::ops::Range;
+#[cfg(feature = "alloc")]
+use resolve::{try_resolve_bounds, ResolveError};
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// Returns the half-open range of indices `start..end`, clamped to `len`.
+///
+/// The result is empty if `start >= end`.
+#[inline]
+fn clamp(start: usize, end: usize, len: usize) -> Range<usize> {
+    let end = cmp::min(end, len);
+    cmp::min(start, end)..end
+}
+
+/// Writes `true` into `mask` at exactly the indices in `selected`.
+#[inline]
+fn fill(mask: &mut [bool], selected: Range<usize>) {
+    for (index, value) in mask.iter_mut().enumerate() {
+        *value = index >= selected.start && index < selected.end;
+    }
+}
+
+/// Creates a mask of length `len` with `true` at exactly the indices in `selected`.
+#[cfg(feature = "alloc")]
+#[inline]
+fn create(len: usize, selected: Range<usize>) -> Vec<bool> {
+    let mut mask = Vec::with_capacity(len);
+    mask.resize(len, false);
+    fill(&mut mask, selected);
+    mask
+}
+
+impl RangeFromExclusiveToInclusive<usize> {
+    /// Writes a selection mask of the range into `mask`.
+    ///
+    /// Each element of `mask` is set to whether its index is contained in the range. The range is
+    /// clamped to the length of `mask`, so any part of it beyond the end of `mask` is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let mut mask = [true; 5];
+    /// RangeFromExclusiveToInclusive { start: 1, end: 3 }.fill_mask(&mut mask);
+    ///
+    /// assert_eq!(mask, [false, false, true, true, false]);
+    /// ```
+    pub fn fill_mask(&self, mask: &mut [bool]) {
+        let len = mask.len();
+        fill(
+            mask,
+            clamp(
+                self.start.saturating_add(1),
+                self.end.saturating_add(1),
+                len,
+            ),
+        );
+    }
+
+    /// Returns a selection mask of length `len`, with `true` at exactly the indices contained in
+    /// the range.
+    ///
+    /// The range is clamped to `len`, so any part of it beyond `len` is ignored. Use
+    /// [`try_to_mask()`] to treat that as an error instead.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 9 }.to_mask(4),
+    ///     [false, false, true, true]
+    /// );
+    /// ```
+    ///
+    /// [`try_to_mask()`]: RangeFromExclusiveToInclusive::try_to_mask
+    #[cfg(feature = "alloc")]
+    pub fn to_mask(&self, len: usize) -> Vec<bool> {
+        create(
+            len,
+            clamp(
+                self.start.saturating_add(1),
+                self.end.saturating_add(1),
+                len,
+            ),
+        )
+    }
+
+    /// Returns a selection mask of length `len`, with `true` at exactly the indices contained in
+    /// the range.
+    ///
+    /// # Errors
+    /// Returns an error if the range is inverted or extends beyond `len`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToInclusive, ResolveError};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 3 }.try_to_mask(4),
+    ///     Ok(vec![false, false, true, true])
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 9 }.try_to_mask(4),
+    ///     Err(ResolveError::EndOutOfBounds { index: 10, len: 4 })
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_to_mask(&self, len: usize) -> Result<Vec<bool>, ResolveError> {
+        try_resolve_bounds(*self, len).map(|selected| create(len, selected))
+    }
+}
+
+impl RangeFromExclusiveToExclusive<usize> {
+    /// Writes a selection mask of the range into `mask`.
+    ///
+    /// Each element of `mask` is set to whether its index is contained in the range. The range is
+    /// clamped to the length of `mask`, so any part of it beyond the end of `mask` is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let mut mask = [true; 5];
+    /// RangeFromExclusiveToExclusive { start: 1, end: 3 }.fill_mask(&mut mask);
+    ///
+    /// assert_eq!(mask, [false, false, true, false, false]);
+    /// ```
+    pub fn fill_mask(&self, mask: &mut [bool]) {
+        let len = mask.len();
+        fill(mask, clamp(self.start.saturating_add(1), self.end, len));
+    }
+
+    /// Returns a selection mask of length `len`, with `true` at exactly the indices contained in
+    /// the range.
+    ///
+    /// The range is clamped to `len`, so any part of it beyond `len` is ignored. Use
+    /// [`try_to_mask()`] to treat that as an error instead.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 9 }.to_mask(4),
+    ///     [false, false, true, true]
+    /// );
+    /// ```
+    ///
+    /// [`try_to_mask()`]: RangeFromExclusiveToExclusive::try_to_mask
+    #[cfg(feature = "alloc")]
+    pub fn to_mask(&self, len: usize) -> Vec<bool> {
+        create(len, clamp(self.start.saturating_add(1), self.end, len))
+    }
+
+    /// Returns a selection mask of length `len`, with `true` at exactly the indices contained in
+    /// the range.
+    ///
+    /// # Errors
+    /// Returns an error if the range is inverted or extends beyond `len`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, ResolveError};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }.try_to_mask(4),
+    ///     Ok(vec![false, false, true, true])
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 9 }.try_to_mask(4),
+    ///     Err(ResolveError::EndOutOfBounds { index: 9, len: 4 })
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_to_mask(&self, len: usize) -> Result<Vec<bool>, ResolveError> {
+        try_resolve_bounds(*self, len).map(|selected| create(len, selected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::RangeBounds;
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    /// Asserts that `mask` selects exactly the indices contained in `range`.
+    fn assert_matches_contains<R>(mask: &[bool], range: &R)
+    where
+        R: RangeBounds<usize>,
+    {
+        for (index, &selected) in mask.iter().enumerate() {
+            assert_eq!(selected, range.contains(&index), "index {}", index);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_fill_mask_brute_force() {
+        for len in 0..8 {
+            for start in 0..10 {
+                for end in 0..10 {
+                    let range = RangeFromExclusiveToInclusive { start, end };
+                    let mut mask = [true; 8];
+                    range.fill_mask(&mut mask[..len]);
+
+                    assert_matches_contains(&mask[..len], &range);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_fill_mask_brute_force() {
+        for len in 0..8 {
+            for start in 0..10 {
+                for end in 0..10 {
+                    let range = RangeFromExclusiveToExclusive { start, end };
+                    let mut mask = [true; 8];
+                    range.fill_mask(&mut mask[..len]);
+
+                    assert_matches_contains(&mask[..len], &range);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fill_mask_extreme_bounds() {
+        let mut mask = [true; 4];
+
+        RangeFromExclusiveToInclusive {
+            start: 1,
+            end: usize::max_value(),
+        }
+        .fill_mask(&mut mask);
+        assert_eq!(mask, [false, false, true, true]);
+
+        RangeFromExclusiveToExclusive {
+            start: usize::max_value(),
+            end: usize::max_value(),
+        }
+        .fill_mask(&mut mask);
+        assert_eq!(mask, [false; 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    mod to_mask {
+        use super::assert_matches_contains;
+        use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive, ResolveError};
+
+        #[test]
+        fn range_from_exclusive_to_inclusive_to_mask() {
+            for len in 0..8 {
+                for start in 0..10 {
+                    for end in 0..10 {
+                        let range = RangeFromExclusiveToInclusive { start, end };
+                        let mask = range.to_mask(len);
+
+                        assert_eq!(mask.len(), len);
+                        assert_matches_contains(&mask, &range);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn range_from_exclusive_to_exclusive_to_mask() {
+            for len in 0..8 {
+                for start in 0..10 {
+                    for end in 0..10 {
+                        let range = RangeFromExclusiveToExclusive { start, end };
+                        let mask = range.to_mask(len);
+
+                        assert_eq!(mask.len(), len);
+                        assert_matches_contains(&mask, &range);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn to_mask_clamps_overhang() {
+            assert_eq!(
+                RangeFromExclusiveToExclusive { start: 2, end: 10 }.to_mask(5),
+                [false, false, false, true, true]
+            );
+            assert_eq!(
+                RangeFromExclusiveToInclusive { start: 7, end: 10 }.to_mask(5),
+                [false; 5]
+            );
+        }
+
+        #[test]
+        fn range_from_exclusive_to_inclusive_try_to_mask() {
+            assert_ok_eq!(
+                RangeFromExclusiveToInclusive { start: 2, end: 4 }.try_to_mask(5),
+                [false, false, false, true, true]
+            );
+            assert_eq!(
+                RangeFromExclusiveToInclusive { start: 2, end: 5 }.try_to_mask(5),
+                Err(ResolveError::EndOutOfBounds { index: 6, len: 5 })
+            );
+            assert_eq!(
+                RangeFromExclusiveToInclusive { start: 3, end: 2 }.try_to_mask(5),
+                Err(ResolveError::Inverted { start: 4, end: 3 })
+            );
+            assert_eq!(
+                RangeFromExclusiveToInclusive {
+                    start: 2,
+                    end: usize::max_value()
+                }
+                .try_to_mask(5),
+                Err(ResolveError::EndOverflow)
+            );
+        }
+
+        #[test]
+        fn range_from_exclusive_to_exclusive_try_to_mask() {
+            assert_ok_eq!(
+                RangeFromExclusiveToExclusive { start: 2, end: 5 }.try_to_mask(5),
+                [false, false, false, true, true]
+            );
+            assert_ok_eq!(
+                RangeFromExclusiveToExclusive { start: 5, end: 5 }.try_to_mask(5),
+                [false; 5]
+            );
+            assert_eq!(
+                RangeFromExclusiveToExclusive { start: 2, end: 6 }.try_to_mask(5),
+                Err(ResolveError::EndOutOfBounds { index: 6, len: 5 })
+            );
+            assert_eq!(
+                RangeFromExclusiveToExclusive { start: 3, end: 2 }.try_to_mask(5),
+                Err(ResolveError::Inverted { start: 4, end: 2 })
+            );
+            assert_eq!(
+                RangeFromExclusiveToExclusive {
+                    start: usize::max_value(),
+                    end: 2
+                }
+                .try_to_mask(5),
+                Err(ResolveError::StartOverflow)
+            );
+        }
+    }
+}
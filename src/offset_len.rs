@@ -0,0 +1,288 @@
+//! Conversion between ranges and `(offset, len)` pairs.
+
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl RangeFromExclusiveToInclusive<usize> {
+    /// Returns the first index contained in the range and the number of indices it contains.
+    ///
+    /// Returns `None` if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }.to_offset_len(),
+    ///     Some((2, 3))
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 4, end: 4 }.to_offset_len(),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_offset_len(&self) -> Option<(usize, usize)> {
+        if self.start < self.end {
+            // `start < end` guarantees `start + 1` does not overflow.
+            Some((self.start + 1, self.end - self.start))
+        } else {
+            None
+        }
+    }
+
+    /// Creates the range containing the `len` indices beginning at `offset`.
+    ///
+    /// Returns `None` if `offset` is `0`, as the range would need to start below `0`, or if the
+    /// last index would overflow a `usize`. A `len` of `0` gives an empty range positioned at
+    /// `offset`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive::from_offset_len(2, 3),
+    ///     Some(RangeFromExclusiveToInclusive { start: 1, end: 4 })
+    /// );
+    /// assert_eq!(RangeFromExclusiveToInclusive::from_offset_len(0, 3), None);
+    /// ```
+    #[inline]
+    pub fn from_offset_len(offset: usize, len: usize) -> Option<Self> {
+        let start = offset.checked_sub(1)?;
+        Some(RangeFromExclusiveToInclusive {
+            start,
+            end: start.checked_add(len)?,
+        })
+    }
+}
+
+impl RangeFromExclusiveToExclusive<usize> {
+    /// Returns the first index contained in the range and the number of indices it contains.
+    ///
+    /// Returns `None` if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }.to_offset_len(),
+    ///     Some((2, 2))
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 3, end: 4 }.to_offset_len(),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_offset_len(&self) -> Option<(usize, usize)> {
+        let offset = self.start.checked_add(1)?;
+        if offset < self.end {
+            Some((offset, self.end - offset))
+        } else {
+            None
+        }
+    }
+
+    /// Creates the range containing the `len` indices beginning at `offset`.
+    ///
+    /// Returns `None` if `offset` is `0` and `len` is not, as the range would need to start below
+    /// `0`, or if the end of the range would overflow a `usize`. A `len` of `0` gives the empty
+    /// range with both bounds equal to `offset`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive::from_offset_len(2, 2),
+    ///     Some(RangeFromExclusiveToExclusive { start: 1, end: 4 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive::from_offset_len(0, 0),
+    ///     Some(RangeFromExclusiveToExclusive { start: 0, end: 0 })
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_offset_len(offset: usize, len: usize) -> Option<Self> {
+        if len == 0 {
+            return Some(RangeFromExclusiveToExclusive {
+                start: offset,
+                end: offset,
+            });
+        }
+        Some(RangeFromExclusiveToExclusive {
+            start: offset.checked_sub(1)?,
+            end: offset.checked_add(len)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(impl_index)]
+    use resolve::Resolve;
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[test]
+    fn to_inclusive_to_offset_len_at_maximum() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: usize::max_value() - 1,
+                end: usize::max_value(),
+            }
+            .to_offset_len(),
+            (usize::max_value(), 1)
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 0,
+                end: usize::max_value(),
+            }
+            .to_offset_len(),
+            (1, usize::max_value())
+        );
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: usize::max_value(),
+            end: usize::max_value(),
+        }
+        .to_offset_len());
+    }
+
+    #[test]
+    fn to_inclusive_to_offset_len_inverted() {
+        assert_none!(RangeFromExclusiveToInclusive { start: 4, end: 2 }.to_offset_len());
+    }
+
+    #[test]
+    fn to_inclusive_from_offset_len_overflow() {
+        assert_none!(RangeFromExclusiveToInclusive::from_offset_len(
+            usize::max_value(),
+            2
+        ));
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive::from_offset_len(usize::max_value(), 1),
+            RangeFromExclusiveToInclusive {
+                start: usize::max_value() - 1,
+                end: usize::max_value(),
+            }
+        );
+    }
+
+    #[test]
+    fn to_inclusive_from_offset_len_empty() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive::from_offset_len(3, 0),
+            RangeFromExclusiveToInclusive { start: 2, end: 2 }
+        );
+    }
+
+    #[test]
+    fn to_inclusive_round_trip() {
+        for offset in 1..10 {
+            for len in 1..10 {
+                let range = RangeFromExclusiveToInclusive::from_offset_len(offset, len).unwrap();
+                assert_some_eq!(range.to_offset_len(), (offset, len));
+            }
+        }
+        for start in 0..10 {
+            for end in start + 1..10 {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                let (offset, len) = range.to_offset_len().unwrap();
+                assert_some_eq!(
+                    RangeFromExclusiveToInclusive::from_offset_len(offset, len),
+                    range
+                );
+            }
+        }
+    }
+
+    #[cfg(impl_index)]
+    #[test]
+    fn to_inclusive_agrees_with_resolve() {
+        for start in 0..10 {
+            for end in start..10 {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                let resolved = range.resolve(10);
+                match range.to_offset_len() {
+                    Some((offset, len)) => assert_eq!(offset..offset + len, resolved),
+                    None => assert!(resolved.start >= resolved.end),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_exclusive_to_offset_len_at_maximum() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: usize::max_value() - 2,
+                end: usize::max_value(),
+            }
+            .to_offset_len(),
+            (usize::max_value() - 1, 1)
+        );
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: usize::max_value(),
+            end: usize::max_value(),
+        }
+        .to_offset_len());
+    }
+
+    #[test]
+    fn to_exclusive_to_offset_len_inverted() {
+        assert_none!(RangeFromExclusiveToExclusive { start: 4, end: 2 }.to_offset_len());
+    }
+
+    #[test]
+    fn to_exclusive_from_offset_len_overflow() {
+        assert_none!(RangeFromExclusiveToExclusive::from_offset_len(
+            usize::max_value(),
+            1
+        ));
+        assert_none!(RangeFromExclusiveToExclusive::from_offset_len(0, 1));
+    }
+
+    #[test]
+    fn to_exclusive_from_offset_len_empty() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive::from_offset_len(3, 0),
+            RangeFromExclusiveToExclusive { start: 3, end: 3 }
+        );
+    }
+
+    #[test]
+    fn to_exclusive_round_trip() {
+        for offset in 1..10 {
+            for len in 1..10 {
+                let range = RangeFromExclusiveToExclusive::from_offset_len(offset, len).unwrap();
+                assert_some_eq!(range.to_offset_len(), (offset, len));
+            }
+        }
+        for start in 0..10 {
+            for end in start + 2..10 {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                let (offset, len) = range.to_offset_len().unwrap();
+                assert_some_eq!(
+                    RangeFromExclusiveToExclusive::from_offset_len(offset, len),
+                    range
+                );
+            }
+        }
+    }
+
+    #[cfg(impl_index)]
+    #[test]
+    fn to_exclusive_agrees_with_resolve() {
+        for start in 0..10 {
+            for end in start..10 {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                let resolved = range.resolve(10);
+                match range.to_offset_len() {
+                    Some((offset, len)) => assert_eq!(offset..offset + len, resolved),
+                    None => assert!(resolved.start >= resolved.end),
+                }
+            }
+        }
+    }
+}
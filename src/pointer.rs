@@ -0,0 +1,371 @@
+//! Stepping over ranges of [`NonNull`] pointers.
+//!
+//! A pointer steps by the size of its pointee, so the successor of a `NonNull<T>` points to the next
+//! `T` in memory. Pointers to zero-sized types cannot be stepped, and attempting to do so panics.
+//!
+//! [`NonNull`]: core::ptr::NonNull
+
+use core::fmt;
+use core::iter;
+use core::mem;
+use core::ptr::NonNull;
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive, StepsBetween};
+
+#[cold]
+#[inline(never)]
+fn zero_sized_fail() -> ! {
+    panic!("attempted to step a pointer to a zero-sized type");
+}
+
+/// Returns the size of `T`, panicking if `T` is zero-sized.
+#[inline]
+fn step_size<T>() -> usize {
+    let size = mem::size_of::<T>();
+    if size == 0 {
+        zero_sized_fail();
+    }
+    size
+}
+
+/// Steps by `size_of::<T>()` bytes.
+///
+/// `end` is only reachable from `start` if the two pointers are a whole number of `T`s apart, as is
+/// always the case for pointers into the same allocation. Otherwise, `None` is returned.
+///
+/// # Panics
+/// Panics if `T` is zero-sized.
+impl<T> StepsBetween for NonNull<T> {
+    #[inline]
+    fn steps_between(start: &Self, end: &Self) -> Option<usize> {
+        let size = step_size::<T>();
+        let start = start.as_ptr() as usize;
+        let end = end.as_ptr() as usize;
+        if start > end {
+            return None;
+        }
+        let distance = end - start;
+        if distance % size == 0 {
+            Some(distance / size)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn steps_between_wide(start: &Self, end: &Self) -> Option<u128> {
+        Self::steps_between(start, end).map(|steps| steps as u128)
+    }
+}
+
+/// An iterator over the pointers in a range of [`NonNull`] pointers.
+///
+/// This is created by the `iter()` method on the bounded ranges over `NonNull<T>`. The first
+/// pointer yielded points one `T` past `start`.
+///
+/// # Soundness
+/// Stepping is only meaningful when both bounds of a range point into, or one element past the end
+/// of, the same allocation. The arithmetic here never dereferences a pointer and uses only wrapping
+/// offsets, so it is safe to perform on any pointers, but the pointers it produces are only valid
+/// to dereference if they lie within an allocation that the bounds were derived from. Ranges whose
+/// bounds come from different allocations produce meaningless, though still non-null, pointers.
+///
+/// The bounds must also carry permission to access every element they step over. A pointer created
+/// from a reference to a single element may only access that element, so bounds should be derived
+/// from a pointer to the whole buffer, such as one returned by [`slice::as_mut_ptr()`].
+///
+/// [`slice::as_mut_ptr()`]: https://doc.rust-lang.org/std/primitive.slice.html#method.as_mut_ptr
+///
+/// [`NonNull`]: core::ptr::NonNull
+pub struct PointerSteps<T> {
+    current: NonNull<T>,
+    remaining: usize,
+}
+
+impl<T> PointerSteps<T> {
+    #[inline]
+    fn new(start: NonNull<T>, end: NonNull<T>, inclusive: bool) -> Self {
+        let size = step_size::<T>();
+        let start_address = start.as_ptr() as usize;
+        let end_address = end.as_ptr() as usize;
+        let remaining = if start_address >= end_address {
+            0
+        } else if inclusive {
+            (end_address - start_address) / size
+        } else {
+            (end_address - start_address - 1) / size
+        };
+        PointerSteps {
+            current: start,
+            remaining,
+        }
+    }
+}
+
+impl<T> Clone for PointerSteps<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        PointerSteps {
+            current: self.current,
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<T> fmt::Debug for PointerSteps<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PointerSteps")
+            .field("current", &self.current)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<T> Iterator for PointerSteps<T> {
+    type Item = NonNull<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<NonNull<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // Every remaining pointer lies strictly above `start`, so the address never wraps to null.
+        self.current = NonNull::new(self.current.as_ptr().wrapping_add(1))?;
+        self.remaining -= 1;
+        Some(self.current)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for PointerSteps<T> {}
+
+impl<T> iter::FusedIterator for PointerSteps<T> {}
+
+impl<T> RangeFromExclusiveToExclusive<NonNull<T>> {
+    /// Returns an iterator over the pointers in the range, beginning one `T` past `start` and
+    /// ending before `end`.
+    ///
+    /// The pointers are only valid to dereference if `start` and `end` were derived from a pointer
+    /// to the same allocation. See the [`PointerSteps`] documentation for details.
+    ///
+    /// # Panics
+    /// Panics if `T` is zero-sized.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    /// use std::ptr::NonNull;
+    ///
+    /// let mut values = [1, 2, 3, 4];
+    /// let base = values.as_mut_ptr();
+    /// let range = RangeFromExclusiveToExclusive {
+    ///     start: NonNull::new(base).unwrap(),
+    ///     end: NonNull::new(base.wrapping_add(3)).unwrap(),
+    /// };
+    ///
+    /// for mut pointer in range.iter() {
+    ///     // SAFETY: Every pointer in the range points to an element of `values`.
+    ///     unsafe { *pointer.as_mut() *= 10 };
+    /// }
+    /// assert_eq!(values, [1, 20, 30, 4]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> PointerSteps<T> {
+        PointerSteps::new(self.start, self.end, false)
+    }
+}
+
+impl<T> RangeFromExclusiveToInclusive<NonNull<T>> {
+    /// Returns an iterator over the pointers in the range, beginning one `T` past `start` and
+    /// ending at `end`.
+    ///
+    /// The pointers are only valid to dereference if `start` and `end` were derived from a pointer
+    /// to the same allocation. See the [`PointerSteps`] documentation for details.
+    ///
+    /// # Panics
+    /// Panics if `T` is zero-sized.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    /// use std::ptr::NonNull;
+    ///
+    /// let values = [1, 2, 3, 4];
+    /// let base = values.as_ptr() as *mut i32;
+    /// let range = RangeFromExclusiveToInclusive {
+    ///     start: NonNull::new(base).unwrap(),
+    ///     end: NonNull::new(base.wrapping_add(3)).unwrap(),
+    /// };
+    ///
+    /// // SAFETY: Every pointer in the range points to an element of `values`.
+    /// let sum: i32 = range.iter().map(|pointer| unsafe { *pointer.as_ptr() }).sum();
+    /// assert_eq!(sum, 9);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> PointerSteps<T> {
+        PointerSteps::new(self.start, self.end, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ptr::NonNull;
+    use std::vec::Vec;
+    use {steps_between, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[derive(Debug, PartialEq)]
+    struct Node {
+        value: u64,
+        next: Option<usize>,
+    }
+
+    fn nodes(len: usize) -> Vec<Node> {
+        (0..len)
+            .map(|i| Node {
+                value: i as u64 * 10,
+                next: Some(i + 1),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn steps_between_elements() {
+        let nodes = nodes(8);
+        let base = nodes.as_ptr();
+        let first = NonNull::from(&nodes[0]);
+
+        for i in 0..=8 {
+            let pointer = NonNull::new(base.wrapping_add(i) as *mut Node).unwrap();
+            assert_some_eq!(steps_between(&first, &pointer), i);
+        }
+        assert_none!(steps_between(&NonNull::from(&nodes[3]), &first));
+    }
+
+    #[test]
+    fn steps_between_unaligned() {
+        let bytes = [0u32; 4];
+        let start = NonNull::from(&bytes[0]);
+        let end = NonNull::new((bytes.as_ptr() as *mut u8).wrapping_add(6) as *mut u32).unwrap();
+
+        assert_none!(steps_between(&start, &end));
+    }
+
+    #[test]
+    fn to_exclusive_iter() {
+        let mut nodes = nodes(6);
+        let base = nodes.as_mut_ptr();
+        let range = RangeFromExclusiveToExclusive {
+            start: NonNull::new(base.wrapping_add(1)).unwrap(),
+            end: NonNull::new(base.wrapping_add(5)).unwrap(),
+        };
+
+        let pointers = range.iter().collect::<Vec<_>>();
+        assert_eq!(pointers.len(), 3);
+        for (i, mut pointer) in pointers.into_iter().enumerate() {
+            assert_eq!(pointer.as_ptr(), base.wrapping_add(i + 2));
+            unsafe {
+                pointer.as_mut().next = None;
+            }
+        }
+        assert_eq!(
+            nodes.iter().map(|node| node.next).collect::<Vec<_>>(),
+            [Some(1), Some(2), None, None, None, Some(6)]
+        );
+    }
+
+    #[test]
+    fn to_exclusive_iter_before_first_element() {
+        let nodes = nodes(4);
+        let base = nodes.as_ptr() as *mut Node;
+        // The exclusive start may point one element before the allocation, as long as it is never
+        // dereferenced.
+        let range = RangeFromExclusiveToExclusive {
+            start: NonNull::new(base.wrapping_sub(1)).unwrap(),
+            end: NonNull::new(base.wrapping_add(4)).unwrap(),
+        };
+
+        let values = range
+            .iter()
+            .map(|pointer| unsafe { pointer.as_ref().value })
+            .collect::<Vec<_>>();
+        assert_eq!(values, [0, 10, 20, 30]);
+    }
+
+    #[test]
+    fn to_inclusive_iter() {
+        let nodes = nodes(5);
+        let range = RangeFromExclusiveToInclusive {
+            start: NonNull::from(&nodes[0]),
+            end: NonNull::from(&nodes[4]),
+        };
+
+        let mut iter = range.iter();
+        assert_eq!(iter.len(), 4);
+        assert_some_eq!(iter.next(), NonNull::from(&nodes[1]));
+        assert_eq!(iter.len(), 3);
+        assert_some_eq!(iter.last(), NonNull::from(&nodes[4]));
+    }
+
+    #[test]
+    fn empty() {
+        let nodes = nodes(3);
+        let first = NonNull::from(&nodes[0]);
+        let second = NonNull::from(&nodes[1]);
+
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: first,
+            end: second
+        }
+        .iter()
+        .next());
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: first,
+            end: first
+        }
+        .iter()
+        .next());
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: second,
+            end: first
+        }
+        .iter()
+        .next());
+    }
+
+    #[test]
+    fn iter_fused() {
+        let nodes = nodes(3);
+        let mut iter = RangeFromExclusiveToInclusive {
+            start: NonNull::from(&nodes[1]),
+            end: NonNull::from(&nodes[2]),
+        }
+        .iter();
+
+        assert_some!(iter.next());
+        assert_none!(iter.next());
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to step a pointer to a zero-sized type")]
+    fn zero_sized_iter() {
+        let units = [(), ()];
+
+        RangeFromExclusiveToExclusive {
+            start: NonNull::from(&units[0]),
+            end: NonNull::from(&units[1]),
+        }
+        .iter();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to step a pointer to a zero-sized type")]
+    fn zero_sized_steps_between() {
+        let unit = ();
+
+        steps_between(&NonNull::from(&unit), &NonNull::from(&unit));
+    }
+}
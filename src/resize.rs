@@ -0,0 +1,654 @@
+//! Growing and shrinking the range types over primitive integers.
+
+use iter_bounds::Integer;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl<Idx> RangeFromExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Returns the range extended downwards, if necessary, to contain `value`.
+    ///
+    /// Returns `None` if `value` is the minimum value of `Idx` and is not already contained, since
+    /// no exclusive lower bound admits it.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 5 }.extended_to_include(2),
+    ///     Some(RangeFromExclusive { start: 1 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 5 }.extended_to_include(9),
+    ///     Some(RangeFromExclusive { start: 5 })
+    /// );
+    /// assert_eq!(RangeFromExclusive { start: 5u8 }.extended_to_include(0), None);
+    /// ```
+    #[inline]
+    pub fn extended_to_include(self, value: Idx) -> Option<Self> {
+        if value > self.start {
+            Some(self)
+        } else {
+            value
+                .checked_pred()
+                .map(|start| RangeFromExclusive { start })
+        }
+    }
+
+    /// Extends the range downwards, if necessary, to contain `value`.
+    ///
+    /// Returns `false`, leaving the range unchanged, if `value` is the minimum value of `Idx` and
+    /// is not already contained, since no exclusive lower bound admits it.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// let mut range = RangeFromExclusive { start: 5 };
+    ///
+    /// assert!(range.extend_to_include(2));
+    /// assert_eq!(range, RangeFromExclusive { start: 1 });
+    /// ```
+    #[inline]
+    pub fn extend_to_include(&mut self, value: Idx) -> bool {
+        match self.extended_to_include(value) {
+            Some(range) => {
+                *self = range;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Widens the range by `by` on both sides, decreasing `start` and increasing `end`.
+    ///
+    /// Returns `None` if either bound overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 3u8, end: 5 }.widen(2),
+    ///     Some(RangeFromExclusiveToInclusive { start: 1, end: 7 })
+    /// );
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 1u8, end: 5 }.widen(2), None);
+    /// ```
+    #[inline]
+    pub fn widen(self, by: Idx) -> Option<Self> {
+        Some(RangeFromExclusiveToInclusive {
+            start: self.start.checked_sub(by)?,
+            end: self.end.checked_add(by)?,
+        })
+    }
+
+    /// Shrinks the range by `by` on both sides, increasing `start` and decreasing `end`.
+    ///
+    /// If this would leave no values in the range, the range collapses to an empty range with both
+    /// bounds equal instead of becoming inverted.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1u8, end: 7 }.shrink(2),
+    ///     RangeFromExclusiveToInclusive { start: 3, end: 5 }
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1u8, end: 7 }.shrink(4),
+    ///     RangeFromExclusiveToInclusive { start: 3, end: 3 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn shrink(self, by: Idx) -> Self {
+        let end = self.end.saturating_sub(by).max(self.start);
+        RangeFromExclusiveToInclusive {
+            start: self.start.saturating_add(by).min(end),
+            end,
+        }
+    }
+
+    /// Returns the smallest range containing both the values of this range and `value`.
+    ///
+    /// `start` is lowered to `value - 1` if `value` is not above it, and `end` is raised to `value`
+    /// if it is below it. An empty range is replaced by the range containing only `value`.
+    ///
+    /// Returns `None` if `value` is the minimum value of `Idx` and is not already contained, since
+    /// no exclusive lower bound admits it.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 3, end: 5 };
+    ///
+    /// assert_eq!(
+    ///     range.extended_to_include(1),
+    ///     Some(RangeFromExclusiveToInclusive { start: 0, end: 5 })
+    /// );
+    /// assert_eq!(
+    ///     range.extended_to_include(8),
+    ///     Some(RangeFromExclusiveToInclusive { start: 3, end: 8 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 3, end: 3 }.extended_to_include(8),
+    ///     Some(RangeFromExclusiveToInclusive { start: 7, end: 8 })
+    /// );
+    /// ```
+    #[inline]
+    pub fn extended_to_include(self, value: Idx) -> Option<Self> {
+        if self.start >= self.end {
+            return value
+                .checked_pred()
+                .map(|start| RangeFromExclusiveToInclusive { start, end: value });
+        }
+        Some(RangeFromExclusiveToInclusive {
+            start: if value > self.start {
+                self.start
+            } else {
+                value.checked_pred()?
+            },
+            end: self.end.max(value),
+        })
+    }
+
+    /// Extends the range, if necessary, to contain `value`.
+    ///
+    /// This is the in-place version of [`extended_to_include()`]. Returns `false`, leaving the
+    /// range unchanged, if `value` is the minimum value of `Idx` and is not already contained.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let mut bounds = RangeFromExclusiveToInclusive { start: 0, end: 0 };
+    /// for &key in [7, 3, 9, 4].iter() {
+    ///     assert!(bounds.extend_to_include(key));
+    /// }
+    ///
+    /// assert_eq!(bounds, RangeFromExclusiveToInclusive { start: 2, end: 9 });
+    /// ```
+    ///
+    /// [`extended_to_include()`]: RangeFromExclusiveToInclusive::extended_to_include
+    #[inline]
+    pub fn extend_to_include(&mut self, value: Idx) -> bool {
+        match self.extended_to_include(value) {
+            Some(range) => {
+                *self = range;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Widens the range by `by` on both sides, decreasing `start` and increasing `end`.
+    ///
+    /// Returns `None` if either bound overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 3u8, end: 6 }.widen(2),
+    ///     Some(RangeFromExclusiveToExclusive { start: 1, end: 8 })
+    /// );
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 3u8, end: 255 }.widen(2), None);
+    /// ```
+    #[inline]
+    pub fn widen(self, by: Idx) -> Option<Self> {
+        Some(RangeFromExclusiveToExclusive {
+            start: self.start.checked_sub(by)?,
+            end: self.end.checked_add(by)?,
+        })
+    }
+
+    /// Shrinks the range by `by` on both sides, increasing `start` and decreasing `end`.
+    ///
+    /// If this would leave no values in the range, the range collapses to an empty range with both
+    /// bounds equal instead of becoming inverted.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1u8, end: 8 }.shrink(2),
+    ///     RangeFromExclusiveToExclusive { start: 3, end: 6 }
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1u8, end: 8 }.shrink(3),
+    ///     RangeFromExclusiveToExclusive { start: 5, end: 5 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn shrink(self, by: Idx) -> Self {
+        let end = self.end.saturating_sub(by).max(self.start);
+        let start = self.start.saturating_add(by).min(end);
+        // `start <= end`, so the successor of `start` only overflows if both are the maximum.
+        match start.checked_succ() {
+            Some(succ) if succ < end => RangeFromExclusiveToExclusive { start, end },
+            _ => RangeFromExclusiveToExclusive { start: end, end },
+        }
+    }
+
+    /// Returns the smallest range containing both the values of this range and `value`.
+    ///
+    /// `start` is lowered to `value - 1` if `value` is not above it, and `end` is raised to
+    /// `value + 1` if `value` is not below it. An empty range is replaced by the range containing
+    /// only `value`.
+    ///
+    /// Returns `None` if `value` is the minimum or maximum value of `Idx` and is not already
+    /// contained, since no exclusive bound admits it.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 3, end: 6 };
+    ///
+    /// assert_eq!(
+    ///     range.extended_to_include(1),
+    ///     Some(RangeFromExclusiveToExclusive { start: 0, end: 6 })
+    /// );
+    /// assert_eq!(
+    ///     range.extended_to_include(8),
+    ///     Some(RangeFromExclusiveToExclusive { start: 3, end: 9 })
+    /// );
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 3u8, end: 6 }.extended_to_include(255), None);
+    /// ```
+    #[inline]
+    pub fn extended_to_include(self, value: Idx) -> Option<Self> {
+        if self.is_empty_discrete() {
+            return Some(RangeFromExclusiveToExclusive {
+                start: value.checked_pred()?,
+                end: value.checked_succ()?,
+            });
+        }
+        Some(RangeFromExclusiveToExclusive {
+            start: if value > self.start {
+                self.start
+            } else {
+                value.checked_pred()?
+            },
+            end: if value < self.end {
+                self.end
+            } else {
+                value.checked_succ()?
+            },
+        })
+    }
+
+    /// Extends the range, if necessary, to contain `value`.
+    ///
+    /// This is the in-place version of [`extended_to_include()`]. Returns `false`, leaving the
+    /// range unchanged, if `value` is the minimum or maximum value of `Idx` and is not already
+    /// contained.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let mut bounds = RangeFromExclusiveToExclusive { start: 0, end: 0 };
+    /// for &key in [7, 3, 9, 4].iter() {
+    ///     assert!(bounds.extend_to_include(key));
+    /// }
+    ///
+    /// assert_eq!(bounds, RangeFromExclusiveToExclusive { start: 2, end: 10 });
+    /// ```
+    ///
+    /// [`extended_to_include()`]: RangeFromExclusiveToExclusive::extended_to_include
+    #[inline]
+    pub fn extend_to_include(&mut self, value: Idx) -> bool {
+        match self.extended_to_include(value) {
+            Some(range) => {
+                *self = range;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iter_bounds::iter_bounds;
+    use std::vec::Vec;
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_widen() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -1i8,
+                end: 1
+            }
+            .widen(3),
+            RangeFromExclusiveToInclusive { start: -4, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_widen_at_limits() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 0u8,
+                end: 255
+            }
+            .widen(0),
+            RangeFromExclusiveToInclusive { start: 0, end: 255 }
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -127i8,
+                end: 126
+            }
+            .widen(1),
+            RangeFromExclusiveToInclusive {
+                start: -128,
+                end: 127
+            }
+        );
+        // Only the start overflows.
+        assert_none!(RangeFromExclusiveToInclusive { start: 0u8, end: 5 }.widen(1));
+        // Only the end overflows.
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: 5u8,
+            end: 255
+        }
+        .widen(1));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_shrink() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        assert_eq!(range.shrink(0), range);
+        assert_eq!(
+            range.shrink(1),
+            RangeFromExclusiveToInclusive { start: 1, end: 9 }
+        );
+        assert_eq!(
+            range.shrink(4),
+            RangeFromExclusiveToInclusive { start: 4, end: 6 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_shrink_past_empty() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        for by in 5..=255 {
+            let shrunk = range.shrink(by);
+            assert_eq!(shrunk.start, shrunk.end);
+            assert_eq!(iter_bounds(shrunk).count(), 0);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_shrink_agrees_with_values() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        for by in 0..8u8 {
+            assert!(iter_bounds(range.shrink(by)).eq(iter_bounds(range)
+                .skip(by as usize)
+                .take(10usize.saturating_sub(2 * by as usize))));
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_shrink_at_limits() {
+        let range = RangeFromExclusiveToInclusive {
+            start: i8::min_value(),
+            end: i8::max_value(),
+        };
+
+        assert_eq!(
+            range.shrink(i8::max_value()),
+            RangeFromExclusiveToInclusive { start: -1, end: 0 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_widen() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: -1i8,
+                end: 1
+            }
+            .widen(3),
+            RangeFromExclusiveToExclusive { start: -4, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_widen_at_limits() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: -127i8,
+                end: 126
+            }
+            .widen(1),
+            RangeFromExclusiveToExclusive {
+                start: -128,
+                end: 127
+            }
+        );
+        // Only the start overflows.
+        assert_none!(RangeFromExclusiveToExclusive { start: 0u8, end: 5 }.widen(1));
+        // Only the end overflows.
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: 5u8,
+            end: 255
+        }
+        .widen(1));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_shrink() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        assert_eq!(range.shrink(0), range);
+        assert_eq!(
+            range.shrink(1),
+            RangeFromExclusiveToExclusive { start: 1, end: 9 }
+        );
+        assert_eq!(
+            range.shrink(4),
+            RangeFromExclusiveToExclusive { start: 4, end: 6 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_shrink_past_empty() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        for by in 5..=255 {
+            let shrunk = range.shrink(by);
+            assert_eq!(shrunk.start, shrunk.end);
+            assert_eq!(iter_bounds(shrunk).count(), 0);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_shrink_canonicalizes_empty() {
+        // `{4, 5}` would be empty, so it is collapsed.
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 0u8, end: 9 }.shrink(4),
+            RangeFromExclusiveToExclusive { start: 5, end: 5 }
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 254u8,
+                end: 255
+            }
+            .shrink(0),
+            RangeFromExclusiveToExclusive {
+                start: 255,
+                end: 255
+            }
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 9u8, end: 0 }.shrink(0),
+            RangeFromExclusiveToExclusive { start: 9, end: 9 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_shrink_agrees_with_values() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 0u8,
+            end: 10,
+        };
+
+        for by in 0..8u8 {
+            assert!(iter_bounds(range.shrink(by)).eq(iter_bounds(range)
+                .skip(by as usize)
+                .take(9usize.saturating_sub(2 * by as usize))));
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_widen_shrink_round_trip() {
+        for start in i8::min_value()..=i8::max_value() {
+            for &end in [-128, -100, -2, 0, 1, 2, 3, 50, 126, 127].iter() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                // Empty ranges are canonicalized by `shrink()`.
+                if iter_bounds(range).count() == 0 {
+                    continue;
+                }
+                for by in 0..=i8::max_value() {
+                    if let Some(widened) = range.widen(by) {
+                        assert_eq!(widened.shrink(by), range);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_shrink_widen_round_trip() {
+        for start in 0..=u8::max_value() {
+            for &end in [0, 1, 2, 10, 128, 254, 255].iter() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                for by in 0..=u8::max_value() {
+                    let shrunk = range.shrink(by);
+                    // Shrinking saturated if the result is collapsed.
+                    if shrunk.start == shrunk.end {
+                        continue;
+                    }
+                    assert_some_eq!(shrunk.widen(by), range);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_extend_to_include() {
+        let mut range = RangeFromExclusive { start: 0i8 };
+
+        assert!(range.extend_to_include(1));
+        assert_eq!(range, RangeFromExclusive { start: 0 });
+        assert!(range.extend_to_include(0));
+        assert_eq!(range, RangeFromExclusive { start: -1 });
+        assert!(range.extend_to_include(-127));
+        assert_eq!(range, RangeFromExclusive { start: -128 });
+    }
+
+    #[test]
+    fn range_from_exclusive_extend_to_include_minimum() {
+        let mut range = RangeFromExclusive { start: 0i8 };
+
+        assert!(!range.extend_to_include(i8::min_value()));
+        assert_eq!(range, RangeFromExclusive { start: 0 });
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_extend_to_include_contains() {
+        for start in 0..8u8 {
+            for end in 0..8u8 {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                for value in 1..8u8 {
+                    let extended = assert_some!(range.extended_to_include(value));
+                    let mut expected = iter_bounds(range).collect::<Vec<_>>();
+                    expected.push(value);
+                    let low = *expected.iter().min().unwrap();
+                    let high = *expected.iter().max().unwrap();
+
+                    assert!(iter_bounds(extended).eq(low..=high));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_extend_to_include_limits() {
+        let mut range = RangeFromExclusiveToInclusive { start: 0u8, end: 5 };
+
+        assert!(range.extend_to_include(u8::max_value()));
+        assert_eq!(range, RangeFromExclusiveToInclusive { start: 0, end: 255 });
+        assert!(!range.extend_to_include(0));
+        assert_eq!(range, RangeFromExclusiveToInclusive { start: 0, end: 255 });
+        assert_none!(RangeFromExclusiveToInclusive { start: 3u8, end: 3 }.extended_to_include(0));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_extend_to_include_contains() {
+        for start in 0..8u8 {
+            for end in 0..8u8 {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                for value in 1..8u8 {
+                    let extended = assert_some!(range.extended_to_include(value));
+                    let mut expected = iter_bounds(range).collect::<Vec<_>>();
+                    expected.push(value);
+                    let low = *expected.iter().min().unwrap();
+                    let high = *expected.iter().max().unwrap();
+
+                    assert!(iter_bounds(extended).eq(low..=high));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_extend_to_include_limits() {
+        let mut range = RangeFromExclusiveToExclusive { start: 0u8, end: 5 };
+
+        assert!(range.extend_to_include(254));
+        assert_eq!(range, RangeFromExclusiveToExclusive { start: 0, end: 255 });
+        assert!(!range.extend_to_include(255));
+        assert!(!range.extend_to_include(0));
+        assert_eq!(range, RangeFromExclusiveToExclusive { start: 0, end: 255 });
+        assert_none!(RangeFromExclusiveToExclusive { start: 3u8, end: 3 }.extended_to_include(0));
+        assert_none!(RangeFromExclusiveToExclusive { start: 3u8, end: 3 }
+            .extended_to_include(u8::max_value()));
+    }
+}
@@ -0,0 +1,508 @@
+//! Resolution of ranges into concrete offsets within a slice.
+//!
+//! The panic messages here mirror those of the standard library's slice indexing.
+
+use core::fmt;
+use core::ops::{Bound, Range, RangeBounds};
+#[cfg(feature = "std")]
+use std::error::Error;
+use {
+    AnyExclusiveRange, RangeFromExclusive, RangeFromExclusiveToExclusive,
+    RangeFromExclusiveToInclusive,
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An error resolving a range into offsets within a slice.
+///
+/// This is returned by [`try_resolve_bounds()`] and by the `try_to_mask()` method on the ranges
+/// over `usize`. Its `Display` output matches the message that [`resolve_bounds()`] panics with.
+///
+/// [`try_resolve_bounds()`]: try_resolve_bounds
+/// [`resolve_bounds()`]: resolve_bounds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResolveError {
+    /// The exclusive start bound is `usize::MAX`, so the first offset cannot be represented.
+    StartOverflow,
+    /// The inclusive end bound is `usize::MAX`, so the offset past the end cannot be represented.
+    EndOverflow,
+    /// The range is unbounded above, and its first offset is past the end of the slice.
+    StartOutOfBounds {
+        /// The first offset of the range.
+        index: usize,
+        /// The length of the slice.
+        len: usize,
+    },
+    /// The offset past the end of the range is past the end of the slice.
+    EndOutOfBounds {
+        /// The offset past the end of the range.
+        index: usize,
+        /// The length of the slice.
+        len: usize,
+    },
+    /// The first offset of the range is greater than the offset past its end.
+    Inverted {
+        /// The first offset of the range.
+        start: usize,
+        /// The offset past the end of the range.
+        end: usize,
+    },
+    /// A bound of the range is not on a `char` boundary of the string slice being indexed.
+    ///
+    /// This is only returned by the `resolve_str()` method on the ranges over `usize`.
+    NotCharBoundary {
+        /// The byte offset that is not on a `char` boundary.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ResolveError::StartOverflow => {
+                f.write_str("attempted to index slice from after maximum usize")
+            }
+            ResolveError::EndOverflow => {
+                f.write_str("attempted to index slice up to maximum usize")
+            }
+            ResolveError::StartOutOfBounds { index, len } => write!(
+                f,
+                "range start index {} out of range for slice of length {}",
+                index, len
+            ),
+            ResolveError::EndOutOfBounds { index, len } => write!(
+                f,
+                "range end index {} out of range for slice of length {}",
+                index, len
+            ),
+            ResolveError::Inverted { start, end } => {
+                write!(f, "slice index starts at {} but ends at {}", start, end)
+            }
+            ResolveError::NotCharBoundary { index } => {
+                write!(f, "byte index {} is not a char boundary", index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ResolveError {}
+
+#[cold]
+#[inline(never)]
+fn resolve_fail(error: ResolveError) -> ! {
+    panic!("{}", error);
+}
+
+/// Resolves `range` into the equivalent half-open range of offsets in a slice of length `len`,
+/// returning an error if it is invalid.
+///
+/// This accepts any [`RangeBounds<usize>`], including the standard library's ranges, the ranges in
+/// this crate, and `(Bound<usize>, Bound<usize>)` tuples, and resolves them the same way that they
+/// are resolved when indexing a slice. As with [`RangeFromExclusiveToExclusive`], a range with equal
+/// exclusive bounds is empty, and is resolved to the empty range at its end.
+///
+/// # Example
+/// ```
+/// use more_ranges::{try_resolve_bounds, RangeFromExclusive, ResolveError};
+/// use std::ops::Bound;
+///
+/// assert_eq!(try_resolve_bounds(RangeFromExclusive { start: 1 }, 5), Ok(2..5));
+/// assert_eq!(try_resolve_bounds(..=2, 5), Ok(0..3));
+/// assert_eq!(
+///     try_resolve_bounds((Bound::Excluded(1), Bound::Included(5)), 5),
+///     Err(ResolveError::EndOutOfBounds { index: 6, len: 5 })
+/// );
+/// ```
+///
+/// [`RangeBounds<usize>`]: core::ops::RangeBounds
+pub fn try_resolve_bounds<R>(range: R, len: usize) -> Result<Range<usize>, ResolveError>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => {
+            let first = start.checked_add(1).ok_or(ResolveError::StartOverflow)?;
+            // A range with equal exclusive bounds is empty, and is resolved to the empty range at
+            // its end.
+            if range.end_bound() == Bound::Excluded(&start) {
+                start
+            } else {
+                first
+            }
+        }
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).ok_or(ResolveError::EndOverflow)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => {
+            if start > len {
+                return Err(ResolveError::StartOutOfBounds { index: start, len });
+            }
+            len
+        }
+    };
+    if start > end {
+        return Err(ResolveError::Inverted { start, end });
+    }
+    if end > len {
+        return Err(ResolveError::EndOutOfBounds { index: end, len });
+    }
+    Ok(start..end)
+}
+
+/// Resolves `range` into the equivalent half-open range of offsets in a slice of length `len`.
+///
+/// This is the same as [`try_resolve_bounds()`], but panics instead of returning an error. It
+/// provides, on stable Rust, the same functionality as the unstable [`slice::range()`], extended
+/// to exclusive start bounds.
+///
+/// # Panics
+/// Panics if the range is inverted, if it extends beyond `len`, or if one of its bounds cannot be
+/// converted into an offset without overflowing.
+///
+/// # Example
+/// ```
+/// use more_ranges::{resolve_bounds, RangeFromExclusiveToExclusive};
+/// use std::ops::Bound;
+///
+/// assert_eq!(resolve_bounds(RangeFromExclusiveToExclusive { start: 1, end: 4 }, 5), 2..4);
+/// assert_eq!(resolve_bounds(1.., 5), 1..5);
+/// assert_eq!(resolve_bounds((Bound::Excluded(2), Bound::Unbounded), 5), 3..5);
+/// ```
+///
+/// [`try_resolve_bounds()`]: try_resolve_bounds
+/// [`slice::range()`]: https://doc.rust-lang.org/std/slice/fn.range.html
+#[inline]
+pub fn resolve_bounds<R>(range: R, len: usize) -> Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    match try_resolve_bounds(range, len) {
+        Ok(range) => range,
+        Err(error) => resolve_fail(error),
+    }
+}
+
+/// A range that can be resolved into offsets within a slice.
+///
+/// This is implemented for this crate's range types over `usize`, and cannot be implemented outside
+/// of this crate. It allows them to be used wherever the slice and collection extension methods
+/// accept a range of offsets.
+pub trait Resolve: sealed::Sealed {
+    /// Resolves the range into the equivalent half-open range of offsets in a slice of length
+    /// `len`.
+    ///
+    /// # Panics
+    /// Panics if the range is inverted or if it extends beyond `len`.
+    fn resolve(self, len: usize) -> Range<usize>;
+
+    /// Resolves the range in the same way as [`resolve()`], returning `None` instead of
+    /// panicking.
+    ///
+    /// [`resolve()`]: Resolve::resolve
+    fn try_resolve(self, len: usize) -> Option<Range<usize>>;
+}
+
+macro_rules! impl_resolve {
+    ($($range:ty),*) => {
+        $(
+            impl sealed::Sealed for $range {}
+
+            impl Resolve for $range {
+                #[inline]
+                fn resolve(self, len: usize) -> Range<usize> {
+                    resolve_bounds(self, len)
+                }
+
+                #[inline]
+                fn try_resolve(self, len: usize) -> Option<Range<usize>> {
+                    try_resolve_bounds(self, len).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_resolve!(
+    RangeFromExclusive<usize>,
+    RangeFromExclusiveToInclusive<usize>,
+    RangeFromExclusiveToExclusive<usize>,
+    AnyExclusiveRange<usize>
+);
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use resolve::Resolve;
+    use {
+        resolve_bounds, try_resolve_bounds, RangeFromExclusive, RangeFromExclusiveToExclusive,
+        RangeFromExclusiveToInclusive, ResolveError,
+    };
+
+    #[test]
+    fn range_from_exclusive() {
+        assert_eq!(RangeFromExclusive { start: 1 }.resolve(5), 2..5);
+    }
+
+    #[test]
+    fn range_from_exclusive_empty() {
+        assert_eq!(RangeFromExclusive { start: 4 }.resolve(5), 5..5);
+    }
+
+    #[test]
+    #[should_panic(expected = "range start index 6 out of range for slice of length 5")]
+    fn range_from_exclusive_start_out_of_bounds() {
+        RangeFromExclusive { start: 5 }.resolve(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice from after maximum usize")]
+    fn range_from_exclusive_start_overflow() {
+        RangeFromExclusive {
+            start: usize::max_value(),
+        }
+        .resolve(5);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 1, end: 3 }.resolve(5),
+            2..4
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_empty() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 4, end: 4 }.resolve(5),
+            5..5
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn range_from_exclusive_to_inclusive_end_out_of_bounds() {
+        RangeFromExclusiveToInclusive { start: 1, end: 5 }.resolve(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 4 but ends at 3")]
+    fn range_from_exclusive_to_inclusive_inverted() {
+        RangeFromExclusiveToInclusive { start: 3, end: 2 }.resolve(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice from after maximum usize")]
+    fn range_from_exclusive_to_inclusive_start_overflow() {
+        RangeFromExclusiveToInclusive {
+            start: usize::max_value(),
+            end: usize::max_value(),
+        }
+        .resolve(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice up to maximum usize")]
+    fn range_from_exclusive_to_inclusive_end_overflow() {
+        RangeFromExclusiveToInclusive {
+            start: 1,
+            end: usize::max_value(),
+        }
+        .resolve(5);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }.resolve(5),
+            2..4
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_adjacent() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 1, end: 2 }.resolve(5),
+            2..2
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_equal() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 5, end: 5 }.resolve(5),
+            5..5
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn range_from_exclusive_to_exclusive_end_out_of_bounds() {
+        RangeFromExclusiveToExclusive { start: 1, end: 6 }.resolve(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 4 but ends at 2")]
+    fn range_from_exclusive_to_exclusive_inverted() {
+        RangeFromExclusiveToExclusive { start: 3, end: 2 }.resolve(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice from after maximum usize")]
+    fn range_from_exclusive_to_exclusive_start_overflow() {
+        RangeFromExclusiveToExclusive {
+            start: usize::max_value(),
+            end: usize::max_value(),
+        }
+        .resolve(5);
+    }
+
+    #[test]
+    fn try_resolve_in_bounds() {
+        assert_some_eq!(RangeFromExclusive { start: 1 }.try_resolve(5), 2..5);
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive { start: 1, end: 3 }.try_resolve(5),
+            2..4
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive { start: 5, end: 5 }.try_resolve(5),
+            5..5
+        );
+    }
+
+    #[test]
+    fn try_resolve_out_of_bounds() {
+        assert_none!(RangeFromExclusive { start: 5 }.try_resolve(5));
+        assert_none!(RangeFromExclusiveToInclusive { start: 1, end: 5 }.try_resolve(5));
+        assert_none!(RangeFromExclusiveToExclusive { start: 1, end: 6 }.try_resolve(5));
+    }
+
+    #[test]
+    fn try_resolve_inverted() {
+        assert_none!(RangeFromExclusiveToInclusive { start: 3, end: 2 }.try_resolve(5));
+        assert_none!(RangeFromExclusiveToExclusive { start: 3, end: 2 }.try_resolve(5));
+    }
+
+    #[test]
+    fn try_resolve_overflow() {
+        assert_none!(RangeFromExclusive {
+            start: usize::max_value()
+        }
+        .try_resolve(5));
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: 1,
+            end: usize::max_value()
+        }
+        .try_resolve(5));
+    }
+
+    #[test]
+    fn resolve_bounds_std_ranges() {
+        assert_eq!(resolve_bounds(1..4, 5), 1..4);
+        assert_eq!(resolve_bounds(1..=4, 5), 1..5);
+        assert_eq!(resolve_bounds(2.., 5), 2..5);
+        assert_eq!(resolve_bounds(..3, 5), 0..3);
+        assert_eq!(resolve_bounds(..=3, 5), 0..4);
+        assert_eq!(resolve_bounds(.., 5), 0..5);
+    }
+
+    #[test]
+    fn resolve_bounds_exclusive_ranges() {
+        assert_eq!(resolve_bounds(RangeFromExclusive { start: 1 }, 5), 2..5);
+        assert_eq!(
+            resolve_bounds(RangeFromExclusiveToInclusive { start: 1, end: 3 }, 5),
+            2..4
+        );
+        assert_eq!(
+            resolve_bounds(RangeFromExclusiveToExclusive { start: 1, end: 4 }, 5),
+            2..4
+        );
+        assert_eq!(
+            resolve_bounds(RangeFromExclusiveToExclusive { start: 5, end: 5 }, 5),
+            5..5
+        );
+    }
+
+    #[test]
+    fn resolve_bounds_tuples() {
+        assert_eq!(resolve_bounds((Excluded(1), Excluded(4)), 5), 2..4);
+        assert_eq!(resolve_bounds((Excluded(3), Excluded(3)), 5), 3..3);
+        assert_eq!(resolve_bounds((Included(3), Excluded(3)), 5), 3..3);
+        assert_eq!(resolve_bounds((Excluded(0), Included(0)), 5), 1..1);
+        assert_eq!(resolve_bounds((Unbounded, Included(4)), 5), 0..5);
+        assert_eq!(resolve_bounds((Excluded(4), Unbounded), 5), 5..5);
+    }
+
+    #[test]
+    fn resolve_bounds_matches_resolve() {
+        for start in 0..8 {
+            for end in 0..8 {
+                assert_eq!(
+                    try_resolve_bounds((Excluded(start), Excluded(end)), 6).ok(),
+                    RangeFromExclusiveToExclusive { start, end }.try_resolve(6)
+                );
+                assert_eq!(
+                    try_resolve_bounds((Excluded(start), Included(end)), 6).ok(),
+                    RangeFromExclusiveToInclusive { start, end }.try_resolve(6)
+                );
+            }
+            assert_eq!(
+                try_resolve_bounds((Excluded(start), Unbounded), 6).ok(),
+                RangeFromExclusive { start }.try_resolve(6)
+            );
+        }
+    }
+
+    #[test]
+    fn try_resolve_bounds_errors() {
+        assert_eq!(
+            try_resolve_bounds((Excluded(usize::max_value()), Unbounded), 5),
+            Err(ResolveError::StartOverflow)
+        );
+        assert_eq!(
+            try_resolve_bounds(
+                (Excluded(usize::max_value()), Excluded(usize::max_value())),
+                5
+            ),
+            Err(ResolveError::StartOverflow)
+        );
+        assert_eq!(
+            try_resolve_bounds(..=usize::max_value(), 5),
+            Err(ResolveError::EndOverflow)
+        );
+        assert_eq!(
+            try_resolve_bounds(6.., 5),
+            Err(ResolveError::StartOutOfBounds { index: 6, len: 5 })
+        );
+        assert_eq!(
+            try_resolve_bounds(1..6, 5),
+            Err(ResolveError::EndOutOfBounds { index: 6, len: 5 })
+        );
+        assert_eq!(
+            try_resolve_bounds((Excluded(3), Excluded(2)), 5),
+            Err(ResolveError::Inverted { start: 4, end: 2 })
+        );
+        assert_eq!(
+            try_resolve_bounds((Included(4), Excluded(3)), 5),
+            Err(ResolveError::Inverted { start: 4, end: 3 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 4 but ends at 3")]
+    fn resolve_bounds_inverted() {
+        resolve_bounds((Excluded(3), Included(2)), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice up to maximum usize")]
+    fn resolve_bounds_end_overflow() {
+        resolve_bounds((Unbounded, Included(usize::max_value())), 5);
+    }
+}
@@ -0,0 +1,196 @@
+//! Retaining elements of a `Vec` within a range.
+
+use alloc::vec::Vec;
+use resolve::Resolve;
+
+/// Extension trait for retaining elements of a [`Vec`] only within a range.
+///
+/// [`Vec`]: alloc::vec::Vec
+pub trait RetainRangeExt<T> {
+    /// Retains only the elements within `range` that satisfy the predicate.
+    ///
+    /// Elements outside of `range` are left untouched. The predicate is called on each element
+    /// within `range` exactly once, in order, and the retained elements keep their original order.
+    ///
+    /// This is performed in a single pass, shifting the elements after `range` only once.
+    ///
+    /// `range` may be any of [`RangeFromExclusive<usize>`], [`RangeFromExclusiveToExclusive<usize>`],
+    /// or [`RangeFromExclusiveToInclusive<usize>`].
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds of the `Vec`, with the same messages as slice indexing.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToInclusive, RetainRangeExt};
+    ///
+    /// let mut v = vec![1, 2, 3, 4, 5, 6];
+    /// v.retain_range(RangeFromExclusiveToInclusive { start: 0, end: 4 }, |x| x % 2 == 0);
+    ///
+    /// assert_eq!(v, [1, 2, 4, 6]);
+    /// ```
+    ///
+    /// [`RangeFromExclusive<usize>`]: ::RangeFromExclusive
+    /// [`RangeFromExclusiveToExclusive<usize>`]: ::RangeFromExclusiveToExclusive
+    /// [`RangeFromExclusiveToInclusive<usize>`]: ::RangeFromExclusiveToInclusive
+    fn retain_range<R, F>(&mut self, range: R, pred: F)
+    where
+        R: Resolve,
+        F: FnMut(&T) -> bool;
+}
+
+impl<T> RetainRangeExt<T> for Vec<T> {
+    fn retain_range<R, F>(&mut self, range: R, mut pred: F)
+    where
+        R: Resolve,
+        F: FnMut(&T) -> bool,
+    {
+        let range = range.resolve(self.len());
+        let mut retained = range.start;
+        for index in range.clone() {
+            // Every element at or after `index` has not yet been visited, so the predicate is
+            // always called on the original element.
+            if pred(&self[index]) {
+                self.swap(retained, index);
+                retained += 1;
+            }
+        }
+        self.drain(retained..range.end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+    use {
+        RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+        RetainRangeExt,
+    };
+
+    /// Retains elements in the half-open range `start..end` by rebuilding the `Vec`.
+    fn naive_retain_range<T, F>(v: Vec<T>, start: usize, end: usize, mut pred: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        v.into_iter()
+            .enumerate()
+            .filter(|&(index, ref value)| index < start || index >= end || pred(value))
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    #[test]
+    fn range_from_exclusive() {
+        let mut v = (0..10).collect::<Vec<_>>();
+        v.retain_range(RangeFromExclusive { start: 3 }, |x| x % 3 == 0);
+
+        assert_eq!(
+            v,
+            naive_retain_range((0..10).collect(), 4, 10, |x| x % 3 == 0)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive() {
+        let mut v = (0..10).collect::<Vec<_>>();
+        v.retain_range(RangeFromExclusiveToExclusive { start: 1, end: 8 }, |x| {
+            x % 2 == 0
+        });
+
+        assert_eq!(
+            v,
+            naive_retain_range((0..10).collect(), 2, 8, |x| x % 2 == 0)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive() {
+        let mut v = (0..10).collect::<Vec<_>>();
+        v.retain_range(RangeFromExclusiveToInclusive { start: 1, end: 8 }, |x| {
+            x % 2 == 1
+        });
+
+        assert_eq!(
+            v,
+            naive_retain_range((0..10).collect(), 2, 9, |x| x % 2 == 1)
+        );
+    }
+
+    #[test]
+    fn matches_naive_model() {
+        for len in 0..8 {
+            for start in 0..len {
+                for end in start..=len {
+                    for modulus in 1..4 {
+                        let pred = |x: &usize| x % modulus == 0;
+                        let mut v = (0..len).collect::<Vec<_>>();
+                        v.retain_range(RangeFromExclusiveToExclusive { start, end }, pred);
+
+                        let expected_start = if start == end { end } else { start + 1 };
+                        assert_eq!(
+                            v,
+                            naive_retain_range((0..len).collect(), expected_start, end, pred)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn drop_everything_in_range() {
+        let mut v = (0..10).collect::<Vec<_>>();
+        v.retain_range(RangeFromExclusiveToInclusive { start: 2, end: 6 }, |_| {
+            false
+        });
+
+        assert_eq!(v, [0, 1, 2, 7, 8, 9]);
+    }
+
+    #[test]
+    fn drop_nothing_in_range() {
+        let mut v = (0..10).collect::<Vec<_>>();
+        v.retain_range(RangeFromExclusiveToInclusive { start: 2, end: 6 }, |_| true);
+
+        assert_eq!(v, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn predicate_called_in_order() {
+        let mut v = (0..10).collect::<Vec<_>>();
+        let mut visited = Vec::new();
+        v.retain_range(RangeFromExclusiveToExclusive { start: 2, end: 8 }, |&x| {
+            visited.push(x);
+            x % 2 == 0
+        });
+
+        assert_eq!(visited, [3, 4, 5, 6, 7]);
+        assert_eq!(v, [0, 1, 2, 4, 6, 8, 9]);
+    }
+
+    #[test]
+    fn empty_range() {
+        let mut v = (0..10).collect::<Vec<_>>();
+        v.retain_range(RangeFromExclusiveToExclusive { start: 4, end: 4 }, |_| {
+            false
+        });
+
+        assert_eq!(v, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 11 out of range for slice of length 10")]
+    fn out_of_bounds() {
+        let mut v = (0..10).collect::<Vec<_>>();
+        v.retain_range(RangeFromExclusiveToInclusive { start: 4, end: 10 }, |_| {
+            false
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "range start index 11 out of range for slice of length 10")]
+    fn start_out_of_bounds() {
+        let mut v = (0..10).collect::<Vec<_>>();
+        v.retain_range(RangeFromExclusive { start: 10 }, |_| false);
+    }
+}
@@ -0,0 +1,368 @@
+//! Token-based checks of serde implementations, for use in tests.
+//!
+//! This covers only the subset of the serde data model that the types in this crate use: structs
+//! of integers, and newtype variants. It is written here rather than depending on `serde_test`,
+//! which does not build on this crate's minimum supported Rust version.
+
+use core::fmt::{self, Display};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess,
+    VariantAccess, Visitor,
+};
+use serde::ser::{self, Impossible, Serialize, SerializeStruct};
+use std::string::{String, ToString};
+
+/// A single step of serialization or deserialization.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Token {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    Str(&'static str),
+    Struct {
+        name: &'static str,
+        len: usize,
+    },
+    StructEnd,
+    NewtypeVariant {
+        name: &'static str,
+        variant: &'static str,
+    },
+}
+
+/// Asserts that `value` serializes to `tokens`, and that `tokens` deserialize to `value`.
+pub fn assert_tokens<T>(value: &T, tokens: &[Token])
+where
+    T: Serialize + DeserializeOwned + PartialEq + fmt::Debug,
+{
+    let mut serializer = Serializer { tokens };
+    assert_ok!(value.serialize(&mut serializer));
+    assert_eq!(serializer.tokens, [], "not all tokens were serialized");
+
+    let mut deserializer = Deserializer { tokens };
+    assert_eq!(assert_ok!(T::deserialize(&mut deserializer)), *value);
+    assert_eq!(deserializer.tokens, [], "not all tokens were deserialized");
+}
+
+/// Asserts that deserializing `tokens` fails with the message `error`.
+pub fn assert_de_tokens_error<T>(tokens: &[Token], error: &str)
+where
+    T: DeserializeOwned + fmt::Debug,
+{
+    assert_eq!(
+        assert_err!(T::deserialize(&mut Deserializer { tokens })).0,
+        error
+    );
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(&self.0)
+    }
+}
+
+impl ::std::error::Error for Error {
+    fn description(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ser::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        Error(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        Error(msg.to_string())
+    }
+}
+
+struct Serializer<'a> {
+    tokens: &'a [Token],
+}
+
+impl<'a> Serializer<'a> {
+    fn assert_next(&mut self, token: Token) {
+        match self.tokens.split_first() {
+            Some((&expected, rest)) => {
+                assert_eq!(token, expected);
+                self.tokens = rest;
+            }
+            None => panic!("expected end of tokens, but {:?} was serialized", token),
+        }
+    }
+}
+
+macro_rules! serialize_tokens {
+    ($($method:ident($t:ty) => $token:ident,)*) => {
+        $(
+            fn $method(self, value: $t) -> Result<(), Error> {
+                self.assert_next(Token::$token(value));
+                Ok(())
+            }
+        )*
+    };
+}
+
+macro_rules! serialize_unsupported {
+    ($($method:ident($($arg:ty),*) -> $ok:ty,)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ok, Error> {
+                panic!(concat!("`", stringify!($method), "` is not supported"));
+            }
+        )*
+    };
+}
+
+impl<'a, 'b> ser::Serializer for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    serialize_tokens! {
+        serialize_u8(u8) => U8,
+        serialize_u16(u16) => U16,
+        serialize_u32(u32) => U32,
+        serialize_u64(u64) => U64,
+        serialize_i8(i8) => I8,
+        serialize_i16(i16) => I16,
+        serialize_i32(i32) => I32,
+        serialize_i64(i64) => I64,
+    }
+
+    serialize_unsupported! {
+        serialize_bool(bool) -> (),
+        serialize_f32(f32) -> (),
+        serialize_f64(f64) -> (),
+        serialize_char(char) -> (),
+        serialize_str(&str) -> (),
+        serialize_bytes(&[u8]) -> (),
+        serialize_none() -> (),
+        serialize_unit() -> (),
+        serialize_unit_struct(&'static str) -> (),
+        serialize_unit_variant(&'static str, u32, &'static str) -> (),
+        serialize_seq(Option<usize>) -> Self::SerializeSeq,
+        serialize_tuple(usize) -> Self::SerializeTuple,
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct,
+        serialize_tuple_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeTupleVariant,
+        serialize_map(Option<usize>) -> Self::SerializeMap,
+        serialize_struct_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeStructVariant,
+    }
+
+    fn serialize_some<T>(self, _: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        panic!("`serialize_some` is not supported");
+    }
+
+    fn collect_str<T>(self, _: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Display,
+    {
+        panic!("`collect_str` is not supported");
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, _: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        panic!("`serialize_newtype_struct` is not supported");
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.assert_next(Token::NewtypeVariant { name, variant });
+        value.serialize(self)
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self, Error> {
+        self.assert_next(Token::Struct { name, len });
+        Ok(self)
+    }
+}
+
+impl<'a, 'b> SerializeStruct for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.assert_next(Token::Str(key));
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.assert_next(Token::StructEnd);
+        Ok(())
+    }
+}
+
+struct Deserializer<'a> {
+    tokens: &'a [Token],
+}
+
+impl<'a> Deserializer<'a> {
+    fn next_token(&mut self) -> Token {
+        match self.tokens.split_first() {
+            Some((&token, rest)) => {
+                self.tokens = rest;
+                token
+            }
+            None => panic!("unexpected end of tokens"),
+        }
+    }
+}
+
+impl<'de, 'a, 'b> de::Deserializer<'de> for &'b mut Deserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.next_token() {
+            Token::U8(value) => visitor.visit_u8(value),
+            Token::U16(value) => visitor.visit_u16(value),
+            Token::U32(value) => visitor.visit_u32(value),
+            Token::U64(value) => visitor.visit_u64(value),
+            Token::I8(value) => visitor.visit_i8(value),
+            Token::I16(value) => visitor.visit_i16(value),
+            Token::I32(value) => visitor.visit_i32(value),
+            Token::I64(value) => visitor.visit_i64(value),
+            Token::Str(value) => visitor.visit_str(value),
+            Token::Struct { .. } => visitor.visit_map(StructAccess { de: self }),
+            token => panic!("unexpected token {:?}", token),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.next_token() {
+            Token::NewtypeVariant { variant, .. } => {
+                visitor.visit_enum(NewtypeVariantAccess { de: self, variant })
+            }
+            token => panic!("unexpected token {:?}", token),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct StructAccess<'a, 'b: 'a> {
+    de: &'a mut Deserializer<'b>,
+}
+
+impl<'de, 'a, 'b> MapAccess<'de> for StructAccess<'a, 'b> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if self.de.tokens.first() == Some(&Token::StructEnd) {
+            self.de.next_token();
+            Ok(None)
+        } else {
+            seed.deserialize(&mut *self.de).map(Some)
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+struct NewtypeVariantAccess<'a, 'b: 'a> {
+    de: &'a mut Deserializer<'b>,
+    variant: &'static str,
+}
+
+impl<'de, 'a, 'b> EnumAccess<'de> for NewtypeVariantAccess<'a, 'b> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self), Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant: de::value::StrDeserializer<Error> = self.variant.into_deserializer();
+        Ok((seed.deserialize(variant)?, self))
+    }
+}
+
+impl<'de, 'a, 'b> VariantAccess<'de> for NewtypeVariantAccess<'a, 'b> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        panic!("unit variants are not supported");
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn tuple_variant<V>(self, _: usize, _: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        panic!("tuple variants are not supported");
+    }
+
+    fn struct_variant<V>(self, _: &'static [&'static str], _: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        panic!("struct variants are not supported");
+    }
+}
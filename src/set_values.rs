@@ -0,0 +1,443 @@
+//! Lazily iterating over the values of set operations on ranges.
+//!
+//! The union, intersection, or difference of two ranges of integers is made up of at most two
+//! disjoint runs of consecutive values. Each iterator here yields the values of those runs in
+//! ascending order without materializing any intermediate ranges.
+
+use core::iter;
+use core::ops::{Bound, RangeBounds};
+use iter_bounds::Integer;
+use {iter_bounds, BoundsIter, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// Returns the first and last values contained in `range`, or `None` if it is empty.
+#[inline]
+fn interval<T, R>(range: R) -> Option<(T, T)>
+where
+    T: Integer,
+    R: RangeBounds<T>,
+{
+    let mut values = iter_bounds(range);
+    let first = values.next()?;
+    Some((first, values.next_back().unwrap_or(first)))
+}
+
+/// Returns an iterator over the values from `first` to `last`, inclusive.
+#[inline]
+fn run<T>(interval: Option<(T, T)>) -> BoundsIter<T>
+where
+    T: Integer,
+{
+    match interval {
+        Some((first, last)) => iter_bounds((Bound::Included(first), Bound::Included(last))),
+        // `MIN < MAX` for every integer type, so this contains no values.
+        None => iter_bounds((Bound::Included(T::MAX), Bound::Included(T::MIN))),
+    }
+}
+
+macro_rules! set_values {
+    ($($(#[$attr:meta])* $name:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Debug)]
+            pub struct $name<T> {
+                /// The lower run of values.
+                front: BoundsIter<T>,
+                /// The upper run of values, which lies entirely above `front`.
+                back: BoundsIter<T>,
+            }
+
+            impl<T> Iterator for $name<T>
+            where
+                T: Integer,
+            {
+                type Item = T;
+
+                #[inline]
+                fn next(&mut self) -> Option<T> {
+                    self.front.next().or_else(|| self.back.next())
+                }
+
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    let (front_lower, front_upper) = self.front.size_hint();
+                    let (back_lower, back_upper) = self.back.size_hint();
+                    (
+                        front_lower.saturating_add(back_lower),
+                        match (front_upper, back_upper) {
+                            (Some(front_upper), Some(back_upper)) => {
+                                front_upper.checked_add(back_upper)
+                            }
+                            _ => None,
+                        },
+                    )
+                }
+            }
+
+            impl<T> DoubleEndedIterator for $name<T>
+            where
+                T: Integer,
+            {
+                #[inline]
+                fn next_back(&mut self) -> Option<T> {
+                    self.back.next_back().or_else(|| self.front.next_back())
+                }
+            }
+
+            impl<T> iter::FusedIterator for $name<T> where T: Integer {}
+        )*
+    };
+}
+
+set_values! {
+    /// An iterator over the values contained in either of two ranges, in ascending order.
+    ///
+    /// This is created by the `union_values()` method on the bounded ranges.
+    UnionValues;
+    /// An iterator over the values contained in both of two ranges, in ascending order.
+    ///
+    /// This is created by the `intersection_values()` method on the bounded ranges.
+    IntersectionValues;
+    /// An iterator over the values contained in one range but not another, in ascending order.
+    ///
+    /// This is created by the `difference_values()` method on the bounded ranges.
+    DifferenceValues;
+}
+
+#[inline]
+fn union_values<T>(a: Option<(T, T)>, b: Option<(T, T)>) -> UnionValues<T>
+where
+    T: Integer,
+{
+    let (a, b) = match (a, b) {
+        (Some(a), Some(b)) => {
+            if a.0 <= b.0 {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        }
+        (a, None) => {
+            return UnionValues {
+                front: run(a),
+                back: run(None),
+            }
+        }
+        (None, b) => {
+            return UnionValues {
+                front: run(b),
+                back: run(None),
+            }
+        }
+    };
+    // The runs merge if `b` begins no later than immediately after the end of `a`.
+    if a.1.checked_succ().map_or(true, |after| b.0 <= after) {
+        UnionValues {
+            front: run(Some((a.0, if a.1 < b.1 { b.1 } else { a.1 }))),
+            back: run(None),
+        }
+    } else {
+        UnionValues {
+            front: run(Some(a)),
+            back: run(Some(b)),
+        }
+    }
+}
+
+#[inline]
+fn intersection_values<T>(a: Option<(T, T)>, b: Option<(T, T)>) -> IntersectionValues<T>
+where
+    T: Integer,
+{
+    let interval = match (a, b) {
+        (Some(a), Some(b)) => {
+            let first = if a.0 < b.0 { b.0 } else { a.0 };
+            let last = if a.1 < b.1 { a.1 } else { b.1 };
+            if first <= last {
+                Some((first, last))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+    IntersectionValues {
+        front: run(interval),
+        back: run(None),
+    }
+}
+
+#[inline]
+fn difference_values<T>(a: Option<(T, T)>, b: Option<(T, T)>) -> DifferenceValues<T>
+where
+    T: Integer,
+{
+    let (a, b) = match (a, b) {
+        (Some(a), Some(b)) => (a, b),
+        (a, None) => {
+            return DifferenceValues {
+                front: run(a),
+                back: run(None),
+            }
+        }
+        (None, Some(_)) => {
+            return DifferenceValues {
+                front: run(None),
+                back: run(None),
+            }
+        }
+    };
+    // The values of `a` below `b`.
+    let front = b.0.checked_pred().and_then(|before| {
+        if a.0 <= before {
+            Some((a.0, if before < a.1 { before } else { a.1 }))
+        } else {
+            None
+        }
+    });
+    // The values of `a` above `b`.
+    let back = b.1.checked_succ().and_then(|after| {
+        if after <= a.1 {
+            Some((if a.0 < after { after } else { a.0 }, a.1))
+        } else {
+            None
+        }
+    });
+    DifferenceValues {
+        front: run(front),
+        back: run(back),
+    }
+}
+
+macro_rules! impl_set_values {
+    ($($range:ident, $end_bound:ident;)*) => {
+        $(
+            impl<T> $range<T>
+            where
+                T: Integer,
+            {
+                /// Returns an iterator over the values contained in either `self` or `other`, in
+                /// ascending order.
+                ///
+                /// # Example
+                /// ```
+                /// use more_ranges::{RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+                ///
+                /// let range = RangeFromExclusiveToInclusive { start: 1, end: 3 };
+                ///
+                /// assert!(range.union_values(6..8).eq(vec![2, 3, 6, 7]));
+                /// assert!(range
+                ///     .union_values(RangeFromExclusiveToExclusive { start: 2, end: 6 })
+                ///     .eq(2..6));
+                /// ```
+                #[inline]
+                pub fn union_values<R>(&self, other: R) -> UnionValues<T>
+                where
+                    R: RangeBounds<T>,
+                {
+                    union_values(
+                        interval((Bound::Excluded(self.start), Bound::$end_bound(self.end))),
+                        interval(other),
+                    )
+                }
+
+                /// Returns an iterator over the values contained in both `self` and `other`, in
+                /// ascending order.
+                ///
+                /// # Example
+                /// ```
+                /// use more_ranges::{RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+                ///
+                /// let range = RangeFromExclusiveToInclusive { start: 1, end: 5 };
+                ///
+                /// assert!(range
+                ///     .intersection_values(RangeFromExclusiveToExclusive { start: 3, end: 9 })
+                ///     .eq(4..=5));
+                /// ```
+                #[inline]
+                pub fn intersection_values<R>(&self, other: R) -> IntersectionValues<T>
+                where
+                    R: RangeBounds<T>,
+                {
+                    intersection_values(
+                        interval((Bound::Excluded(self.start), Bound::$end_bound(self.end))),
+                        interval(other),
+                    )
+                }
+
+                /// Returns an iterator over the values contained in `self` but not in `other`, in
+                /// ascending order.
+                ///
+                /// # Example
+                /// ```
+                /// use more_ranges::RangeFromExclusiveToInclusive;
+                ///
+                /// let range = RangeFromExclusiveToInclusive { start: 1, end: 6 };
+                ///
+                /// assert!(range.difference_values(3..5).eq(vec![2, 5, 6]));
+                /// ```
+                #[inline]
+                pub fn difference_values<R>(&self, other: R) -> DifferenceValues<T>
+                where
+                    R: RangeBounds<T>,
+                {
+                    difference_values(
+                        interval((Bound::Excluded(self.start), Bound::$end_bound(self.end))),
+                        interval(other),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_set_values! {
+    RangeFromExclusiveToExclusive, Excluded;
+    RangeFromExclusiveToInclusive, Included;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::RangeBounds;
+    use std::collections::BTreeSet;
+    use std::vec::Vec;
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    /// Bounds at and around the edges of `u8`, along with some overlapping interior values.
+    const BOUNDS: [u8; 10] = [0, 1, 2, 4, 5, 6, 9, 253, 254, 255];
+
+    fn model<R>(range: &R) -> BTreeSet<u8>
+    where
+        R: RangeBounds<u8>,
+    {
+        (0..=u8::max_value())
+            .filter(|value| range.contains(value))
+            .collect()
+    }
+
+    fn to_exclusive_ranges() -> Vec<RangeFromExclusiveToExclusive<u8>> {
+        BOUNDS
+            .iter()
+            .flat_map(|&start| {
+                BOUNDS
+                    .iter()
+                    .map(move |&end| RangeFromExclusiveToExclusive { start, end })
+            })
+            .collect()
+    }
+
+    fn to_inclusive_ranges() -> Vec<RangeFromExclusiveToInclusive<u8>> {
+        BOUNDS
+            .iter()
+            .flat_map(|&start| {
+                BOUNDS
+                    .iter()
+                    .map(move |&end| RangeFromExclusiveToInclusive { start, end })
+            })
+            .collect()
+    }
+
+    /// Asserts that `iter` yields exactly `expected` from both ends, with an exact `size_hint`.
+    fn assert_yields<I>(iter: I, expected: &BTreeSet<u8>)
+    where
+        I: DoubleEndedIterator<Item = u8> + Clone,
+    {
+        assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        assert_eq!(
+            iter.clone().collect::<Vec<_>>(),
+            expected.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>(),
+            expected.iter().rev().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn union_matches_model() {
+        for a in to_exclusive_ranges() {
+            for b in to_inclusive_ranges() {
+                let expected = model(&a).union(&model(&b)).cloned().collect();
+                assert_yields(a.union_values(b), &expected);
+                assert_yields(b.union_values(a), &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn intersection_matches_model() {
+        for a in to_exclusive_ranges() {
+            for b in to_inclusive_ranges() {
+                let expected = model(&a).intersection(&model(&b)).cloned().collect();
+                assert_yields(a.intersection_values(b), &expected);
+                assert_yields(b.intersection_values(a), &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn difference_matches_model() {
+        for a in to_exclusive_ranges() {
+            for b in to_inclusive_ranges() {
+                assert_yields(
+                    a.difference_values(b),
+                    &model(&a).difference(&model(&b)).cloned().collect(),
+                );
+                assert_yields(
+                    b.difference_values(a),
+                    &model(&b).difference(&model(&a)).cloned().collect(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn same_type_operands() {
+        let a = RangeFromExclusiveToExclusive { start: 1, end: 5 };
+        let b = RangeFromExclusiveToExclusive { start: 3, end: 9 };
+
+        assert!(a.union_values(b).eq(2..9));
+        assert!(a.intersection_values(b).eq(4..5));
+        assert!(a.difference_values(b).eq(2..4));
+    }
+
+    #[test]
+    fn huge_spans() {
+        let a = RangeFromExclusiveToInclusive {
+            start: 0u64,
+            end: u64::max_value(),
+        };
+        let b = RangeFromExclusiveToExclusive {
+            start: 1u64,
+            end: u64::max_value(),
+        };
+
+        let mut difference = a.difference_values(b);
+        assert_some_eq!(difference.next(), 1);
+        assert_some_eq!(difference.next(), u64::max_value());
+        assert_none!(difference.next());
+
+        let mut union = b.union_values(a);
+        assert_some_eq!(union.next(), 1);
+        assert_some_eq!(union.next_back(), u64::max_value());
+    }
+
+    #[test]
+    fn size_hint_overflow() {
+        let a = RangeFromExclusiveToInclusive {
+            start: 0u128,
+            end: u128::max_value(),
+        };
+
+        assert_eq!(a.union_values(..).size_hint(), (usize::max_value(), None));
+    }
+
+    #[test]
+    fn fused() {
+        let mut iter = RangeFromExclusiveToInclusive { start: 1, end: 2 }.union_values(5..6);
+
+        assert_some_eq!(iter.next(), 2);
+        assert_some_eq!(iter.next(), 5);
+        assert_none!(iter.next());
+        assert_none!(iter.next());
+    }
+}
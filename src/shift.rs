@@ -0,0 +1,575 @@
+//! Shifting ranges over primitive integers with explicit overflow behavior.
+//!
+//! Unlike the `Add` and `Sub` operators, whose overflow behavior depends on whether overflow checks
+//! are enabled, these methods behave the same in every build profile.
+
+use iter_bounds::Integer;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl<Idx> RangeFromExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Shifts the bound up by `by`, returning `None` if it overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 1u8 }.checked_shift_up(10),
+    ///     Some(RangeFromExclusive { start: 11 })
+    /// );
+    /// assert_eq!(RangeFromExclusive { start: 250u8 }.checked_shift_up(10), None);
+    /// ```
+    #[inline]
+    pub fn checked_shift_up(self, by: Idx) -> Option<Self> {
+        Some(RangeFromExclusive {
+            start: self.start.checked_add(by)?,
+        })
+    }
+
+    /// Shifts the bound down by `by`, returning `None` if it overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 11u8 }.checked_shift_down(10),
+    ///     Some(RangeFromExclusive { start: 1 })
+    /// );
+    /// assert_eq!(RangeFromExclusive { start: 1u8 }.checked_shift_down(10), None);
+    /// ```
+    #[inline]
+    pub fn checked_shift_down(self, by: Idx) -> Option<Self> {
+        Some(RangeFromExclusive {
+            start: self.start.checked_sub(by)?,
+        })
+    }
+
+    /// Shifts the bound up by `by`, saturating at the numeric bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 250u8 }.saturating_shift_up(10),
+    ///     RangeFromExclusive { start: 255 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn saturating_shift_up(self, by: Idx) -> Self {
+        RangeFromExclusive {
+            start: self.start.saturating_add(by),
+        }
+    }
+
+    /// Shifts the bound down by `by`, saturating at the numeric bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 5u8 }.saturating_shift_down(10),
+    ///     RangeFromExclusive { start: 0 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn saturating_shift_down(self, by: Idx) -> Self {
+        RangeFromExclusive {
+            start: self.start.saturating_sub(by),
+        }
+    }
+
+    /// Shifts the bound up by `by`, wrapping around at the numeric bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 250u8 }.wrapping_shift_up(10),
+    ///     RangeFromExclusive { start: 4 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn wrapping_shift_up(self, by: Idx) -> Self {
+        RangeFromExclusive {
+            start: self.start.wrapping_add(by),
+        }
+    }
+
+    /// Shifts the bound down by `by`, wrapping around at the numeric bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 5u8 }.wrapping_shift_down(10),
+    ///     RangeFromExclusive { start: 251 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn wrapping_shift_down(self, by: Idx) -> Self {
+        RangeFromExclusive {
+            start: self.start.wrapping_sub(by),
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Shifts both bounds up by `by`, returning `None` if either overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1u8, end: 4 }.checked_shift_up(10),
+    ///     Some(RangeFromExclusiveToInclusive { start: 11, end: 14 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1u8, end: 250 }.checked_shift_up(10),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_shift_up(self, by: Idx) -> Option<Self> {
+        Some(RangeFromExclusiveToInclusive {
+            start: self.start.checked_add(by)?,
+            end: self.end.checked_add(by)?,
+        })
+    }
+
+    /// Shifts both bounds down by `by`, returning `None` if either overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 11u8, end: 14 }.checked_shift_down(10),
+    ///     Some(RangeFromExclusiveToInclusive { start: 1, end: 4 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1u8, end: 14 }.checked_shift_down(10),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_shift_down(self, by: Idx) -> Option<Self> {
+        Some(RangeFromExclusiveToInclusive {
+            start: self.start.checked_sub(by)?,
+            end: self.end.checked_sub(by)?,
+        })
+    }
+
+    /// Shifts both bounds up by `by`, saturating at the numeric bounds.
+    ///
+    /// Since each bound saturates independently, a range shifted against a numeric bound can collapse
+    /// to empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 248u8, end: 252 }.saturating_shift_up(5),
+    ///     RangeFromExclusiveToInclusive { start: 253, end: 255 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn saturating_shift_up(self, by: Idx) -> Self {
+        RangeFromExclusiveToInclusive {
+            start: self.start.saturating_add(by),
+            end: self.end.saturating_add(by),
+        }
+    }
+
+    /// Shifts both bounds down by `by`, saturating at the numeric bounds.
+    ///
+    /// Since each bound saturates independently, a range shifted against a numeric bound can collapse
+    /// to empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 3u8, end: 7 }.saturating_shift_down(5),
+    ///     RangeFromExclusiveToInclusive { start: 0, end: 2 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn saturating_shift_down(self, by: Idx) -> Self {
+        RangeFromExclusiveToInclusive {
+            start: self.start.saturating_sub(by),
+            end: self.end.saturating_sub(by),
+        }
+    }
+
+    /// Shifts both bounds up by `by`, wrapping around at the numeric bounds.
+    ///
+    /// The distance between the bounds is preserved modulo the size of the type, although a range that
+    /// wraps across a numeric bound is left inverted.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 248u8, end: 252 }.wrapping_shift_up(5),
+    ///     RangeFromExclusiveToInclusive { start: 253, end: 1 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn wrapping_shift_up(self, by: Idx) -> Self {
+        RangeFromExclusiveToInclusive {
+            start: self.start.wrapping_add(by),
+            end: self.end.wrapping_add(by),
+        }
+    }
+
+    /// Shifts both bounds down by `by`, wrapping around at the numeric bounds.
+    ///
+    /// The distance between the bounds is preserved modulo the size of the type, although a range that
+    /// wraps across a numeric bound is left inverted.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 3u8, end: 7 }.wrapping_shift_down(5),
+    ///     RangeFromExclusiveToInclusive { start: 254, end: 2 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn wrapping_shift_down(self, by: Idx) -> Self {
+        RangeFromExclusiveToInclusive {
+            start: self.start.wrapping_sub(by),
+            end: self.end.wrapping_sub(by),
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Shifts both bounds up by `by`, returning `None` if either overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1u8, end: 4 }.checked_shift_up(10),
+    ///     Some(RangeFromExclusiveToExclusive { start: 11, end: 14 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1u8, end: 250 }.checked_shift_up(10),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_shift_up(self, by: Idx) -> Option<Self> {
+        Some(RangeFromExclusiveToExclusive {
+            start: self.start.checked_add(by)?,
+            end: self.end.checked_add(by)?,
+        })
+    }
+
+    /// Shifts both bounds down by `by`, returning `None` if either overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 11u8, end: 14 }.checked_shift_down(10),
+    ///     Some(RangeFromExclusiveToExclusive { start: 1, end: 4 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1u8, end: 14 }.checked_shift_down(10),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_shift_down(self, by: Idx) -> Option<Self> {
+        Some(RangeFromExclusiveToExclusive {
+            start: self.start.checked_sub(by)?,
+            end: self.end.checked_sub(by)?,
+        })
+    }
+
+    /// Shifts both bounds up by `by`, saturating at the numeric bounds.
+    ///
+    /// Since each bound saturates independently, a range shifted against a numeric bound can collapse
+    /// to empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 248u8, end: 252 }.saturating_shift_up(5),
+    ///     RangeFromExclusiveToExclusive { start: 253, end: 255 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn saturating_shift_up(self, by: Idx) -> Self {
+        RangeFromExclusiveToExclusive {
+            start: self.start.saturating_add(by),
+            end: self.end.saturating_add(by),
+        }
+    }
+
+    /// Shifts both bounds down by `by`, saturating at the numeric bounds.
+    ///
+    /// Since each bound saturates independently, a range shifted against a numeric bound can collapse
+    /// to empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 3u8, end: 7 }.saturating_shift_down(5),
+    ///     RangeFromExclusiveToExclusive { start: 0, end: 2 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn saturating_shift_down(self, by: Idx) -> Self {
+        RangeFromExclusiveToExclusive {
+            start: self.start.saturating_sub(by),
+            end: self.end.saturating_sub(by),
+        }
+    }
+
+    /// Shifts both bounds up by `by`, wrapping around at the numeric bounds.
+    ///
+    /// The distance between the bounds is preserved modulo the size of the type, although a range that
+    /// wraps across a numeric bound is left inverted.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 248u8, end: 252 }.wrapping_shift_up(5),
+    ///     RangeFromExclusiveToExclusive { start: 253, end: 1 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn wrapping_shift_up(self, by: Idx) -> Self {
+        RangeFromExclusiveToExclusive {
+            start: self.start.wrapping_add(by),
+            end: self.end.wrapping_add(by),
+        }
+    }
+
+    /// Shifts both bounds down by `by`, wrapping around at the numeric bounds.
+    ///
+    /// The distance between the bounds is preserved modulo the size of the type, although a range that
+    /// wraps across a numeric bound is left inverted.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 3u8, end: 7 }.wrapping_shift_down(5),
+    ///     RangeFromExclusiveToExclusive { start: 254, end: 2 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn wrapping_shift_down(self, by: Idx) -> Self {
+        RangeFromExclusiveToExclusive {
+            start: self.start.wrapping_sub(by),
+            end: self.end.wrapping_sub(by),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iter_bounds::iter_bounds;
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[test]
+    fn range_from_exclusive_checked_shift_up() {
+        assert_some_eq!(
+            RangeFromExclusive { start: -5i8 }.checked_shift_up(5),
+            RangeFromExclusive { start: 0 }
+        );
+        assert_none!(RangeFromExclusive {
+            start: u64::max_value()
+        }
+        .checked_shift_up(1));
+    }
+
+    #[test]
+    fn range_from_exclusive_checked_shift_down() {
+        assert_some_eq!(
+            RangeFromExclusive { start: 0i8 }.checked_shift_down(-127),
+            RangeFromExclusive { start: 127 }
+        );
+        assert_none!(RangeFromExclusive { start: 0usize }.checked_shift_down(1));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_checked_shift_up() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -3i32,
+                end: 3
+            }
+            .checked_shift_up(3),
+            RangeFromExclusiveToInclusive { start: 0, end: 6 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_checked_shift_up_end_overflow() {
+        // Only the end overflows, so the range is not partially shifted.
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: 0u8,
+            end: 255
+        }
+        .checked_shift_up(1));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_checked_shift_down_start_overflow() {
+        // Only the start overflows.
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: i16::min_value(),
+            end: 0
+        }
+        .checked_shift_down(1));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_checked_shift_down() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 10u128,
+                end: 20
+            }
+            .checked_shift_down(10),
+            RangeFromExclusiveToExclusive { start: 0, end: 10 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_checked_shift_inverted() {
+        // An inverted range can overflow at its start when shifting up.
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: 255u8,
+            end: 0
+        }
+        .checked_shift_up(1));
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: 255u8,
+            end: 0
+        }
+        .checked_shift_down(1));
+    }
+
+    #[test]
+    fn range_from_exclusive_saturating_shift() {
+        assert_eq!(
+            RangeFromExclusive { start: 100i8 }.saturating_shift_up(100),
+            RangeFromExclusive { start: 127 }
+        );
+        assert_eq!(
+            RangeFromExclusive { start: -100i8 }.saturating_shift_down(100),
+            RangeFromExclusive { start: -128 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_wrapping_shift() {
+        assert_eq!(
+            RangeFromExclusive { start: 100i8 }.wrapping_shift_up(100),
+            RangeFromExclusive { start: -56 }
+        );
+        assert_eq!(
+            RangeFromExclusive { start: -100i8 }.wrapping_shift_down(100),
+            RangeFromExclusive { start: 56 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_saturating_shift_collapses() {
+        // Both bounds saturate to the maximum, leaving nothing after the start.
+        let range = RangeFromExclusiveToInclusive {
+            start: 250u8,
+            end: 254,
+        }
+        .saturating_shift_up(10);
+
+        assert_eq!(
+            range,
+            RangeFromExclusiveToInclusive {
+                start: 255,
+                end: 255
+            }
+        );
+        assert_eq!(iter_bounds(range).count(), 0);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_wrapping_shift_preserves_length() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 250u8,
+            end: 254,
+        };
+
+        for &by in [0, 1, 5, 6, 128, 255].iter() {
+            let shifted = range.wrapping_shift_up(by);
+            assert_eq!(shifted.end.wrapping_sub(shifted.start), 4);
+            assert_eq!(range.wrapping_shift_down(by).wrapping_shift_up(by), range);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_saturating_shift_collapses() {
+        let range = RangeFromExclusiveToExclusive {
+            start: -126i8,
+            end: -120,
+        }
+        .saturating_shift_down(10);
+
+        assert_eq!(
+            range,
+            RangeFromExclusiveToExclusive {
+                start: -128,
+                end: -128
+            }
+        );
+        assert_eq!(iter_bounds(range).count(), 0);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_wrapping_shift_preserves_length() {
+        let range = RangeFromExclusiveToExclusive {
+            start: -126i8,
+            end: -120,
+        };
+
+        for &by in [0, 1, 2, 3, 127, -128].iter() {
+            let shifted = range.wrapping_shift_down(by);
+            assert_eq!(shifted.end.wrapping_sub(shifted.start), 6);
+            assert_eq!(range.wrapping_shift_up(by).wrapping_shift_down(by), range);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! Mutable access to multiple disjoint ranges of a slice.
+
+use core::mem;
+use core::ops::Range;
+use resolve::Resolve;
+
+/// Returns whether two resolved ranges share at least one element.
+///
+/// This matches [`slice::get_disjoint_mut`]: an empty range only overlaps a range that contains its
+/// position strictly between its bounds.
+///
+/// [`slice::get_disjoint_mut`]: https://doc.rust-lang.org/std/primitive.slice.html#method.get_disjoint_mut
+fn overlaps(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Extension trait for accessing ranges of a slice.
+pub trait SliceExclusiveExt<T> {
+    /// Returns mutable references to many disjoint ranges of the slice at once.
+    ///
+    /// The returned slices are in the same order as `ranges`, which need not be sorted. Ranges that
+    /// are merely adjacent are disjoint. As with [`slice::get_disjoint_mut`], an empty range is
+    /// considered to overlap any range that contains its position strictly between its bounds.
+    ///
+    /// Returns `None` if any range is out of bounds or inverted, or if any two ranges overlap.
+    ///
+    /// `ranges` may contain any of [`RangeFromExclusive<usize>`],
+    /// [`RangeFromExclusiveToExclusive<usize>`], or [`RangeFromExclusiveToInclusive<usize>`].
+    ///
+    /// This method requires Rust 1.55 or newer.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, SliceExclusiveExt};
+    ///
+    /// let mut buffer = [0; 8];
+    /// if let Some([a, b]) = buffer.get_disjoint_range_mut([
+    ///     RangeFromExclusiveToExclusive { start: 4, end: 7 },
+    ///     RangeFromExclusiveToExclusive { start: 0, end: 3 },
+    /// ]) {
+    ///     a.copy_from_slice(&[1, 1]);
+    ///     b.copy_from_slice(&[2, 2]);
+    /// }
+    ///
+    /// assert_eq!(buffer, [0, 2, 2, 0, 0, 1, 1, 0]);
+    /// ```
+    ///
+    /// [`slice::get_disjoint_mut`]: https://doc.rust-lang.org/std/primitive.slice.html#method.get_disjoint_mut
+    /// [`RangeFromExclusive<usize>`]: ::RangeFromExclusive
+    /// [`RangeFromExclusiveToExclusive<usize>`]: ::RangeFromExclusiveToExclusive
+    /// [`RangeFromExclusiveToInclusive<usize>`]: ::RangeFromExclusiveToInclusive
+    fn get_disjoint_range_mut<R, const N: usize>(
+        &mut self,
+        ranges: [R; N],
+    ) -> Option<[&mut [T]; N]>
+    where
+        R: Resolve;
+}
+
+impl<T> SliceExclusiveExt<T> for [T] {
+    fn get_disjoint_range_mut<R, const N: usize>(&mut self, ranges: [R; N]) -> Option<[&mut [T]; N]>
+    where
+        R: Resolve,
+    {
+        let len = self.len();
+        let mut resolved = [(); N].map(|_| 0..0);
+        for (slot, range) in resolved.iter_mut().zip(ranges) {
+            *slot = range.try_resolve(len)?;
+        }
+
+        for (i, a) in resolved.iter().enumerate() {
+            if resolved[..i].iter().any(|b| overlaps(a, b)) {
+                return None;
+            }
+        }
+
+        // Split off the non-empty ranges in order of their position in the slice. Since they are
+        // pairwise disjoint, each one starts at or after the end of the previous one.
+        let mut order = [0; N];
+        for (i, index) in order.iter_mut().enumerate() {
+            *index = i;
+        }
+        order.sort_unstable_by_key(|&i| resolved[i].start);
+
+        let mut slices = [(); N].map(|_| None);
+        let mut rest = self;
+        let mut offset = 0;
+        for &i in &order {
+            let range = &resolved[i];
+            if range.start == range.end {
+                slices[i] = Some(Default::default());
+                continue;
+            }
+            let (_, tail) = mem::take(&mut rest).split_at_mut(range.start - offset);
+            let (slice, tail) = tail.split_at_mut(range.end - range.start);
+            slices[i] = Some(slice);
+            rest = tail;
+            offset = range.end;
+        }
+
+        Some(slices.map(Option::unwrap))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+        SliceExclusiveExt,
+    };
+
+    #[test]
+    fn sorted() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        let [a, b] = slice
+            .get_disjoint_range_mut([
+                RangeFromExclusiveToExclusive { start: 0, end: 3 },
+                RangeFromExclusiveToExclusive { start: 4, end: 7 },
+            ])
+            .unwrap();
+
+        assert_eq!(a, [1, 2]);
+        assert_eq!(b, [5, 6]);
+    }
+
+    #[test]
+    fn unsorted() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        let [a, b, c] = slice
+            .get_disjoint_range_mut([
+                RangeFromExclusiveToInclusive { start: 5, end: 7 },
+                RangeFromExclusiveToInclusive { start: 0, end: 2 },
+                RangeFromExclusiveToInclusive { start: 2, end: 4 },
+            ])
+            .unwrap();
+
+        assert_eq!(a, [6, 7]);
+        assert_eq!(b, [1, 2]);
+        assert_eq!(c, [3, 4]);
+    }
+
+    #[test]
+    fn adjacent() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        let [a, b] = slice
+            .get_disjoint_range_mut([
+                RangeFromExclusiveToExclusive { start: 0, end: 4 },
+                RangeFromExclusiveToExclusive { start: 3, end: 8 },
+            ])
+            .unwrap();
+
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(b, [4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn overlapping() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        assert_none!(slice.get_disjoint_range_mut([
+            RangeFromExclusiveToExclusive { start: 0, end: 4 },
+            RangeFromExclusiveToExclusive { start: 2, end: 6 },
+        ]));
+    }
+
+    #[test]
+    fn overlapping_unsorted() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        assert_none!(slice.get_disjoint_range_mut([
+            RangeFromExclusiveToInclusive { start: 4, end: 6 },
+            RangeFromExclusiveToInclusive { start: 0, end: 1 },
+            RangeFromExclusiveToInclusive { start: 1, end: 5 },
+        ]));
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        assert_none!(slice.get_disjoint_range_mut([
+            RangeFromExclusiveToInclusive { start: 0, end: 2 },
+            RangeFromExclusiveToInclusive { start: 5, end: 8 },
+        ]));
+    }
+
+    #[test]
+    fn inverted() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        assert_none!(
+            slice.get_disjoint_range_mut([RangeFromExclusiveToExclusive { start: 5, end: 2 }])
+        );
+    }
+
+    #[test]
+    fn empty_ranges() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        let [a, b, c] = slice
+            .get_disjoint_range_mut([
+                RangeFromExclusiveToExclusive { start: 3, end: 3 },
+                RangeFromExclusiveToExclusive { start: 0, end: 3 },
+                RangeFromExclusiveToExclusive { start: 8, end: 8 },
+            ])
+            .unwrap();
+
+        assert!(a.is_empty());
+        assert_eq!(b, [1, 2]);
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn empty_range_inside_other_range() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        assert_none!(slice.get_disjoint_range_mut([
+            RangeFromExclusiveToExclusive { start: 3, end: 3 },
+            RangeFromExclusiveToExclusive { start: 0, end: 8 },
+        ]));
+    }
+
+    #[test]
+    fn range_from_exclusive() {
+        let mut slice = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        let [a] = slice
+            .get_disjoint_range_mut([RangeFromExclusive { start: 5 }])
+            .unwrap();
+
+        assert_eq!(a, [6, 7]);
+    }
+
+    #[test]
+    fn writes_are_visible() {
+        let mut slice = [0; 6];
+
+        {
+            let [a, b] = slice
+                .get_disjoint_range_mut([
+                    RangeFromExclusiveToInclusive { start: 3, end: 5 },
+                    RangeFromExclusiveToInclusive { start: 0, end: 2 },
+                ])
+                .unwrap();
+            a.copy_from_slice(&[1, 1]);
+            b.copy_from_slice(&[2, 2]);
+        }
+
+        assert_eq!(slice, [0, 2, 2, 0, 1, 1]);
+    }
+
+    #[test]
+    fn no_ranges() {
+        let mut slice = [0, 1, 2];
+
+        assert_some!(slice.get_disjoint_range_mut::<RangeFromExclusive<usize>, 0>([]));
+    }
+}
@@ -0,0 +1,322 @@
+//! Resolving ranges of byte offsets within string slices, snapping to `char` boundaries.
+
+use core::ops::Range;
+use {
+    try_resolve_bounds, RangeFromExclusive, RangeFromExclusiveToExclusive,
+    RangeFromExclusiveToInclusive, ResolveError,
+};
+
+/// How to handle bounds that do not fall on `char` boundaries when resolving a range within a
+/// string slice.
+///
+/// This is used by the `resolve_str()` method on the ranges over `usize`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SnapMode {
+    /// Moves each bound inward to the nearest `char` boundary, so that the resolved range contains
+    /// only the `char`s entirely within the original range. A range containing no whole `char`s is
+    /// moved to the nearest `char` boundary after its start.
+    Inward,
+    /// Moves each bound outward to the nearest `char` boundary, so that the resolved range contains
+    /// every `char` at least partially within the original range. An empty range is moved to the
+    /// nearest `char` boundary before it.
+    Outward,
+    /// Returns an error if either bound is not on a `char` boundary.
+    Strict,
+}
+
+/// Returns the nearest `char` boundary in `s` at or after `index`.
+#[inline]
+fn boundary_at_or_after(s: &str, mut index: usize) -> usize {
+    while !s.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
+/// Returns the nearest `char` boundary in `s` at or before `index`.
+#[inline]
+fn boundary_at_or_before(s: &str, mut index: usize) -> usize {
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Snaps the already resolved `range` to `char` boundaries in `s`.
+fn snap(s: &str, range: Range<usize>, mode: SnapMode) -> Result<Range<usize>, ResolveError> {
+    match mode {
+        SnapMode::Inward => {
+            let start = boundary_at_or_after(s, range.start);
+            let end = boundary_at_or_before(s, range.end);
+            // A range within a single `char` contains no whole `char`s.
+            if start > end {
+                Ok(start..start)
+            } else {
+                Ok(start..end)
+            }
+        }
+        SnapMode::Outward => {
+            let start = boundary_at_or_before(s, range.start);
+            // An empty range contains no `char`s, and remains empty.
+            if range.start == range.end {
+                Ok(start..start)
+            } else {
+                Ok(start..boundary_at_or_after(s, range.end))
+            }
+        }
+        SnapMode::Strict => {
+            if !s.is_char_boundary(range.start) {
+                Err(ResolveError::NotCharBoundary { index: range.start })
+            } else if !s.is_char_boundary(range.end) {
+                Err(ResolveError::NotCharBoundary { index: range.end })
+            } else {
+                Ok(range)
+            }
+        }
+    }
+}
+
+impl RangeFromExclusive<usize> {
+    /// Resolves the range into the equivalent half-open range of byte offsets in `s`, adjusting a
+    /// start bound that falls within a `char` according to `mode`.
+    ///
+    /// The resolved range can always be used to index `s`.
+    ///
+    /// # Errors
+    /// Returns an error if the range starts beyond the length of `s`, or, with
+    /// [`SnapMode::Strict`], if its start is not on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusive, ResolveError, SnapMode};
+    ///
+    /// // "é" is encoded in bytes 3 and 4.
+    /// let s = "café";
+    /// let range = RangeFromExclusive { start: 3 };
+    ///
+    /// assert_eq!(range.resolve_str(s, SnapMode::Inward), Ok(5..5));
+    /// assert_eq!(range.resolve_str(s, SnapMode::Outward), Ok(3..5));
+    /// assert_eq!(
+    ///     range.resolve_str(s, SnapMode::Strict),
+    ///     Err(ResolveError::NotCharBoundary { index: 4 })
+    /// );
+    /// ```
+    ///
+    /// [`SnapMode::Strict`]: SnapMode::Strict
+    pub fn resolve_str(&self, s: &str, mode: SnapMode) -> Result<Range<usize>, ResolveError> {
+        snap(s, try_resolve_bounds(*self, s.len())?, mode)
+    }
+}
+
+impl RangeFromExclusiveToInclusive<usize> {
+    /// Resolves the range into the equivalent half-open range of byte offsets in `s`, adjusting
+    /// bounds that fall within a `char` according to `mode`.
+    ///
+    /// The resolved range can always be used to index `s`.
+    ///
+    /// # Errors
+    /// Returns an error if the range is inverted, if it extends beyond the length of `s`, or, with
+    /// [`SnapMode::Strict`], if either bound is not on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToInclusive, SnapMode};
+    ///
+    /// // "é" is encoded in bytes 0 and 1.
+    /// let s = "éa";
+    /// let range = RangeFromExclusiveToInclusive { start: 0, end: 2 };
+    ///
+    /// assert_eq!(range.resolve_str(s, SnapMode::Inward), Ok(2..3));
+    /// assert_eq!(range.resolve_str(s, SnapMode::Outward), Ok(0..3));
+    /// ```
+    ///
+    /// [`SnapMode::Strict`]: SnapMode::Strict
+    pub fn resolve_str(&self, s: &str, mode: SnapMode) -> Result<Range<usize>, ResolveError> {
+        snap(s, try_resolve_bounds(*self, s.len())?, mode)
+    }
+}
+
+impl RangeFromExclusiveToExclusive<usize> {
+    /// Resolves the range into the equivalent half-open range of byte offsets in `s`, adjusting
+    /// bounds that fall within a `char` according to `mode`.
+    ///
+    /// The resolved range can always be used to index `s`.
+    ///
+    /// # Errors
+    /// Returns an error if the range is inverted, if it extends beyond the length of `s`, or, with
+    /// [`SnapMode::Strict`], if either bound is not on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{RangeFromExclusiveToExclusive, SnapMode};
+    ///
+    /// // The thumbs-up is encoded in bytes 1 through 4.
+    /// let s = "a\u{1F44D}b";
+    /// let range = RangeFromExclusiveToExclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.resolve_str(s, SnapMode::Inward), Ok(5..5));
+    /// assert_eq!(range.resolve_str(s, SnapMode::Outward), Ok(1..5));
+    /// assert_eq!(&s[1..5], "\u{1F44D}");
+    /// ```
+    ///
+    /// [`SnapMode::Strict`]: SnapMode::Strict
+    pub fn resolve_str(&self, s: &str, mode: SnapMode) -> Result<Range<usize>, ResolveError> {
+        snap(s, try_resolve_bounds(*self, s.len())?, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Range;
+    use std::vec::Vec;
+    use {
+        RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+        ResolveError, SnapMode,
+    };
+
+    /// An ASCII byte, a 4-byte thumbs-up in bytes 1 through 4, and another ASCII byte.
+    const TEXT: &str = "a\u{1F44D}b";
+
+    /// Asserts that `snapped` is the largest range of `char` boundaries within `start..end`.
+    fn assert_inward(start: usize, end: usize, snapped: &Range<usize>) {
+        let _ = &TEXT[snapped.clone()];
+        let boundaries = (start..=end)
+            .filter(|&index| TEXT.is_char_boundary(index))
+            .collect::<Vec<_>>();
+        match (boundaries.first(), boundaries.last()) {
+            (Some(&first), Some(&last)) => assert_eq!(*snapped, first..last),
+            _ => {
+                let after = (start..)
+                    .find(|&index| TEXT.is_char_boundary(index))
+                    .unwrap();
+                assert_eq!(*snapped, after..after);
+            }
+        }
+    }
+
+    /// Asserts that `snapped` is the smallest range of `char` boundaries containing `start..end`.
+    fn assert_outward(start: usize, end: usize, snapped: &Range<usize>) {
+        let _ = &TEXT[snapped.clone()];
+        let first = (0..=start)
+            .rev()
+            .find(|&index| TEXT.is_char_boundary(index))
+            .unwrap();
+        if start == end {
+            assert_eq!(*snapped, first..first);
+        } else {
+            let last = (end..=TEXT.len())
+                .find(|&index| TEXT.is_char_boundary(index))
+                .unwrap();
+            assert_eq!(*snapped, first..last);
+        }
+    }
+
+    #[test]
+    fn to_exclusive_every_byte() {
+        for start in 0..=TEXT.len() {
+            for end in start + 1..=TEXT.len() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                let first = start + 1;
+
+                assert_inward(
+                    first,
+                    end,
+                    &assert_ok!(range.resolve_str(TEXT, SnapMode::Inward)),
+                );
+                assert_outward(
+                    first,
+                    end,
+                    &assert_ok!(range.resolve_str(TEXT, SnapMode::Outward)),
+                );
+                if TEXT.is_char_boundary(first) && TEXT.is_char_boundary(end) {
+                    assert_ok_eq!(range.resolve_str(TEXT, SnapMode::Strict), first..end);
+                } else {
+                    assert_matches!(
+                        range.resolve_str(TEXT, SnapMode::Strict),
+                        Err(ResolveError::NotCharBoundary { .. })
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_inclusive_every_byte() {
+        for start in 0..TEXT.len() {
+            for end in start..TEXT.len() {
+                let range = RangeFromExclusiveToInclusive { start, end };
+
+                assert_inward(
+                    start + 1,
+                    end + 1,
+                    &assert_ok!(range.resolve_str(TEXT, SnapMode::Inward)),
+                );
+                assert_outward(
+                    start + 1,
+                    end + 1,
+                    &assert_ok!(range.resolve_str(TEXT, SnapMode::Outward)),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_every_byte() {
+        for start in 0..TEXT.len() {
+            let range = RangeFromExclusive { start };
+
+            assert_inward(
+                start + 1,
+                TEXT.len(),
+                &assert_ok!(range.resolve_str(TEXT, SnapMode::Inward)),
+            );
+            assert_outward(
+                start + 1,
+                TEXT.len(),
+                &assert_ok!(range.resolve_str(TEXT, SnapMode::Outward)),
+            );
+        }
+    }
+
+    #[test]
+    fn strict_reports_first_bad_bound() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 1, end: 3 }.resolve_str(TEXT, SnapMode::Strict),
+            Err(ResolveError::NotCharBoundary { index: 2 })
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 0, end: 3 }.resolve_str(TEXT, SnapMode::Strict),
+            Err(ResolveError::NotCharBoundary { index: 3 })
+        );
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        for &mode in [SnapMode::Inward, SnapMode::Outward, SnapMode::Strict].iter() {
+            assert_eq!(
+                RangeFromExclusiveToInclusive { start: 2, end: 6 }.resolve_str(TEXT, mode),
+                Err(ResolveError::EndOutOfBounds { index: 7, len: 6 })
+            );
+            assert_eq!(
+                RangeFromExclusiveToExclusive { start: 4, end: 2 }.resolve_str(TEXT, mode),
+                Err(ResolveError::Inverted { start: 5, end: 2 })
+            );
+            assert_eq!(
+                RangeFromExclusive { start: 6 }.resolve_str(TEXT, mode),
+                Err(ResolveError::StartOutOfBounds { index: 7, len: 6 })
+            );
+        }
+    }
+
+    #[test]
+    fn equal_bounds_within_char() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive { start: 3, end: 3 }.resolve_str(TEXT, SnapMode::Inward),
+            5..5
+        );
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive { start: 3, end: 3 }.resolve_str(TEXT, SnapMode::Outward),
+            1..1
+        );
+    }
+}
@@ -0,0 +1,604 @@
+//! Conversions between the range types and the standard library's ranges over integers.
+
+use core::ops::{Range, RangeFrom, RangeInclusive};
+use iter_bounds::Integer;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl<Idx> RangeFromExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Converts the range into the equivalent [`RangeFrom`], `(start + 1)..`.
+    ///
+    /// Returns `None` if `start + 1` overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// let cursor = RangeFromExclusive { start: 1 };
+    ///
+    /// assert_eq!(cursor.to_range_from(), Some(2..));
+    /// assert_eq!(&[0, 1, 2, 3][cursor.to_range_from().unwrap()], [2, 3]);
+    /// ```
+    ///
+    /// [`RangeFrom`]: core::ops::RangeFrom
+    #[inline]
+    pub fn to_range_from(self) -> Option<RangeFrom<Idx>> {
+        self.start.checked_succ().map(|start| start..)
+    }
+
+    /// Converts a [`RangeFrom`] into the equivalent range, `(start - 1)..` with an exclusive start.
+    ///
+    /// Returns `None` if `start - 1` overflows, such as for a range over an unsigned type starting
+    /// at `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive::from_range_from(0i32..),
+    ///     Some(RangeFromExclusive { start: -1 })
+    /// );
+    /// assert_eq!(RangeFromExclusive::from_range_from(0u32..), None);
+    /// ```
+    ///
+    /// [`RangeFrom`]: core::ops::RangeFrom
+    #[inline]
+    pub fn from_range_from(range: RangeFrom<Idx>) -> Option<Self> {
+        range
+            .start
+            .checked_pred()
+            .map(|start| RangeFromExclusive { start })
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Converts the range into the equivalent [`RangeInclusive`], `(start + 1)..=end`.
+    ///
+    /// A range with equal bounds is converted to `(end + 1)..=end`, which is empty.
+    ///
+    /// Returns `None` if `start + 1` overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 4 }.to_range_inclusive(),
+    ///     Some(2..=4)
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 255u8, end: 255 }.to_range_inclusive(),
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// [`RangeInclusive`]: core::ops::RangeInclusive
+    #[inline]
+    pub fn to_range_inclusive(self) -> Option<RangeInclusive<Idx>> {
+        self.start.checked_succ().map(|start| start..=self.end)
+    }
+
+    /// Converts a [`RangeInclusive`] into the equivalent range, `(start - 1)..=end` with an
+    /// exclusive start.
+    ///
+    /// An empty `range`, either because `start > end` or because it has been exhausted by
+    /// iteration, is converted to the empty range `end..=end`.
+    ///
+    /// Returns `None` if `start - 1` overflows, such as for a non-empty range over an unsigned type
+    /// starting at `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive::from_range_inclusive(1u8..=4),
+    ///     Some(RangeFromExclusiveToInclusive { start: 0, end: 4 })
+    /// );
+    /// assert_eq!(RangeFromExclusiveToInclusive::from_range_inclusive(0u8..=4), None);
+    /// ```
+    ///
+    /// [`RangeInclusive`]: core::ops::RangeInclusive
+    pub fn from_range_inclusive(range: RangeInclusive<Idx>) -> Option<Self> {
+        let (start, end) = (*range.start(), *range.end());
+        // An exhausted range has the same bounds as a new one, but does not compare equal to it.
+        if start > end || range != (start..=end) {
+            return Some(RangeFromExclusiveToInclusive { start: end, end });
+        }
+        start
+            .checked_pred()
+            .map(|start| RangeFromExclusiveToInclusive { start, end })
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Converts the range into the equivalent [`Range`], `(start + 1)..end`.
+    ///
+    /// A range with equal bounds is converted to `end..end`, so that the result is empty without
+    /// being reversed.
+    ///
+    /// Returns `None` if `start + 1` overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }.to_range(),
+    ///     Some(2..4)
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 4, end: 4 }.to_range(),
+    ///     Some(4..4)
+    /// );
+    /// ```
+    ///
+    /// [`Range`]: core::ops::Range
+    #[inline]
+    pub fn to_range(self) -> Option<Range<Idx>> {
+        if self.start == self.end {
+            return Some(self.end..self.end);
+        }
+        self.start.checked_succ().map(|start| start..self.end)
+    }
+
+    /// Converts a [`Range`] into the equivalent range, `(start - 1)..end` with an exclusive start.
+    ///
+    /// Returns `None` if `start - 1` overflows, such as for a range over an unsigned type starting
+    /// at `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive::from_range(0i32..4),
+    ///     Some(RangeFromExclusiveToExclusive { start: -1, end: 4 })
+    /// );
+    /// assert_eq!(RangeFromExclusiveToExclusive::from_range(0u32..4), None);
+    /// ```
+    ///
+    /// [`Range`]: core::ops::Range
+    #[inline]
+    pub fn from_range(range: Range<Idx>) -> Option<Self> {
+        range
+            .start
+            .checked_pred()
+            .map(|start| RangeFromExclusiveToExclusive {
+                start,
+                end: range.end,
+            })
+    }
+}
+
+/// Extension trait for converting the standard library's ranges over integers into the range types
+/// with an exclusive start.
+///
+/// This performs the checked `start - 1` adjustment at the boundary between code using the
+/// standard ranges and code using this crate's ranges.
+pub trait ToExclusiveStart {
+    /// The range type with an exclusive start containing the same values.
+    type Output;
+
+    /// Converts the range into the equivalent range with an exclusive start.
+    ///
+    /// Returns `None` if `start - 1` overflows, such as for a range over an unsigned type starting
+    /// at `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{
+    ///     RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+    ///     ToExclusiveStart,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     (5..10).exclusive_start(),
+    ///     Some(RangeFromExclusiveToExclusive { start: 4, end: 10 })
+    /// );
+    /// assert_eq!(
+    ///     (5..=10).exclusive_start(),
+    ///     Some(RangeFromExclusiveToInclusive { start: 4, end: 10 })
+    /// );
+    /// assert_eq!(
+    ///     (5..).exclusive_start(),
+    ///     Some(RangeFromExclusive { start: 4 })
+    /// );
+    /// assert_eq!((0u32..10).exclusive_start(), None);
+    /// ```
+    fn exclusive_start(self) -> Option<Self::Output>;
+}
+
+impl<Idx> ToExclusiveStart for Range<Idx>
+where
+    Idx: Integer,
+{
+    type Output = RangeFromExclusiveToExclusive<Idx>;
+
+    #[inline]
+    fn exclusive_start(self) -> Option<Self::Output> {
+        RangeFromExclusiveToExclusive::from_range(self)
+    }
+}
+
+impl<Idx> ToExclusiveStart for RangeInclusive<Idx>
+where
+    Idx: Integer,
+{
+    type Output = RangeFromExclusiveToInclusive<Idx>;
+
+    #[inline]
+    fn exclusive_start(self) -> Option<Self::Output> {
+        RangeFromExclusiveToInclusive::from_range_inclusive(self)
+    }
+}
+
+impl<Idx> ToExclusiveStart for RangeFrom<Idx>
+where
+    Idx: Integer,
+{
+    type Output = RangeFromExclusive<Idx>;
+
+    #[inline]
+    fn exclusive_start(self) -> Option<Self::Output> {
+        RangeFromExclusive::from_range_from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+        ToExclusiveStart,
+    };
+
+    #[test]
+    fn range_from_exclusive_to_range_from() {
+        assert_some_eq!(RangeFromExclusive { start: 1 }.to_range_from(), 2..);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_range_from_boundary() {
+        assert_some_eq!(RangeFromExclusive { start: 254u8 }.to_range_from(), 255..);
+        assert_none!(RangeFromExclusive {
+            start: u8::max_value()
+        }
+        .to_range_from());
+    }
+
+    #[test]
+    fn range_from_exclusive_from_range_from_u8() {
+        assert_some_eq!(
+            RangeFromExclusive::from_range_from(1u8..),
+            RangeFromExclusive { start: 0 }
+        );
+        assert_some_eq!(
+            RangeFromExclusive::from_range_from(u8::max_value()..),
+            RangeFromExclusive { start: 254 }
+        );
+        assert_none!(RangeFromExclusive::from_range_from(0u8..));
+    }
+
+    #[test]
+    fn range_from_exclusive_from_range_from_i8() {
+        assert_some_eq!(
+            RangeFromExclusive::from_range_from(0i8..),
+            RangeFromExclusive { start: -1 }
+        );
+        assert_some_eq!(
+            RangeFromExclusive::from_range_from(i8::min_value() + 1..),
+            RangeFromExclusive {
+                start: i8::min_value()
+            }
+        );
+        assert_none!(RangeFromExclusive::from_range_from(i8::min_value()..));
+    }
+
+    #[test]
+    fn range_from_exclusive_from_range_from_usize() {
+        assert_some_eq!(
+            RangeFromExclusive::from_range_from(usize::max_value()..),
+            RangeFromExclusive {
+                start: usize::max_value() - 1
+            }
+        );
+        assert_none!(RangeFromExclusive::from_range_from(0usize..));
+    }
+
+    #[test]
+    fn range_from_exclusive_from_range_from_round_trip() {
+        for start in 1..=u8::max_value() {
+            let range = assert_some!(RangeFromExclusive::from_range_from(start..));
+            assert_some_eq!(range.to_range_from(), start..);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_range_from_suffix() {
+        let slice = [0, 1, 2, 3, 4];
+
+        assert_eq!(
+            slice[RangeFromExclusive { start: 2 }.to_range_from().unwrap()],
+            [3, 4]
+        );
+        assert!(slice[RangeFromExclusive { start: 4 }.to_range_from().unwrap()].is_empty());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_to_range_inclusive() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }.to_range_inclusive(),
+            2..=4
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_to_range_inclusive_single() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 254u8,
+                end: 255
+            }
+            .to_range_inclusive(),
+            255..=255
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_to_range_inclusive_empty() {
+        let range =
+            assert_some!(RangeFromExclusiveToInclusive { start: 4, end: 4 }.to_range_inclusive());
+
+        assert_eq!((*range.start(), *range.end()), (5, 4));
+        assert_none!(range.clone().next());
+        assert!([0, 1, 2, 3, 4][range].is_empty());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_to_range_inclusive_start_is_max() {
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: u8::max_value(),
+            end: u8::max_value()
+        }
+        .to_range_inclusive());
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: i64::max_value(),
+            end: 0
+        }
+        .to_range_inclusive());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_range_inclusive() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive::from_range_inclusive(1u8..=255),
+            RangeFromExclusiveToInclusive { start: 0, end: 255 }
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive::from_range_inclusive(-5i8..=0),
+            RangeFromExclusiveToInclusive { start: -6, end: 0 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_range_inclusive_underflow() {
+        assert_none!(RangeFromExclusiveToInclusive::from_range_inclusive(0u8..=0));
+        assert_none!(RangeFromExclusiveToInclusive::from_range_inclusive(
+            i32::min_value()..=5
+        ));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_range_inclusive_empty() {
+        let (start, end) = (5u8, 3);
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive::from_range_inclusive(start..=end),
+            RangeFromExclusiveToInclusive { start: 3, end: 3 }
+        );
+        let (start, end) = (i8::min_value() + 1, i8::min_value());
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive::from_range_inclusive(start..=end),
+            RangeFromExclusiveToInclusive {
+                start: i8::min_value(),
+                end: i8::min_value()
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_range_inclusive_exhausted() {
+        let mut range = 0u8..=0;
+        assert_some_eq!(range.next(), 0);
+
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive::from_range_inclusive(range),
+            RangeFromExclusiveToInclusive { start: 0, end: 0 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_from_range_inclusive_round_trip() {
+        for start in 1..5u8 {
+            for end in start..5 {
+                let range = assert_some!(RangeFromExclusiveToInclusive::from_range_inclusive(
+                    start..=end
+                ));
+                assert_some_eq!(range.to_range_inclusive(), start..=end);
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_to_range_inclusive_iterate() {
+        let range = RangeFromExclusiveToInclusive {
+            start: -2i32,
+            end: 2,
+        };
+        let mut sum = 0;
+        for value in range.to_range_inclusive().unwrap() {
+            sum += value;
+        }
+
+        assert_eq!(sum, 2);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_range() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }.to_range(),
+            2..4
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_range_adjacent() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive { start: 3, end: 4 }.to_range(),
+            4..4
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_range_equal() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive { start: 4, end: 4 }.to_range(),
+            4..4
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: u8::max_value(),
+                end: u8::max_value()
+            }
+            .to_range(),
+            255..255
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_range_inverted() {
+        let range = assert_some!(RangeFromExclusiveToExclusive { start: 4, end: 1 }.to_range());
+
+        assert_eq!((range.start, range.end), (5, 1));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_range_overflow() {
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: i8::max_value(),
+            end: 0
+        }
+        .to_range());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_range() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive::from_range(2u8..4),
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_range_unsigned_underflow() {
+        assert_none!(RangeFromExclusiveToExclusive::from_range(0u8..4));
+        assert_none!(RangeFromExclusiveToExclusive::from_range(0usize..0));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_range_signed() {
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive::from_range(0i8..4),
+            RangeFromExclusiveToExclusive { start: -1, end: 4 }
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive::from_range(-5i64..-2),
+            RangeFromExclusiveToExclusive { start: -6, end: -2 }
+        );
+        assert_none!(RangeFromExclusiveToExclusive::from_range(
+            i8::min_value()..0
+        ));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_from_range_round_trip() {
+        for start in 1..5u8 {
+            for end in start..5 {
+                let range = assert_some!(RangeFromExclusiveToExclusive::from_range(start..end));
+                assert_some_eq!(range.to_range(), start..end);
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_to_range_index() {
+        let slice = [0, 1, 2, 3, 4];
+
+        assert_eq!(
+            slice[RangeFromExclusiveToExclusive { start: 0, end: 3 }
+                .to_range()
+                .unwrap()],
+            [1, 2]
+        );
+        assert!(slice[RangeFromExclusiveToExclusive { start: 5, end: 5 }
+            .to_range()
+            .unwrap()]
+        .is_empty());
+    }
+
+    #[test]
+    fn range_exclusive_start() {
+        assert_some_eq!(
+            (-5i64..5).exclusive_start(),
+            RangeFromExclusiveToExclusive { start: -6, end: 5 }
+        );
+        assert_none!((0u8..5).exclusive_start());
+        assert_none!((i16::min_value()..5).exclusive_start());
+    }
+
+    #[test]
+    fn range_inclusive_exclusive_start() {
+        assert_some_eq!(
+            (1usize..=5).exclusive_start(),
+            RangeFromExclusiveToInclusive { start: 0, end: 5 }
+        );
+        assert_none!((0usize..=5).exclusive_start());
+    }
+
+    #[test]
+    fn range_from_exclusive_start() {
+        assert_some_eq!(
+            (u8::max_value()..).exclusive_start(),
+            RangeFromExclusive { start: 254 }
+        );
+        assert_none!((0u128..).exclusive_start());
+    }
+
+    #[test]
+    fn exclusive_start_agrees_with_from() {
+        for start in 0..=u8::max_value() {
+            for end in 0..=u8::max_value() {
+                assert_eq!(
+                    (start..end).exclusive_start(),
+                    RangeFromExclusiveToExclusive::from_range(start..end)
+                );
+                assert_eq!(
+                    (start..=end).exclusive_start(),
+                    RangeFromExclusiveToInclusive::from_range_inclusive(start..=end)
+                );
+            }
+            assert_eq!(
+                (start..).exclusive_start(),
+                RangeFromExclusive::from_range_from(start..)
+            );
+        }
+    }
+}
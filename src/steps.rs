@@ -0,0 +1,255 @@
+//! Counting the steps between two values.
+//!
+//! This provides, on stable Rust, the same arithmetic as the unstable
+//! [`Step::steps_between`](core::iter::Step::steps_between).
+
+/// Types whose values can be counted in steps from one to another.
+///
+/// This is implemented for the primitive integers and `char`. For `char`, the surrogate code points
+/// `U+D800` to `U+DFFF` are not valid values and are skipped.
+pub trait StepsBetween {
+    /// Returns the number of successor steps needed to go from `start` to `end`.
+    ///
+    /// Returns `None` if `start > end`, or if the number of steps does not fit in a `usize`.
+    fn steps_between(start: &Self, end: &Self) -> Option<usize>;
+
+    /// Returns the number of successor steps needed to go from `start` to `end`, as a `u128`.
+    ///
+    /// Unlike [`steps_between()`], this can express the distance between any two values of every
+    /// implementing type. Returns `None` only if `start > end`.
+    ///
+    /// [`steps_between()`]: StepsBetween::steps_between
+    fn steps_between_wide(start: &Self, end: &Self) -> Option<u128>;
+}
+
+/// Returns the number of successor steps needed to go from `start` to `end`.
+///
+/// Returns `None` if `start > end`, or if the number of steps does not fit in a `usize`.
+///
+/// # Example
+/// ```
+/// use more_ranges::steps_between;
+///
+/// assert_eq!(steps_between(&-2i8, &3), Some(5));
+/// assert_eq!(steps_between(&3i8, &-2), None);
+/// // The surrogate code points are skipped.
+/// assert_eq!(steps_between(&'\u{D7FF}', &'\u{E000}'), Some(1));
+/// ```
+#[inline]
+pub fn steps_between<T>(start: &T, end: &T) -> Option<usize>
+where
+    T: StepsBetween,
+{
+    T::steps_between(start, end)
+}
+
+/// Returns the number of successor steps needed to go from `start` to `end`, as a `u128`.
+///
+/// Returns `None` only if `start > end`.
+///
+/// # Example
+/// ```
+/// use more_ranges::steps_between_wide;
+///
+/// assert_eq!(
+///     steps_between_wide(&i128::min_value(), &i128::max_value()),
+///     Some(u128::max_value())
+/// );
+/// ```
+#[inline]
+pub fn steps_between_wide<T>(start: &T, end: &T) -> Option<u128>
+where
+    T: StepsBetween,
+{
+    T::steps_between_wide(start, end)
+}
+
+/// The first surrogate code point, which is not a valid `char`.
+const SURROGATE_START: u32 = 0xD800;
+/// The last surrogate code point, which is not a valid `char`.
+const SURROGATE_END: u32 = 0xDFFF;
+
+/// Returns the next `char`, skipping the surrogate code points.
+#[inline]
+pub fn char_succ(value: char) -> Option<char> {
+    match value as u32 {
+        n if n == SURROGATE_START - 1 => ::core::char::from_u32(SURROGATE_END + 1),
+        n => ::core::char::from_u32(n + 1),
+    }
+}
+
+/// Returns the previous `char`, skipping the surrogate code points.
+#[inline]
+pub fn char_pred(value: char) -> Option<char> {
+    match value as u32 {
+        0 => None,
+        n if n == SURROGATE_END + 1 => ::core::char::from_u32(SURROGATE_START - 1),
+        n => ::core::char::from_u32(n - 1),
+    }
+}
+
+/// Narrows a number of steps to a `usize`.
+#[inline]
+fn narrow(steps: u128) -> Option<usize> {
+    if steps <= usize::max_value() as u128 {
+        Some(steps as usize)
+    } else {
+        None
+    }
+}
+
+macro_rules! impl_steps_between {
+    ($wide:ty; $($t:ty),*) => {
+        $(
+            impl StepsBetween for $t {
+                #[inline]
+                fn steps_between(start: &Self, end: &Self) -> Option<usize> {
+                    Self::steps_between_wide(start, end).and_then(narrow)
+                }
+
+                #[inline]
+                fn steps_between_wide(start: &Self, end: &Self) -> Option<u128> {
+                    if start > end {
+                        None
+                    } else {
+                        // The true difference is non-negative and less than 2^128, so it is
+                        // recovered exactly from the wrapping difference.
+                        Some((*end as $wide).wrapping_sub(*start as $wide) as u128)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_steps_between!(u128; u8, u16, u32, u64, u128, usize);
+impl_steps_between!(i128; i8, i16, i32, i64, i128, isize);
+
+impl StepsBetween for char {
+    #[inline]
+    fn steps_between(start: &Self, end: &Self) -> Option<usize> {
+        Self::steps_between_wide(start, end).and_then(narrow)
+    }
+
+    #[inline]
+    fn steps_between_wide(start: &Self, end: &Self) -> Option<u128> {
+        let start = *start as u32;
+        let end = *end as u32;
+        if start > end {
+            return None;
+        }
+        let mut steps = end - start;
+        // Neither bound can be a surrogate, so the range either spans all of them or none.
+        if start < SURROGATE_START && end > SURROGATE_END {
+            steps -= SURROGATE_END - SURROGATE_START + 1;
+        }
+        Some(u128::from(steps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::char;
+    use {steps_between, steps_between_wide};
+
+    #[test]
+    fn unsigned_matches_range_count() {
+        for start in 0..=u8::max_value() {
+            for end in start..=u8::max_value() {
+                assert_some_eq!(steps_between(&start, &end), (start..end).count());
+            }
+        }
+    }
+
+    #[test]
+    fn signed_matches_range_count() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in start..=i8::max_value() {
+                assert_some_eq!(steps_between(&start, &end), (start..end).count());
+            }
+        }
+    }
+
+    #[test]
+    fn inverted() {
+        assert_none!(steps_between(&5u8, &4));
+        assert_none!(steps_between(&-4i64, &-5));
+        assert_none!(steps_between_wide(&1u128, &0));
+        assert_none!(steps_between(&'b', &'a'));
+    }
+
+    #[test]
+    fn equal() {
+        assert_some_eq!(steps_between(&5u8, &5), 0);
+        assert_some_eq!(
+            steps_between_wide(&i128::min_value(), &i128::min_value()),
+            0
+        );
+        assert_some_eq!(steps_between(&'a', &'a'), 0);
+    }
+
+    #[test]
+    fn signed_full_domain() {
+        assert_some_eq!(
+            steps_between(&i16::min_value(), &i16::max_value()),
+            u16::max_value() as usize
+        );
+        assert_some_eq!(
+            steps_between_wide(&i64::min_value(), &i64::max_value()),
+            u128::from(u64::max_value())
+        );
+        assert_some_eq!(
+            steps_between_wide(&i128::min_value(), &i128::max_value()),
+            u128::max_value()
+        );
+        assert_some_eq!(
+            steps_between_wide(&isize::min_value(), &isize::max_value()),
+            usize::max_value() as u128
+        );
+    }
+
+    #[test]
+    fn unsigned_full_domain() {
+        assert_some_eq!(
+            steps_between(&0usize, &usize::max_value()),
+            usize::max_value()
+        );
+        assert_some_eq!(
+            steps_between_wide(&0u128, &u128::max_value()),
+            u128::max_value()
+        );
+    }
+
+    #[test]
+    fn does_not_fit_in_usize() {
+        assert_none!(steps_between(&0u128, &u128::max_value()));
+        assert_none!(steps_between(&i128::min_value(), &0));
+        assert_some_eq!(steps_between_wide(&i128::min_value(), &0), 1 << 127);
+    }
+
+    #[test]
+    fn char_matches_range_count() {
+        let chars = [
+            '\0',
+            'a',
+            '\u{D7FE}',
+            '\u{D7FF}',
+            '\u{E000}',
+            '\u{E001}',
+            '\u{10FFFF}',
+        ];
+        for &start in &chars {
+            for &end in &chars {
+                if start <= end {
+                    assert_some_eq!(steps_between(&start, &end), (start..end).count());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn char_skips_surrogates() {
+        assert_some_eq!(steps_between(&'\u{D7FF}', &'\u{E000}'), 1);
+        assert_some_eq!(steps_between_wide(&'\0', &char::MAX), 0x10FFFF - 0x800);
+    }
+}
@@ -0,0 +1,269 @@
+//! Sub-ranges of the bounded range types over primitive integers.
+//!
+//! These are computed from the bounds alone, without iterating over the values of the range.
+
+use iter_bounds::Integer;
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Returns the sub-range without the first `n` values.
+    ///
+    /// The `start` is advanced by `n`, saturating at `end`. A negative `n` is treated as zero, and an
+    /// empty range is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 6 }.skipped(2),
+    ///     RangeFromExclusiveToInclusive { start: 3, end: 6 }
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 6 }.skipped(10),
+    ///     RangeFromExclusiveToInclusive { start: 6, end: 6 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn skipped(self, n: Idx) -> Self {
+        if self.start >= self.end {
+            return self;
+        }
+        RangeFromExclusiveToInclusive {
+            start: self.start.saturating_add(n).max(self.start).min(self.end),
+            end: self.end,
+        }
+    }
+
+    /// Returns the sub-range containing at most the first `n` values.
+    ///
+    /// A negative `n` is treated as zero, and an empty range is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 6 }.taken(2),
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 3 }
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 6 }.taken(10),
+    ///     RangeFromExclusiveToInclusive { start: 1, end: 6 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn taken(self, n: Idx) -> Self {
+        if self.start >= self.end {
+            return self;
+        }
+        // If `start + n` saturates, it is at least `end`.
+        RangeFromExclusiveToInclusive {
+            start: self.start,
+            end: self.start.saturating_add(n).max(self.start).min(self.end),
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Returns the sub-range without the first `n` values.
+    ///
+    /// The `start` is advanced by `n`, saturating at `end`. A negative `n` is treated as zero, and an
+    /// empty range is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 6 }.skipped(2),
+    ///     RangeFromExclusiveToExclusive { start: 3, end: 6 }
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 6 }.skipped(10),
+    ///     RangeFromExclusiveToExclusive { start: 6, end: 6 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn skipped(self, n: Idx) -> Self {
+        if self.start >= self.end {
+            return self;
+        }
+        RangeFromExclusiveToExclusive {
+            start: self.start.saturating_add(n).max(self.start).min(self.end),
+            end: self.end,
+        }
+    }
+
+    /// Returns the sub-range containing at most the first `n` values.
+    ///
+    /// A negative `n` is treated as zero, and an empty range is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 6 }.taken(2),
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 4 }
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 6 }.taken(10),
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 6 }
+    /// );
+    /// ```
+    #[inline]
+    pub fn taken(self, n: Idx) -> Self {
+        if self.start >= self.end {
+            return self;
+        }
+        // If `start + n + 1` overflows, it is greater than `end`.
+        RangeFromExclusiveToExclusive {
+            start: self.start,
+            end: match self.start.saturating_add(n).max(self.start).checked_succ() {
+                Some(end) => end.min(self.end),
+                None => self.end,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iter_bounds::iter_bounds;
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_skipped_agrees_with_skip() {
+        let range = RangeFromExclusiveToInclusive { start: 1u8, end: 6 };
+
+        for n in 0..10 {
+            assert!(iter_bounds(range.skipped(n)).eq(iter_bounds(range).skip(n as usize)));
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_taken_agrees_with_take() {
+        let range = RangeFromExclusiveToInclusive { start: 1u8, end: 6 };
+
+        for n in 0..10 {
+            assert!(iter_bounds(range.taken(n)).eq(iter_bounds(range).take(n as usize)));
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_limits() {
+        let range = RangeFromExclusiveToInclusive {
+            start: i8::min_value(),
+            end: i8::max_value(),
+        };
+
+        assert_eq!(
+            range.skipped(i8::max_value()),
+            RangeFromExclusiveToInclusive {
+                start: -1,
+                end: 127
+            }
+        );
+        assert_eq!(
+            range
+                .skipped(i8::max_value())
+                .skipped(i8::max_value())
+                .skipped(i8::max_value())
+                .start,
+            127
+        );
+        assert_eq!(
+            range.skipped(5).taken(i8::max_value()),
+            RangeFromExclusiveToInclusive {
+                start: -123,
+                end: 4
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_negative() {
+        let range = RangeFromExclusiveToInclusive {
+            start: -5i8,
+            end: 5,
+        };
+
+        assert_eq!(range.skipped(-1), range);
+        assert_eq!(range.skipped(i8::min_value()), range);
+        assert_eq!(range.taken(-1), range.taken(0));
+        assert_eq!(range.taken(i8::min_value()), range.taken(0));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_empty() {
+        let range = RangeFromExclusiveToInclusive { start: 6u8, end: 1 };
+
+        assert_eq!(range.skipped(2), range);
+        assert_eq!(range.taken(2), range);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_skipped_agrees_with_skip() {
+        let range = RangeFromExclusiveToExclusive { start: 1u8, end: 6 };
+
+        for n in 0..10 {
+            assert!(iter_bounds(range.skipped(n)).eq(iter_bounds(range).skip(n as usize)));
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_taken_agrees_with_take() {
+        let range = RangeFromExclusiveToExclusive { start: 1u8, end: 6 };
+
+        for n in 0..10 {
+            assert!(iter_bounds(range.taken(n)).eq(iter_bounds(range).take(n as usize)));
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_taken_overflow() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 250u8,
+            end: 255,
+        };
+
+        assert_eq!(range.taken(4), range);
+        assert_eq!(range.taken(5), range);
+        assert_eq!(range.taken(255), range);
+        assert_eq!(
+            range.skipped(255),
+            RangeFromExclusiveToExclusive {
+                start: 255,
+                end: 255
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_negative() {
+        let range = RangeFromExclusiveToExclusive {
+            start: -5i8,
+            end: 5,
+        };
+
+        assert_eq!(range.skipped(-1), range);
+        assert_eq!(range.skipped(i8::min_value()), range);
+        assert_eq!(range.taken(-1), range.taken(0));
+        assert_eq!(range.taken(i8::min_value()), range.taken(0));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_empty() {
+        let range = RangeFromExclusiveToExclusive { start: 6u8, end: 1 };
+
+        assert_eq!(range.skipped(2), range);
+        assert_eq!(range.taken(2), range);
+    }
+}
@@ -0,0 +1,187 @@
+//! Fallible conversion of the index type of the range types.
+//!
+//! This requires Rust 1.34 or newer.
+
+use core::convert::TryFrom;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl<Idx> RangeFromExclusive<Idx> {
+    /// Converts the bound of the range into a `U` using [`TryFrom`].
+    ///
+    /// # Errors
+    /// Returns the conversion error if the bound cannot be represented as a `U`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: 1i64 }.try_cast::<usize>(),
+    ///     Ok(RangeFromExclusive { start: 1 })
+    /// );
+    /// assert!(RangeFromExclusive { start: -1i64 }.try_cast::<usize>().is_err());
+    /// ```
+    ///
+    /// [`TryFrom`]: core::convert::TryFrom
+    #[inline]
+    pub fn try_cast<U>(self) -> Result<RangeFromExclusive<U>, U::Error>
+    where
+        U: TryFrom<Idx>,
+    {
+        Ok(RangeFromExclusive {
+            start: U::try_from(self.start)?,
+        })
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx> {
+    /// Converts the bounds of the range into `U`s using [`TryFrom`].
+    ///
+    /// # Errors
+    /// Returns the conversion error of the first bound, starting with `start`, that cannot be
+    /// represented as a `U`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 1u64, end: 4 }.try_cast::<u32>(),
+    ///     Ok(RangeFromExclusiveToInclusive { start: 1, end: 4 })
+    /// );
+    /// assert!(RangeFromExclusiveToInclusive { start: 1u64, end: 1 << 32 }
+    ///     .try_cast::<u32>()
+    ///     .is_err());
+    /// ```
+    ///
+    /// [`TryFrom`]: core::convert::TryFrom
+    #[inline]
+    pub fn try_cast<U>(self) -> Result<RangeFromExclusiveToInclusive<U>, U::Error>
+    where
+        U: TryFrom<Idx>,
+    {
+        Ok(RangeFromExclusiveToInclusive {
+            start: U::try_from(self.start)?,
+            end: U::try_from(self.end)?,
+        })
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx> {
+    /// Converts the bounds of the range into `U`s using [`TryFrom`].
+    ///
+    /// # Errors
+    /// Returns the conversion error of the first bound, starting with `start`, that cannot be
+    /// represented as a `U`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1u64, end: 4 }.try_cast::<u32>(),
+    ///     Ok(RangeFromExclusiveToExclusive { start: 1, end: 4 })
+    /// );
+    /// assert!(RangeFromExclusiveToExclusive { start: 1u64, end: 1 << 32 }
+    ///     .try_cast::<u32>()
+    ///     .is_err());
+    /// ```
+    ///
+    /// [`TryFrom`]: core::convert::TryFrom
+    #[inline]
+    pub fn try_cast<U>(self) -> Result<RangeFromExclusiveToExclusive<U>, U::Error>
+    where
+        U: TryFrom<Idx>,
+    {
+        Ok(RangeFromExclusiveToExclusive {
+            start: U::try_from(self.start)?,
+            end: U::try_from(self.end)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    /// An even number, whose conversion error is the rejected value.
+    #[derive(Debug, PartialEq)]
+    struct Even(u8);
+
+    impl TryFrom<u8> for Even {
+        type Error = u8;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            if value % 2 == 0 {
+                Ok(Even(value))
+            } else {
+                Err(value)
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_try_cast() {
+        assert_ok_eq!(
+            RangeFromExclusive { start: 200i64 }.try_cast::<u8>(),
+            RangeFromExclusive { start: 200 }
+        );
+        assert_err!(RangeFromExclusive { start: 256i64 }.try_cast::<u8>());
+        assert_err!(RangeFromExclusive { start: -1i64 }.try_cast::<u8>());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_try_cast() {
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -128i64,
+                end: 127
+            }
+            .try_cast::<i8>(),
+            RangeFromExclusiveToInclusive {
+                start: -128,
+                end: 127
+            }
+        );
+        assert_err!(RangeFromExclusiveToInclusive {
+            start: -129i64,
+            end: 0
+        }
+        .try_cast::<i8>());
+        assert_err!(RangeFromExclusiveToInclusive {
+            start: 0i64,
+            end: 128
+        }
+        .try_cast::<i8>());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_cast() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 0i64,
+                end: 10
+            }
+            .try_cast::<usize>(),
+            RangeFromExclusiveToExclusive { start: 0, end: 10 }
+        );
+        assert_err!(RangeFromExclusiveToExclusive {
+            start: -1i64,
+            end: 10
+        }
+        .try_cast::<usize>());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_cast_first_error() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 5u8, end: 7 }.try_cast::<Even>(),
+            Err(5)
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 4u8, end: 7 }.try_cast::<Even>(),
+            Err(7)
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Fallible conversion from pairs of [`Bound`]s into the range types.
+//!
+//! This requires Rust 1.34 or newer.
+//!
+//! [`Bound`]: core::ops::Bound
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Bound;
+#[cfg(feature = "std")]
+use std::error::Error;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// An error converting a pair of [`Bound`]s into a range type.
+///
+/// This indicates which bound did not match the kind required by the range type, so that the pair
+/// can be converted into a different range type instead. The start bound is checked first.
+///
+/// [`Bound`]: core::ops::Bound
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TryFromBoundsError {
+    /// The start bound is not `Excluded`.
+    StartNotExcluded,
+    /// The end bound is not `Included`.
+    EndNotIncluded,
+    /// The end bound is not `Excluded`.
+    EndNotExcluded,
+    /// The end bound is not `Unbounded`.
+    EndNotUnbounded,
+}
+
+impl fmt::Display for TryFromBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TryFromBoundsError::StartNotExcluded => f.write_str("start bound is not excluded"),
+            TryFromBoundsError::EndNotIncluded => f.write_str("end bound is not included"),
+            TryFromBoundsError::EndNotExcluded => f.write_str("end bound is not excluded"),
+            TryFromBoundsError::EndNotUnbounded => f.write_str("end bound is not unbounded"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TryFromBoundsError {}
+
+/// Converts an `(Excluded(start), Unbounded)` pair into a range.
+///
+/// # Errors
+/// Returns [`TryFromBoundsError::StartNotExcluded`] if the start bound is not `Excluded`, and
+/// [`TryFromBoundsError::EndNotUnbounded`] if the end bound is not `Unbounded`.
+///
+/// # Example
+/// ```
+/// use more_ranges::{RangeFromExclusive, TryFromBoundsError};
+/// use std::convert::TryFrom;
+/// use std::ops::Bound;
+///
+/// assert_eq!(
+///     RangeFromExclusive::<u32>::try_from((Bound::Excluded(1), Bound::Unbounded)),
+///     Ok(RangeFromExclusive { start: 1 })
+/// );
+/// assert_eq!(
+///     RangeFromExclusive::<u32>::try_from((Bound::Excluded(1), Bound::Included(4))),
+///     Err(TryFromBoundsError::EndNotUnbounded)
+/// );
+/// ```
+///
+/// [`TryFromBoundsError::StartNotExcluded`]: TryFromBoundsError::StartNotExcluded
+/// [`TryFromBoundsError::EndNotUnbounded`]: TryFromBoundsError::EndNotUnbounded
+impl<T> TryFrom<(Bound<T>, Bound<T>)> for RangeFromExclusive<T> {
+    type Error = TryFromBoundsError;
+
+    fn try_from(bounds: (Bound<T>, Bound<T>)) -> Result<Self, Self::Error> {
+        match bounds {
+            (Bound::Excluded(start), Bound::Unbounded) => Ok(RangeFromExclusive { start }),
+            (Bound::Excluded(_), _) => Err(TryFromBoundsError::EndNotUnbounded),
+            _ => Err(TryFromBoundsError::StartNotExcluded),
+        }
+    }
+}
+
+/// Converts an `(Excluded(start), Included(end))` pair into a range.
+///
+/// # Errors
+/// Returns [`TryFromBoundsError::StartNotExcluded`] if the start bound is not `Excluded`, and
+/// [`TryFromBoundsError::EndNotIncluded`] if the end bound is not `Included`.
+///
+/// # Example
+/// ```
+/// use more_ranges::{RangeFromExclusiveToInclusive, TryFromBoundsError};
+/// use std::convert::TryFrom;
+/// use std::ops::Bound;
+///
+/// assert_eq!(
+///     RangeFromExclusiveToInclusive::<u32>::try_from((Bound::Excluded(1), Bound::Included(4))),
+///     Ok(RangeFromExclusiveToInclusive { start: 1, end: 4 })
+/// );
+/// assert_eq!(
+///     RangeFromExclusiveToInclusive::<u32>::try_from((Bound::Excluded(1), Bound::Excluded(4))),
+///     Err(TryFromBoundsError::EndNotIncluded)
+/// );
+/// ```
+///
+/// [`TryFromBoundsError::StartNotExcluded`]: TryFromBoundsError::StartNotExcluded
+/// [`TryFromBoundsError::EndNotIncluded`]: TryFromBoundsError::EndNotIncluded
+impl<T> TryFrom<(Bound<T>, Bound<T>)> for RangeFromExclusiveToInclusive<T> {
+    type Error = TryFromBoundsError;
+
+    fn try_from(bounds: (Bound<T>, Bound<T>)) -> Result<Self, Self::Error> {
+        match bounds {
+            (Bound::Excluded(start), Bound::Included(end)) => {
+                Ok(RangeFromExclusiveToInclusive { start, end })
+            }
+            (Bound::Excluded(_), _) => Err(TryFromBoundsError::EndNotIncluded),
+            _ => Err(TryFromBoundsError::StartNotExcluded),
+        }
+    }
+}
+
+/// Converts an `(Excluded(start), Excluded(end))` pair into a range.
+///
+/// # Errors
+/// Returns [`TryFromBoundsError::StartNotExcluded`] if the start bound is not `Excluded`, and
+/// [`TryFromBoundsError::EndNotExcluded`] if the end bound is not `Excluded`.
+///
+/// # Example
+/// ```
+/// use more_ranges::{RangeFromExclusiveToExclusive, TryFromBoundsError};
+/// use std::convert::TryFrom;
+/// use std::ops::Bound;
+///
+/// assert_eq!(
+///     RangeFromExclusiveToExclusive::<u32>::try_from((Bound::Excluded(1), Bound::Excluded(4))),
+///     Ok(RangeFromExclusiveToExclusive { start: 1, end: 4 })
+/// );
+/// assert_eq!(
+///     RangeFromExclusiveToExclusive::<u32>::try_from((Bound::Included(1), Bound::Excluded(4))),
+///     Err(TryFromBoundsError::StartNotExcluded)
+/// );
+/// ```
+///
+/// [`TryFromBoundsError::StartNotExcluded`]: TryFromBoundsError::StartNotExcluded
+/// [`TryFromBoundsError::EndNotExcluded`]: TryFromBoundsError::EndNotExcluded
+impl<T> TryFrom<(Bound<T>, Bound<T>)> for RangeFromExclusiveToExclusive<T> {
+    type Error = TryFromBoundsError;
+
+    fn try_from(bounds: (Bound<T>, Bound<T>)) -> Result<Self, Self::Error> {
+        match bounds {
+            (Bound::Excluded(start), Bound::Excluded(end)) => {
+                Ok(RangeFromExclusiveToExclusive { start, end })
+            }
+            (Bound::Excluded(_), _) => Err(TryFromBoundsError::EndNotExcluded),
+            _ => Err(TryFromBoundsError::StartNotExcluded),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+    use core::ops::Bound::{self, Excluded, Included, Unbounded};
+    #[cfg(tuple_from_range)]
+    use std::collections::BTreeSet;
+    use std::string::ToString;
+    #[cfg(tuple_from_range)]
+    use std::vec::Vec;
+    use {
+        RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive,
+        TryFromBoundsError,
+    };
+
+    /// Returns each kind of bound, containing `value`.
+    fn bounds(value: u8) -> [Bound<u8>; 3] {
+        [Included(value), Excluded(value), Unbounded]
+    }
+
+    #[test]
+    fn range_from_exclusive_try_from() {
+        assert_ok_eq!(
+            RangeFromExclusive::try_from((Excluded(1), Unbounded)),
+            RangeFromExclusive { start: 1 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_try_from_every_kind() {
+        for &start in bounds(1).iter() {
+            for &end in bounds(4).iter() {
+                let result = RangeFromExclusive::try_from((start, end));
+                match (start, end) {
+                    (Excluded(start), Unbounded) => {
+                        assert_eq!(result, Ok(RangeFromExclusive { start }))
+                    }
+                    (Excluded(_), _) => {
+                        assert_eq!(result, Err(TryFromBoundsError::EndNotUnbounded))
+                    }
+                    _ => assert_eq!(result, Err(TryFromBoundsError::StartNotExcluded)),
+                }
+            }
+        }
+    }
+
+    #[cfg(tuple_from_range)]
+    #[test]
+    fn range_from_exclusive_bounds_round_trip() {
+        let set = (0..10).collect::<BTreeSet<u32>>();
+        let bounds = <(Bound<u32>, Bound<u32>)>::from(RangeFromExclusive { start: 6 });
+
+        assert_eq!(set.range(bounds).collect::<Vec<_>>(), [&7, &8, &9]);
+        assert_ok_eq!(
+            RangeFromExclusive::try_from(bounds),
+            RangeFromExclusive { start: 6 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_try_from() {
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive::try_from((Excluded(1), Included(4))),
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_try_from_every_kind() {
+        for &start in bounds(1).iter() {
+            for &end in bounds(4).iter() {
+                let result = RangeFromExclusiveToInclusive::try_from((start, end));
+                match (start, end) {
+                    (Excluded(start), Included(end)) => {
+                        assert_eq!(result, Ok(RangeFromExclusiveToInclusive { start, end }))
+                    }
+                    (Excluded(_), _) => {
+                        assert_eq!(result, Err(TryFromBoundsError::EndNotIncluded))
+                    }
+                    _ => assert_eq!(result, Err(TryFromBoundsError::StartNotExcluded)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_from() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::try_from((Excluded(1), Excluded(4))),
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_from_every_kind() {
+        for &start in bounds(1).iter() {
+            for &end in bounds(4).iter() {
+                let result = RangeFromExclusiveToExclusive::try_from((start, end));
+                match (start, end) {
+                    (Excluded(start), Excluded(end)) => {
+                        assert_eq!(result, Ok(RangeFromExclusiveToExclusive { start, end }))
+                    }
+                    (Excluded(_), _) => {
+                        assert_eq!(result, Err(TryFromBoundsError::EndNotExcluded))
+                    }
+                    _ => assert_eq!(result, Err(TryFromBoundsError::StartNotExcluded)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_from_half_open() {
+        // The half-open bounds of a PostgreSQL range, in the wrong direction.
+        assert_eq!(
+            RangeFromExclusiveToExclusive::<i32>::try_from((Included(1), Excluded(4))),
+            Err(TryFromBoundsError::StartNotExcluded)
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive::<i32>::try_from((Excluded(1), Included(4))),
+            Err(TryFromBoundsError::EndNotExcluded)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            TryFromBoundsError::StartNotExcluded.to_string(),
+            "start bound is not excluded"
+        );
+        assert_eq!(
+            TryFromBoundsError::EndNotIncluded.to_string(),
+            "end bound is not included"
+        );
+        assert_eq!(
+            TryFromBoundsError::EndNotExcluded.to_string(),
+            "end bound is not excluded"
+        );
+        assert_eq!(
+            TryFromBoundsError::EndNotUnbounded.to_string(),
+            "end bound is not unbounded"
+        );
+    }
+}
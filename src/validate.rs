@@ -0,0 +1,683 @@
+//! Validation of range invariants.
+
+use core::fmt;
+use iter_bounds::Integer;
+#[cfg(feature = "std")]
+use std::error::Error;
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+/// An error describing why a range is invalid or hazardous to use.
+///
+/// This is returned by the `validate()` and `validate_index()` methods on the range types, and by
+/// the `try_new()` constructors.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InvalidRange<Idx> {
+    /// The `start` bound is greater than the `end` bound.
+    Inverted {
+        /// The lower bound of the range (exclusive).
+        start: Idx,
+        /// The upper bound of the range.
+        end: Idx,
+    },
+    /// The `end` bound immediately follows the exclusive `start` bound, so the range contains no
+    /// values.
+    ///
+    /// This is informational: the range is well-formed, but is likely not what was intended.
+    EmptyAdjacent,
+    /// The `start` and `end` bounds are equal, so the range contains no values.
+    Empty,
+    /// The exclusive `start` bound is `usize::MAX`, so the first index of the range cannot be
+    /// computed without overflowing.
+    StartIsMax,
+    /// The inclusive `end` bound is `usize::MAX`, so the index one past the end of the range cannot
+    /// be computed without overflowing.
+    EndIsMax,
+}
+
+impl<Idx> fmt::Display for InvalidRange<Idx>
+where
+    Idx: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InvalidRange::Inverted { ref start, ref end } => write!(
+                f,
+                "range start bound {:?} is greater than end bound {:?}; swap the bounds, or use equal bounds for an empty range",
+                start, end
+            ),
+            InvalidRange::EmptyAdjacent => f.write_str(
+                "range contains no values because its exclusive end bound immediately follows its exclusive start bound; use equal bounds for an empty range, or widen the range",
+            ),
+            InvalidRange::Empty => f.write_str(
+                "range contains no values because its start and end bounds are equal; use a larger end bound",
+            ),
+            InvalidRange::StartIsMax => f.write_str(
+                "range start bound is `usize::MAX`, so its first index overflows; use a smaller start bound",
+            ),
+            InvalidRange::EndIsMax => f.write_str(
+                "range inclusive end bound is `usize::MAX`, so the index past its end overflows; use a smaller end bound",
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Idx> Error for InvalidRange<Idx> where Idx: fmt::Debug {}
+
+impl<Idx> RangeFromExclusive<Idx> {
+    /// Checks the invariants of the range.
+    ///
+    /// A `RangeFromExclusive` is always valid. This method is provided so that generic code can
+    /// validate all range types uniformly.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusive { start: 1 }.validate(), Ok(()));
+    /// ```
+    #[inline]
+    pub fn validate(&self) -> Result<(), InvalidRange<Idx>> {
+        Ok(())
+    }
+}
+
+impl RangeFromExclusive<usize> {
+    /// Checks the invariants of the range, along with the hazards of using it as an index.
+    ///
+    /// # Errors
+    /// Returns [`InvalidRange::StartIsMax`] if `start` is `usize::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{InvalidRange, RangeFromExclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusive { start: usize::max_value() }.validate_index(),
+    ///     Err(InvalidRange::StartIsMax)
+    /// );
+    /// ```
+    ///
+    /// [`InvalidRange::StartIsMax`]: InvalidRange::StartIsMax
+    pub fn validate_index(&self) -> Result<(), InvalidRange<usize>> {
+        self.validate()?;
+        if self.start == usize::max_value() {
+            return Err(InvalidRange::StartIsMax);
+        }
+        Ok(())
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: PartialOrd + Clone,
+{
+    /// Checks the invariants of the range.
+    ///
+    /// Note that a range with `start == end` is a valid empty range.
+    ///
+    /// # Errors
+    /// Returns [`InvalidRange::Inverted`] if `start > end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{InvalidRange, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 1, end: 4 }.validate(), Ok(()));
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 4, end: 1 }.validate(),
+    ///     Err(InvalidRange::Inverted { start: 4, end: 1 })
+    /// );
+    /// ```
+    ///
+    /// [`InvalidRange::Inverted`]: InvalidRange::Inverted
+    pub fn validate(&self) -> Result<(), InvalidRange<Idx>> {
+        if self.start > self.end {
+            return Err(InvalidRange::Inverted {
+                start: self.start.clone(),
+                end: self.end.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: PartialOrd,
+{
+    /// Creates a new range, checking that its bounds are not inverted.
+    ///
+    /// Note that `start == end` is allowed, creating a valid empty range.
+    ///
+    /// # Errors
+    /// Returns [`InvalidRange::Inverted`], containing the bounds, if `start > end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{InvalidRange, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive::try_new(1, 4),
+    ///     Ok(RangeFromExclusiveToInclusive { start: 1, end: 4 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive::try_new(4, 1),
+    ///     Err(InvalidRange::Inverted { start: 4, end: 1 })
+    /// );
+    /// ```
+    ///
+    /// [`InvalidRange::Inverted`]: InvalidRange::Inverted
+    pub fn try_new(start: Idx, end: Idx) -> Result<Self, InvalidRange<Idx>> {
+        if start > end {
+            return Err(InvalidRange::Inverted { start, end });
+        }
+        Ok(RangeFromExclusiveToInclusive { start, end })
+    }
+}
+
+impl RangeFromExclusiveToInclusive<usize> {
+    /// Checks the invariants of the range, along with the hazards of using it as an index.
+    ///
+    /// # Errors
+    /// Returns [`InvalidRange::Inverted`] if `start > end`, [`InvalidRange::StartIsMax`] if `start`
+    /// is `usize::MAX`, and [`InvalidRange::EndIsMax`] if `end` is `usize::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{InvalidRange, RangeFromExclusiveToInclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 0, end: usize::max_value() }.validate_index(),
+    ///     Err(InvalidRange::EndIsMax)
+    /// );
+    /// ```
+    ///
+    /// [`InvalidRange::Inverted`]: InvalidRange::Inverted
+    /// [`InvalidRange::StartIsMax`]: InvalidRange::StartIsMax
+    /// [`InvalidRange::EndIsMax`]: InvalidRange::EndIsMax
+    pub fn validate_index(&self) -> Result<(), InvalidRange<usize>> {
+        self.validate()?;
+        if self.start == usize::max_value() {
+            return Err(InvalidRange::StartIsMax);
+        }
+        if self.end == usize::max_value() {
+            return Err(InvalidRange::EndIsMax);
+        }
+        Ok(())
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: PartialOrd + Clone,
+{
+    /// Checks the invariants of the range.
+    ///
+    /// Note that a range with `start == end` is a valid empty range.
+    ///
+    /// # Errors
+    /// Returns [`InvalidRange::Inverted`] if `start > end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{InvalidRange, RangeFromExclusiveToExclusive};
+    ///
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 1, end: 4 }.validate(), Ok(()));
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 4, end: 1 }.validate(),
+    ///     Err(InvalidRange::Inverted { start: 4, end: 1 })
+    /// );
+    /// ```
+    ///
+    /// [`InvalidRange::Inverted`]: InvalidRange::Inverted
+    pub fn validate(&self) -> Result<(), InvalidRange<Idx>> {
+        if self.start > self.end {
+            return Err(InvalidRange::Inverted {
+                start: self.start.clone(),
+                end: self.end.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: PartialOrd,
+{
+    /// Creates a new range, checking that its bounds are not inverted.
+    ///
+    /// Note that `start == end` is allowed, creating a valid empty range. See
+    /// [`try_new_non_empty()`] for a constructor that rejects empty ranges.
+    ///
+    /// # Errors
+    /// Returns [`InvalidRange::Inverted`], containing the bounds, if `start > end`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{InvalidRange, RangeFromExclusiveToExclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive::try_new(1, 4),
+    ///     Ok(RangeFromExclusiveToExclusive { start: 1, end: 4 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive::try_new(4, 1),
+    ///     Err(InvalidRange::Inverted { start: 4, end: 1 })
+    /// );
+    /// ```
+    ///
+    /// [`try_new_non_empty()`]: RangeFromExclusiveToExclusive::try_new_non_empty
+    /// [`InvalidRange::Inverted`]: InvalidRange::Inverted
+    pub fn try_new(start: Idx, end: Idx) -> Result<Self, InvalidRange<Idx>> {
+        if start > end {
+            return Err(InvalidRange::Inverted { start, end });
+        }
+        Ok(RangeFromExclusiveToExclusive { start, end })
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Creates a new range, checking that it contains at least one value.
+    ///
+    /// Unlike [`try_new()`], this rejects both `start == end` and `end == start + 1`, since
+    /// neither range contains any values.
+    ///
+    /// # Errors
+    /// Returns [`InvalidRange::Inverted`] if `start > end`, [`InvalidRange::Empty`] if
+    /// `start == end`, and [`InvalidRange::EmptyAdjacent`] if `end == start + 1`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{InvalidRange, RangeFromExclusiveToExclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive::try_new_non_empty(1, 3),
+    ///     Ok(RangeFromExclusiveToExclusive { start: 1, end: 3 })
+    /// );
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive::try_new_non_empty(1, 2),
+    ///     Err(InvalidRange::EmptyAdjacent)
+    /// );
+    /// ```
+    ///
+    /// [`try_new()`]: RangeFromExclusiveToExclusive::try_new
+    /// [`InvalidRange::Inverted`]: InvalidRange::Inverted
+    /// [`InvalidRange::Empty`]: InvalidRange::Empty
+    /// [`InvalidRange::EmptyAdjacent`]: InvalidRange::EmptyAdjacent
+    pub fn try_new_non_empty(start: Idx, end: Idx) -> Result<Self, InvalidRange<Idx>> {
+        if start > end {
+            return Err(InvalidRange::Inverted { start, end });
+        }
+        if start == end {
+            return Err(InvalidRange::Empty);
+        }
+        // `start < end`, so the successor of `start` cannot overflow.
+        if start.checked_succ() == Some(end) {
+            return Err(InvalidRange::EmptyAdjacent);
+        }
+        Ok(RangeFromExclusiveToExclusive { start, end })
+    }
+}
+
+impl RangeFromExclusiveToExclusive<usize> {
+    /// Checks the invariants of the range, along with the hazards of using it as an index.
+    ///
+    /// # Errors
+    /// Returns [`InvalidRange::Inverted`] if `start > end`, [`InvalidRange::StartIsMax`] if `start`
+    /// is `usize::MAX`, and [`InvalidRange::EmptyAdjacent`] if `end == start + 1`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::{InvalidRange, RangeFromExclusiveToExclusive};
+    ///
+    /// assert_eq!(
+    ///     RangeFromExclusiveToExclusive { start: 1, end: 2 }.validate_index(),
+    ///     Err(InvalidRange::EmptyAdjacent)
+    /// );
+    /// ```
+    ///
+    /// [`InvalidRange::Inverted`]: InvalidRange::Inverted
+    /// [`InvalidRange::StartIsMax`]: InvalidRange::StartIsMax
+    /// [`InvalidRange::EmptyAdjacent`]: InvalidRange::EmptyAdjacent
+    pub fn validate_index(&self) -> Result<(), InvalidRange<usize>> {
+        self.validate()?;
+        if self.start == usize::max_value() {
+            return Err(InvalidRange::StartIsMax);
+        }
+        if self.start + 1 == self.end {
+            return Err(InvalidRange::EmptyAdjacent);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString;
+    use {
+        InvalidRange, RangeFromExclusive, RangeFromExclusiveToExclusive,
+        RangeFromExclusiveToInclusive,
+    };
+
+    #[test]
+    fn range_from_exclusive_validate() {
+        assert_ok!(RangeFromExclusive { start: 1.5 }.validate());
+    }
+
+    #[test]
+    fn range_from_exclusive_validate_index() {
+        assert_ok!(RangeFromExclusive { start: 1 }.validate_index());
+    }
+
+    #[test]
+    fn range_from_exclusive_validate_index_start_is_max() {
+        assert_matches!(
+            RangeFromExclusive {
+                start: usize::max_value()
+            }
+            .validate_index(),
+            Err(InvalidRange::StartIsMax)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_validate() {
+        assert_ok!(RangeFromExclusiveToInclusive { start: 1, end: 4 }.validate());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_validate_empty() {
+        assert_ok!(RangeFromExclusiveToInclusive { start: 4, end: 4 }.validate());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_validate_inverted() {
+        assert_matches!(
+            RangeFromExclusiveToInclusive {
+                start: 4.0,
+                end: 1.0
+            }
+            .validate(),
+            Err(InvalidRange::Inverted { start, end }) if start == 4.0 && end == 1.0
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_validate_index() {
+        assert_ok!(RangeFromExclusiveToInclusive { start: 1, end: 4 }.validate_index());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_validate_index_inverted() {
+        assert_matches!(
+            RangeFromExclusiveToInclusive {
+                start: usize::max_value(),
+                end: 1
+            }
+            .validate_index(),
+            Err(InvalidRange::Inverted { start, end: 1 }) if start == usize::max_value()
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_validate_index_start_is_max() {
+        assert_matches!(
+            RangeFromExclusiveToInclusive {
+                start: usize::max_value(),
+                end: usize::max_value()
+            }
+            .validate_index(),
+            Err(InvalidRange::StartIsMax)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_validate_index_end_is_max() {
+        assert_matches!(
+            RangeFromExclusiveToInclusive {
+                start: 1,
+                end: usize::max_value()
+            }
+            .validate_index(),
+            Err(InvalidRange::EndIsMax)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_try_new() {
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive::try_new(1, 4),
+            RangeFromExclusiveToInclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_try_new_empty() {
+        assert_ok_eq!(
+            RangeFromExclusiveToInclusive::try_new('a', 'a'),
+            RangeFromExclusiveToInclusive {
+                start: 'a',
+                end: 'a'
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_try_new_inverted() {
+        assert_matches!(
+            RangeFromExclusiveToInclusive::try_new(4.5, 1.5),
+            Err(InvalidRange::Inverted { start, end }) if start == 4.5 && end == 1.5
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_try_new_unordered() {
+        assert_ok!(RangeFromExclusiveToInclusive::try_new(
+            ::core::f64::NAN,
+            1.0
+        ));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate() {
+        assert_ok!(RangeFromExclusiveToExclusive { start: 1, end: 4 }.validate());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate_empty() {
+        assert_ok!(RangeFromExclusiveToExclusive { start: 4, end: 4 }.validate());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate_adjacent() {
+        assert_ok!(RangeFromExclusiveToExclusive { start: 4, end: 5 }.validate());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate_inverted() {
+        assert_matches!(
+            RangeFromExclusiveToExclusive {
+                start: 'b',
+                end: 'a'
+            }
+            .validate(),
+            Err(InvalidRange::Inverted {
+                start: 'b',
+                end: 'a'
+            })
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate_index() {
+        assert_ok!(RangeFromExclusiveToExclusive { start: 1, end: 4 }.validate_index());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate_index_inverted() {
+        assert_matches!(
+            RangeFromExclusiveToExclusive { start: 4, end: 1 }.validate_index(),
+            Err(InvalidRange::Inverted { start: 4, end: 1 })
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate_index_start_is_max() {
+        assert_matches!(
+            RangeFromExclusiveToExclusive {
+                start: usize::max_value(),
+                end: usize::max_value()
+            }
+            .validate_index(),
+            Err(InvalidRange::StartIsMax)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate_index_end_is_max() {
+        assert_ok!(RangeFromExclusiveToExclusive {
+            start: 1,
+            end: usize::max_value()
+        }
+        .validate_index());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate_index_empty_adjacent() {
+        assert_matches!(
+            RangeFromExclusiveToExclusive { start: 4, end: 5 }.validate_index(),
+            Err(InvalidRange::EmptyAdjacent)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_validate_index_empty() {
+        assert_ok!(RangeFromExclusiveToExclusive { start: 4, end: 4 }.validate_index());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_new() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::try_new(1, 4),
+            RangeFromExclusiveToExclusive { start: 1, end: 4 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_new_empty() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::try_new(4.0, 4.0),
+            RangeFromExclusiveToExclusive {
+                start: 4.0,
+                end: 4.0
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_new_adjacent() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::try_new(4, 5),
+            RangeFromExclusiveToExclusive { start: 4, end: 5 }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_new_inverted() {
+        assert_matches!(
+            RangeFromExclusiveToExclusive::try_new('b', 'a'),
+            Err(InvalidRange::Inverted {
+                start: 'b',
+                end: 'a'
+            })
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_new_non_empty() {
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::try_new_non_empty(4, 6),
+            RangeFromExclusiveToExclusive { start: 4, end: 6 }
+        );
+        assert_ok_eq!(
+            RangeFromExclusiveToExclusive::try_new_non_empty(i8::min_value(), i8::max_value()),
+            RangeFromExclusiveToExclusive {
+                start: i8::min_value(),
+                end: i8::max_value()
+            }
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_new_non_empty_adjacent() {
+        assert_matches!(
+            RangeFromExclusiveToExclusive::try_new_non_empty(4, 5),
+            Err(InvalidRange::EmptyAdjacent)
+        );
+        assert_matches!(
+            RangeFromExclusiveToExclusive::try_new_non_empty(u8::max_value() - 1, u8::max_value()),
+            Err(InvalidRange::EmptyAdjacent)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_new_non_empty_empty() {
+        assert_matches!(
+            RangeFromExclusiveToExclusive::try_new_non_empty(u64::max_value(), u64::max_value()),
+            Err(InvalidRange::Empty)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_try_new_non_empty_inverted() {
+        assert_matches!(
+            RangeFromExclusiveToExclusive::try_new_non_empty(4, -1),
+            Err(InvalidRange::Inverted { start: 4, end: -1 })
+        );
+    }
+
+    #[test]
+    fn display_inverted() {
+        assert_eq!(
+            InvalidRange::Inverted { start: 4, end: 1 }.to_string(),
+            "range start bound 4 is greater than end bound 1; swap the bounds, or use equal bounds for an empty range"
+        );
+    }
+
+    #[test]
+    fn display_empty_adjacent() {
+        assert_eq!(
+            InvalidRange::EmptyAdjacent::<usize>.to_string(),
+            "range contains no values because its exclusive end bound immediately follows its exclusive start bound; use equal bounds for an empty range, or widen the range"
+        );
+    }
+
+    #[test]
+    fn display_empty() {
+        assert_eq!(
+            InvalidRange::Empty::<usize>.to_string(),
+            "range contains no values because its start and end bounds are equal; use a larger end bound"
+        );
+    }
+
+    #[test]
+    fn display_start_is_max() {
+        assert_eq!(
+            InvalidRange::StartIsMax::<usize>.to_string(),
+            "range start bound is `usize::MAX`, so its first index overflows; use a smaller start bound"
+        );
+    }
+
+    #[test]
+    fn display_end_is_max() {
+        assert_eq!(
+            InvalidRange::EndIsMax::<usize>.to_string(),
+            "range inclusive end bound is `usize::MAX`, so the index past its end overflows; use a smaller end bound"
+        );
+    }
+}
@@ -0,0 +1,886 @@
+//! Individual values contained in the range types over primitive integers.
+//!
+//! These are computed from the bounds alone, without iterating over the values of the range.
+
+use iter_bounds::Integer;
+use steps::{char_pred, char_succ, StepsBetween};
+use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+impl<Idx> RangeFromExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Returns the smallest value in the range, `start + 1`.
+    ///
+    /// Returns `None` if `start` is the maximum value of `Idx`, in which case the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusive { start: 1 }.first_value(), Some(2));
+    /// assert_eq!(RangeFromExclusive { start: u8::max_value() }.first_value(), None);
+    /// ```
+    #[inline]
+    pub fn first_value(&self) -> Option<Idx> {
+        self.start.checked_succ()
+    }
+
+    /// Returns the `n`th value in the range, counting from zero, without consuming the range.
+    ///
+    /// Returns `None` if the value overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusive { start: 1 }.nth_value(0), Some(2));
+    /// assert_eq!(RangeFromExclusive { start: 1 }.nth_value(3), Some(5));
+    /// assert_eq!(RangeFromExclusive { start: 250u8 }.nth_value(5), None);
+    /// ```
+    #[inline]
+    pub fn nth_value(&self, n: usize) -> Option<Idx> {
+        self.first_value()?.checked_forward(n as u128)
+    }
+
+    /// Returns the offset of `value` from the first value in the range, the inverse of
+    /// [`nth_value()`].
+    ///
+    /// Returns `None` if `value` is not contained in the range, or if the offset does not fit in a
+    /// `usize`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusive;
+    ///
+    /// let range = RangeFromExclusive { start: 1 };
+    ///
+    /// assert_eq!(range.position_of(2), Some(0));
+    /// assert_eq!(range.position_of(5), Some(3));
+    /// assert_eq!(range.position_of(1), None);
+    /// ```
+    ///
+    /// [`nth_value()`]: RangeFromExclusive::nth_value
+    #[inline]
+    pub fn position_of(&self, value: Idx) -> Option<usize> {
+        if value > self.start {
+            // `value` is contained, so the first value exists and is at most `value`.
+            StepsBetween::steps_between(&self.first_value()?, &value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Returns the smallest value in the range, `start + 1`.
+    ///
+    /// Returns `None` if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 1, end: 4 }.first_value(), Some(2));
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 4, end: 4 }.first_value(), None);
+    /// ```
+    #[inline]
+    pub fn first_value(&self) -> Option<Idx> {
+        if self.start < self.end {
+            // `start < end`, so incrementing `start` cannot overflow.
+            self.start.checked_succ()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the largest value in the range, `end`.
+    ///
+    /// Returns `None` if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 1, end: 4 }.last_value(), Some(4));
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 4, end: 4 }.last_value(), None);
+    /// ```
+    #[inline]
+    pub fn last_value(&self) -> Option<Idx> {
+        if self.start < self.end {
+            Some(self.end)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `n`th value in the range, counting from zero, without consuming the range.
+    ///
+    /// Returns `None` if the range contains `n` or fewer values.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.nth_value(0), Some(2));
+    /// assert_eq!(range.nth_value(2), Some(4));
+    /// assert_eq!(range.nth_value(3), None);
+    /// ```
+    #[inline]
+    pub fn nth_value(&self, n: usize) -> Option<Idx> {
+        match self.first_value()?.checked_forward(n as u128) {
+            Some(value) if value <= self.end => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the middle value of the range, or `None` if the range is empty.
+    ///
+    /// If the range contains an even number of values, this is the lower of the two middle values.
+    /// It is computed from the first and last values without overflowing.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 0, end: 5 }.midpoint(), Some(3));
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 0, end: 4 }.midpoint(), Some(2));
+    /// assert_eq!(
+    ///     RangeFromExclusiveToInclusive { start: 250u8, end: 255 }.midpoint(),
+    ///     Some(253)
+    /// );
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 4, end: 4 }.midpoint(), None);
+    /// ```
+    #[inline]
+    pub fn midpoint(&self) -> Option<Idx> {
+        let first = self.first_value()?;
+        let last = self.last_value()?;
+        // The range is not empty, so `first <= last`.
+        let steps = StepsBetween::steps_between_wide(&first, &last)?;
+        first.checked_forward(steps / 2)
+    }
+
+    /// Clamps `value` to the range.
+    ///
+    /// Returns `value` if it is contained in the range, the first value `start + 1` if it is below
+    /// the range, and `end` if it is above the range. Returns `None` if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.clamp(1), Some(2));
+    /// assert_eq!(range.clamp(3), Some(3));
+    /// assert_eq!(range.clamp(9), Some(4));
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 4, end: 4 }.clamp(4), None);
+    /// ```
+    #[inline]
+    pub fn clamp(&self, value: Idx) -> Option<Idx> {
+        let first = self.first_value()?;
+        Some(value.max(first).min(self.end))
+    }
+
+    /// Returns the offset of `value` from the first value in the range, the inverse of
+    /// [`nth_value()`].
+    ///
+    /// Returns `None` if `value` is not contained in the range, or if the offset does not fit in a
+    /// `usize`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.position_of(2), Some(0));
+    /// assert_eq!(range.position_of(4), Some(2));
+    /// assert_eq!(range.position_of(5), None);
+    /// ```
+    ///
+    /// [`nth_value()`]: RangeFromExclusiveToInclusive::nth_value
+    #[inline]
+    pub fn position_of(&self, value: Idx) -> Option<usize> {
+        if value > self.start && value <= self.end {
+            // `value` is contained, so the first value exists and is at most `value`.
+            StepsBetween::steps_between(&self.first_value()?, &value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Integer,
+{
+    /// Returns the smallest value in the range, `start + 1`.
+    ///
+    /// Returns `None` if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 1, end: 4 }.first_value(), Some(2));
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 3, end: 4 }.first_value(), None);
+    /// ```
+    #[inline]
+    pub fn first_value(&self) -> Option<Idx> {
+        match self.start.checked_succ() {
+            Some(first) if first < self.end => Some(first),
+            _ => None,
+        }
+    }
+
+    /// Returns the largest value in the range, `end - 1`.
+    ///
+    /// Returns `None` if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 1, end: 4 }.last_value(), Some(3));
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 3, end: 4 }.last_value(), None);
+    /// ```
+    #[inline]
+    pub fn last_value(&self) -> Option<Idx> {
+        match self.end.checked_pred() {
+            Some(last) if last > self.start => Some(last),
+            _ => None,
+        }
+    }
+
+    /// Returns the `n`th value in the range, counting from zero, without consuming the range.
+    ///
+    /// Returns `None` if the range contains `n` or fewer values.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.nth_value(0), Some(2));
+    /// assert_eq!(range.nth_value(1), Some(3));
+    /// assert_eq!(range.nth_value(2), None);
+    /// ```
+    #[inline]
+    pub fn nth_value(&self, n: usize) -> Option<Idx> {
+        match self.first_value()?.checked_forward(n as u128) {
+            Some(value) if value < self.end => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the middle value of the range, or `None` if the range is empty.
+    ///
+    /// If the range contains an even number of values, this is the lower of the two middle values.
+    /// It is computed from the first and last values without overflowing.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 0, end: 6 }.midpoint(), Some(3));
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 0, end: 5 }.midpoint(), Some(2));
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 4, end: 5 }.midpoint(), None);
+    /// ```
+    #[inline]
+    pub fn midpoint(&self) -> Option<Idx> {
+        let first = self.first_value()?;
+        let last = self.last_value()?;
+        // The range is not empty, so `first <= last`.
+        let steps = StepsBetween::steps_between_wide(&first, &last)?;
+        first.checked_forward(steps / 2)
+    }
+
+    /// Clamps `value` to the range.
+    ///
+    /// Returns `value` if it is contained in the range, the first value `start + 1` if it is below
+    /// the range, and the last value `end - 1` if it is above the range. Returns `None` if the
+    /// range is empty, which includes a range with adjacent bounds such as `{5, 6}`.
+    ///
+    /// Unlike [`RangeFromExclusiveToInclusive::clamp()`], a value above the range is not clamped to
+    /// `end`, since `end` is not contained in the range.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.clamp(1), Some(2));
+    /// assert_eq!(range.clamp(3), Some(3));
+    /// assert_eq!(range.clamp(9), Some(3));
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 5, end: 6 }.clamp(5), None);
+    /// ```
+    ///
+    /// [`RangeFromExclusiveToInclusive::clamp()`]: RangeFromExclusiveToInclusive::clamp
+    #[inline]
+    pub fn clamp(&self, value: Idx) -> Option<Idx> {
+        let first = self.first_value()?;
+        let last = self.last_value()?;
+        Some(value.max(first).min(last))
+    }
+
+    /// Returns the offset of `value` from the first value in the range, the inverse of
+    /// [`nth_value()`].
+    ///
+    /// Returns `None` if `value` is not contained in the range, or if the offset does not fit in a
+    /// `usize`.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 1, end: 4 };
+    ///
+    /// assert_eq!(range.position_of(2), Some(0));
+    /// assert_eq!(range.position_of(3), Some(1));
+    /// assert_eq!(range.position_of(4), None);
+    /// ```
+    ///
+    /// [`nth_value()`]: RangeFromExclusiveToExclusive::nth_value
+    #[inline]
+    pub fn position_of(&self, value: Idx) -> Option<usize> {
+        if value > self.start && value < self.end {
+            // `value` is contained, so the first value exists and is at most `value`.
+            StepsBetween::steps_between(&self.first_value()?, &value)
+        } else {
+            None
+        }
+    }
+}
+
+impl RangeFromExclusiveToInclusive<char> {
+    /// Clamps `value` to the range.
+    ///
+    /// This is the same as the `clamp()` method for the primitive integers, and skips the surrogate
+    /// code points `U+D800` to `U+DFFF`, which are not valid `char`s.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// let range = RangeFromExclusiveToInclusive { start: 'a', end: 'e' };
+    ///
+    /// assert_eq!(range.clamp('a'), Some('b'));
+    /// assert_eq!(range.clamp('c'), Some('c'));
+    /// assert_eq!(range.clamp('z'), Some('e'));
+    /// ```
+    #[inline]
+    pub fn clamp(&self, value: char) -> Option<char> {
+        let first = char_succ(self.start)?;
+        if first > self.end {
+            None
+        } else {
+            Some(value.max(first).min(self.end))
+        }
+    }
+}
+
+impl RangeFromExclusiveToExclusive<char> {
+    /// Clamps `value` to the range.
+    ///
+    /// This is the same as the `clamp()` method for the primitive integers, and skips the surrogate
+    /// code points `U+D800` to `U+DFFF`, which are not valid `char`s.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// let range = RangeFromExclusiveToExclusive { start: 'a', end: 'e' };
+    ///
+    /// assert_eq!(range.clamp('a'), Some('b'));
+    /// assert_eq!(range.clamp('c'), Some('c'));
+    /// assert_eq!(range.clamp('z'), Some('d'));
+    /// ```
+    #[inline]
+    pub fn clamp(&self, value: char) -> Option<char> {
+        let first = char_succ(self.start)?;
+        let last = char_pred(self.end)?;
+        if first > last {
+            None
+        } else {
+            Some(value.max(first).min(last))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iter_bounds::iter_bounds;
+    use {RangeFromExclusive, RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[test]
+    fn range_from_exclusive_first_value() {
+        assert_some_eq!(RangeFromExclusive { start: -1i8 }.first_value(), 0);
+        assert_some_eq!(RangeFromExclusive { start: 126i8 }.first_value(), 127);
+        assert_none!(RangeFromExclusive { start: 127i8 }.first_value());
+    }
+
+    #[test]
+    fn range_from_exclusive_nth_value() {
+        let range = RangeFromExclusive {
+            start: i8::min_value(),
+        };
+
+        for n in 0..255 {
+            assert_eq!(
+                range.nth_value(n),
+                (i8::min_value() + 1..=i8::max_value()).nth(n)
+            );
+        }
+        assert_none!(range.nth_value(255));
+        assert_none!(range.nth_value(usize::max_value()));
+    }
+
+    #[test]
+    fn range_from_exclusive_nth_value_wide() {
+        assert_some_eq!(
+            RangeFromExclusive { start: 0u128 }.nth_value(usize::max_value()),
+            usize::max_value() as u128 + 1
+        );
+        assert_some_eq!(
+            RangeFromExclusive {
+                start: u128::max_value() - 2
+            }
+            .nth_value(1),
+            u128::max_value()
+        );
+        assert_none!(RangeFromExclusive {
+            start: u128::max_value() - 2
+        }
+        .nth_value(2));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_nth_value_agrees_with_iteration() {
+        for start in i8::min_value()..=i8::max_value() {
+            for &end in [-128, -127, -1, 0, 1, 126, 127].iter() {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                for n in 0..260 {
+                    assert_eq!(range.nth_value(n), iter_bounds(range).nth(n));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_nth_value_agrees_with_iteration() {
+        for start in i8::min_value()..=i8::max_value() {
+            for &end in [-128, -127, -1, 0, 1, 126, 127].iter() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                for n in 0..260 {
+                    assert_eq!(range.nth_value(n), iter_bounds(range).nth(n));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_position_of() {
+        let range = RangeFromExclusive {
+            start: i8::min_value(),
+        };
+
+        assert_none!(range.position_of(i8::min_value()));
+        for value in i8::min_value() + 1..=i8::max_value() {
+            let position = assert_some!(range.position_of(value));
+            assert_some_eq!(range.nth_value(position), value);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_position_of_overflow() {
+        let range = RangeFromExclusive { start: 0u128 };
+
+        assert_some_eq!(
+            range.position_of(usize::max_value() as u128 + 1),
+            usize::max_value()
+        );
+        assert_none!(range.position_of(usize::max_value() as u128 + 2));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_position_of_agrees_with_iteration() {
+        for start in i8::min_value()..=i8::max_value() {
+            for &end in [-128, -127, -1, 0, 1, 126, 127].iter() {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                for value in i8::min_value()..=i8::max_value() {
+                    assert_eq!(
+                        range.position_of(value),
+                        iter_bounds(range).position(|x| x == value)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_position_of_agrees_with_iteration() {
+        for start in i8::min_value()..=i8::max_value() {
+            for &end in [-128, -127, -1, 0, 1, 126, 127].iter() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                for value in i8::min_value()..=i8::max_value() {
+                    assert_eq!(
+                        range.position_of(value),
+                        iter_bounds(range).position(|x| x == value)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_position_of_u64() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 1u64 << 40,
+            end: (1 << 40) + 100,
+        };
+
+        assert_some_eq!(range.position_of((1 << 40) + 1), 0);
+        assert_some_eq!(range.position_of((1 << 40) + 99), 98);
+        assert_none!(range.position_of(1 << 40));
+        assert_none!(range.position_of((1 << 40) + 100));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_midpoint_agrees_with_iteration() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in i8::min_value()..=i8::max_value() {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                let len = iter_bounds(range).count();
+                assert_eq!(
+                    range.midpoint(),
+                    iter_bounds(range).nth(len.saturating_sub(1) / 2)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_midpoint_wide() {
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: i128::min_value(),
+                end: i128::max_value()
+            }
+            .midpoint(),
+            0
+        );
+        assert_some_eq!(
+            RangeFromExclusiveToInclusive {
+                start: u128::max_value() - 3,
+                end: u128::max_value()
+            }
+            .midpoint(),
+            u128::max_value() - 1
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_clamp() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 10u8,
+            end: 20,
+        };
+
+        assert_some_eq!(range.clamp(0), 11);
+        assert_some_eq!(range.clamp(10), 11);
+        assert_some_eq!(range.clamp(11), 11);
+        assert_some_eq!(range.clamp(15), 15);
+        assert_some_eq!(range.clamp(20), 20);
+        assert_some_eq!(range.clamp(21), 20);
+        assert_some_eq!(range.clamp(u8::max_value()), 20);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_clamp_single() {
+        let range = RangeFromExclusiveToInclusive {
+            start: -1i8,
+            end: 0,
+        };
+
+        for value in i8::min_value()..=i8::max_value() {
+            assert_some_eq!(range.clamp(value), 0);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_clamp_empty() {
+        for &(start, end) in [(5u8, 5), (6, 5), (255, 255)].iter() {
+            let range = RangeFromExclusiveToInclusive { start, end };
+            for value in 0..=u8::max_value() {
+                assert_none!(range.clamp(value));
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_clamp_is_contained() {
+        for start in 0..12u8 {
+            for end in 0..12u8 {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                for value in 0..12u8 {
+                    match range.clamp(value) {
+                        Some(clamped) => {
+                            assert!(range.contains(&clamped));
+                            if range.contains(&value) {
+                                assert_eq!(clamped, value);
+                            }
+                        }
+                        None => assert!(range.is_empty()),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_midpoint_agrees_with_iteration() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in i8::min_value()..=i8::max_value() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                let len = iter_bounds(range).count();
+                assert_eq!(
+                    range.midpoint(),
+                    iter_bounds(range).nth(len.saturating_sub(1) / 2)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_midpoint_bisection() {
+        // Repeatedly bisecting always makes progress until a single value remains.
+        let mut range = RangeFromExclusiveToExclusive {
+            start: 0u64,
+            end: u64::max_value(),
+        };
+        let target = 0x1234_5678_9ABC_DEF0;
+        let mut steps = 0;
+
+        while let Some(mid) = range.midpoint() {
+            if range.first_value() == range.last_value() {
+                break;
+            }
+            if target <= mid {
+                range.end = mid + 1;
+            } else {
+                range.start = mid;
+            }
+            steps += 1;
+        }
+
+        assert_some_eq!(range.midpoint(), target);
+        assert!(steps <= 64);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_clamp() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 10u8,
+            end: 20,
+        };
+
+        assert_some_eq!(range.clamp(0), 11);
+        assert_some_eq!(range.clamp(10), 11);
+        assert_some_eq!(range.clamp(11), 11);
+        assert_some_eq!(range.clamp(15), 15);
+        assert_some_eq!(range.clamp(19), 19);
+        assert_some_eq!(range.clamp(20), 19);
+        assert_some_eq!(range.clamp(u8::max_value()), 19);
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_clamp_single() {
+        let range = RangeFromExclusiveToExclusive { start: 5u8, end: 7 };
+
+        for value in 0..=u8::max_value() {
+            assert_some_eq!(range.clamp(value), 6);
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_clamp_empty() {
+        for &(start, end) in [(5u8, 6), (5, 5), (6, 5), (254, 255), (0, 0)].iter() {
+            let range = RangeFromExclusiveToExclusive { start, end };
+            for value in 0..=u8::max_value() {
+                assert_none!(range.clamp(value));
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_clamp_is_contained() {
+        for start in 0..12u8 {
+            for end in 0..12u8 {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                for value in 0..12u8 {
+                    match range.clamp(value) {
+                        Some(clamped) => {
+                            assert!(range.contains(&clamped));
+                            if range.contains(&value) {
+                                assert_eq!(clamped, value);
+                            }
+                        }
+                        None => assert!(range.is_empty_discrete()),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_clamp_char() {
+        let range = RangeFromExclusiveToExclusive {
+            start: 'a',
+            end: 'e',
+        };
+
+        assert_some_eq!(range.clamp('\0'), 'b');
+        assert_some_eq!(range.clamp('a'), 'b');
+        assert_some_eq!(range.clamp('c'), 'c');
+        assert_some_eq!(range.clamp('e'), 'd');
+        assert_some_eq!(range.clamp(::core::char::MAX), 'd');
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: 'a',
+            end: 'b'
+        }
+        .clamp('a'));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_clamp_char() {
+        let range = RangeFromExclusiveToInclusive {
+            start: 'a',
+            end: 'e',
+        };
+
+        assert_some_eq!(range.clamp('\0'), 'b');
+        assert_some_eq!(range.clamp('a'), 'b');
+        assert_some_eq!(range.clamp('e'), 'e');
+        assert_some_eq!(range.clamp(::core::char::MAX), 'e');
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: 'a',
+            end: 'a'
+        }
+        .clamp('a'));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_clamp_char_surrogates() {
+        let range = RangeFromExclusiveToInclusive {
+            start: '\u{D7FF}',
+            end: '\u{E001}',
+        };
+
+        assert_some_eq!(range.clamp('\0'), '\u{E000}');
+        assert_none!(RangeFromExclusiveToInclusive {
+            start: ::core::char::MAX,
+            end: ::core::char::MAX
+        }
+        .clamp('a'));
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_clamp_char_surrogates() {
+        // The surrogate code points lie between the bounds, but are not contained.
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: '\u{D7FF}',
+            end: '\u{E000}'
+        }
+        .clamp('a'));
+        let range = RangeFromExclusiveToExclusive {
+            start: '\u{D7FE}',
+            end: '\u{E001}',
+        };
+        assert_some_eq!(range.clamp('\0'), '\u{D7FF}');
+        assert_some_eq!(range.clamp(::core::char::MAX), '\u{E000}');
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_clamp_char_limits() {
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: ::core::char::MAX,
+            end: ::core::char::MAX
+        }
+        .clamp('a'));
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: '\0',
+            end: '\0'
+        }
+        .clamp('a'));
+        assert_some_eq!(
+            RangeFromExclusiveToExclusive {
+                start: '\0',
+                end: ::core::char::MAX
+            }
+            .clamp(::core::char::MAX),
+            '\u{10FFFE}'
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_values_agree_with_iteration() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in i8::min_value()..=i8::max_value() {
+                let range = RangeFromExclusiveToInclusive { start, end };
+                assert_eq!(range.first_value(), iter_bounds(range).next());
+                assert_eq!(range.last_value(), iter_bounds(range).next_back());
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_values_at_limits() {
+        let range = RangeFromExclusiveToInclusive {
+            start: u8::max_value() - 1,
+            end: u8::max_value(),
+        };
+
+        assert_some_eq!(range.first_value(), u8::max_value());
+        assert_some_eq!(range.last_value(), u8::max_value());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_values_agree_with_iteration() {
+        for start in i8::min_value()..=i8::max_value() {
+            for end in i8::min_value()..=i8::max_value() {
+                let range = RangeFromExclusiveToExclusive { start, end };
+                assert_eq!(range.first_value(), iter_bounds(range).next());
+                assert_eq!(range.last_value(), iter_bounds(range).next_back());
+            }
+        }
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_values_at_limits() {
+        let range = RangeFromExclusiveToExclusive {
+            start: u8::min_value(),
+            end: u8::max_value(),
+        };
+
+        assert_some_eq!(range.first_value(), 1);
+        assert_some_eq!(range.last_value(), u8::max_value() - 1);
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: u8::max_value(),
+            end: u8::max_value()
+        }
+        .first_value());
+        assert_none!(RangeFromExclusiveToExclusive {
+            start: u8::min_value(),
+            end: u8::min_value()
+        }
+        .last_value());
+    }
+}
@@ -0,0 +1,350 @@
+//! Measuring the distance between the bounds of the bounded range types.
+
+use core::time::Duration;
+use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An index type whose ranges have an arithmetic width.
+///
+/// This is implemented for the primitive integers and floats, and for [`Duration`]. It cannot be
+/// implemented outside of this crate.
+///
+/// [`Duration`]: core::time::Duration
+pub trait Width: sealed::Sealed + Copy + PartialOrd {
+    /// Returns `end - start`, given that `start` is not greater than `end`.
+    fn width_between(start: Self, end: Self) -> Self;
+}
+
+macro_rules! impl_width {
+    ($($t:ident),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Width for $t {
+                #[inline]
+                fn width_between(start: Self, end: Self) -> Self {
+                    end.saturating_sub(start)
+                }
+            }
+        )*
+    };
+}
+
+impl_width!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl sealed::Sealed for f32 {}
+
+impl Width for f32 {
+    #[inline]
+    fn width_between(start: Self, end: Self) -> Self {
+        end - start
+    }
+}
+
+impl sealed::Sealed for f64 {}
+
+impl Width for f64 {
+    #[inline]
+    fn width_between(start: Self, end: Self) -> Self {
+        end - start
+    }
+}
+
+impl sealed::Sealed for Duration {}
+
+impl Width for Duration {
+    #[inline]
+    fn width_between(start: Self, end: Self) -> Self {
+        end - start
+    }
+}
+
+impl<Idx> RangeFromExclusiveToInclusive<Idx>
+where
+    Idx: Width,
+{
+    /// Returns the distance `end - start` between the bounds.
+    ///
+    /// This is the arithmetic width of the range, not the number of values it contains. A range
+    /// whose `end` is below its `start` reports a zero width rather than a negative one.
+    ///
+    /// For the primitive integers, the width saturates at the maximum value of the type, which
+    /// only happens for signed ranges spanning more than half of the type. For floats, the width
+    /// is `NaN` if either bound is `NaN`, or if both bounds are the same infinity.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToInclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 0.5, end: 2.0 }.width(), 1.5);
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 2u8, end: 7 }.width(), 5);
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: 7u8, end: 2 }.width(), 0);
+    /// assert_eq!(RangeFromExclusiveToInclusive { start: -100i8, end: 100 }.width(), 127);
+    /// ```
+    #[inline]
+    pub fn width(&self) -> Idx {
+        if self.end < self.start {
+            Idx::width_between(self.start, self.start)
+        } else {
+            Idx::width_between(self.start, self.end)
+        }
+    }
+}
+
+impl<Idx> RangeFromExclusiveToExclusive<Idx>
+where
+    Idx: Width,
+{
+    /// Returns the distance `end - start` between the bounds.
+    ///
+    /// This is the arithmetic width of the range, not the number of values it contains: over
+    /// integers, `{3, 4}` has a width of `1` but contains no values. A range whose `end` is below
+    /// its `start` reports a zero width rather than a negative one.
+    ///
+    /// For the primitive integers, the width saturates at the maximum value of the type, which
+    /// only happens for signed ranges spanning more than half of the type. For floats, the width
+    /// is `NaN` if either bound is `NaN`, or if both bounds are the same infinity.
+    ///
+    /// # Example
+    /// ```
+    /// use more_ranges::RangeFromExclusiveToExclusive;
+    ///
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 0.5, end: 2.0 }.width(), 1.5);
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 3u8, end: 4 }.width(), 1);
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: 7u8, end: 2 }.width(), 0);
+    /// assert_eq!(RangeFromExclusiveToExclusive { start: -100i8, end: 100 }.width(), 127);
+    /// ```
+    #[inline]
+    pub fn width(&self) -> Idx {
+        if self.end < self.start {
+            Idx::width_between(self.start, self.start)
+        } else {
+            Idx::width_between(self.start, self.end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use {RangeFromExclusiveToExclusive, RangeFromExclusiveToInclusive};
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_width() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -3i8,
+                end: 4
+            }
+            .width(),
+            7
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive { start: 4i8, end: 4 }.width(),
+            0
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 4i8,
+                end: -3
+            }
+            .width(),
+            0
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 0u8,
+                end: u8::max_value()
+            }
+            .width(),
+            u8::max_value()
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_width_saturates() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: i8::min_value(),
+                end: i8::max_value()
+            }
+            .width(),
+            i8::max_value()
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -100i8,
+                end: 100
+            }
+            .width(),
+            i8::max_value()
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: -1i8,
+                end: i8::max_value()
+            }
+            .width(),
+            i8::max_value()
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: i128::min_value(),
+                end: 0
+            }
+            .width(),
+            i128::max_value()
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_width_float() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 0.25,
+                end: 1.0
+            }
+            .width(),
+            0.75
+        );
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 1.0,
+                end: 0.25
+            }
+            .width(),
+            0.0
+        );
+        assert!(RangeFromExclusiveToInclusive {
+            start: 0.0,
+            end: ::core::f64::NAN
+        }
+        .width()
+        .is_nan());
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: 0.0,
+                end: ::core::f64::INFINITY
+            }
+            .width(),
+            ::core::f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_inclusive_width_duration() {
+        assert_eq!(
+            RangeFromExclusiveToInclusive {
+                start: Duration::from_secs(5),
+                end: Duration::from_secs(2)
+            }
+            .width(),
+            Duration::from_secs(0)
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_width() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: -3i8,
+                end: 4
+            }
+            .width(),
+            7
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 3i8, end: 4 }.width(),
+            1
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive { start: 4i8, end: 4 }.width(),
+            0
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 4i8,
+                end: -3
+            }
+            .width(),
+            0
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_width_saturates() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: i8::min_value(),
+                end: i8::max_value()
+            }
+            .width(),
+            i8::max_value()
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: -100i8,
+                end: 100
+            }
+            .width(),
+            i8::max_value()
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: i64::min_value(),
+                end: -1
+            }
+            .width(),
+            i64::max_value()
+        );
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_width_float() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 0.25,
+                end: 1.0
+            }
+            .width(),
+            0.75
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: 1.0,
+                end: 0.25
+            }
+            .width(),
+            0.0
+        );
+        assert!(RangeFromExclusiveToExclusive {
+            start: ::core::f64::NAN,
+            end: 1.0
+        }
+        .width()
+        .is_nan());
+    }
+
+    #[test]
+    fn range_from_exclusive_to_exclusive_width_duration() {
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: Duration::from_secs(2),
+                end: Duration::from_secs(5)
+            }
+            .width(),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            RangeFromExclusiveToExclusive {
+                start: Duration::from_secs(5),
+                end: Duration::from_secs(2)
+            }
+            .width(),
+            Duration::from_secs(0)
+        );
+    }
+}